@@ -0,0 +1,497 @@
+//! Safe wrappers for the camera API in [`sdl3_sys::camera`].
+//!
+//! Open a [`Camera`] with a format picked by [`FormatRequest`], then poll it for frames
+//! with [`Camera::poll_frame`] or [`Camera::frames`], e.g. from `app_iterate`. Each
+//! [`Frame`] gives access to the frame's [`Surface`] and is released back to the camera
+//! when it's dropped.
+//!
+//! The user may have to approve access to the camera before frames arrive. Polling reports
+//! this with [`FramePoll::PermissionPending`], and returns an error if access was denied.
+
+use crate::{
+    surface::{Surface, SurfaceLock},
+    Error, Result,
+};
+use alloc::{string::String, vec::Vec};
+use core::{
+    ffi::{c_int, CStr},
+    fmt,
+    iter::FusedIterator,
+    mem::ManuallyDrop,
+    ops::Deref,
+    ptr::{self, NonNull},
+    slice,
+};
+use sdl3_sys::{
+    camera::{
+        SDL_AcquireCameraFrame, SDL_Camera, SDL_CameraID, SDL_CameraPosition, SDL_CameraSpec,
+        SDL_CloseCamera, SDL_GetCameraFormat, SDL_GetCameraID, SDL_GetCameraName,
+        SDL_GetCameraPermissionState, SDL_GetCameraPosition, SDL_GetCameraSupportedFormats,
+        SDL_GetCameras, SDL_OpenCamera, SDL_ReleaseCameraFrame,
+    },
+    pixels::{SDL_Colorspace, SDL_PixelFormat},
+    stdinc::SDL_free,
+};
+
+/// A camera format. See [`SDL_CameraSpec`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CameraSpec {
+    /// Pixel format of the frames
+    pub format: SDL_PixelFormat,
+    /// Colorspace of the frames
+    pub colorspace: SDL_Colorspace,
+    /// Width of the frames
+    pub width: i32,
+    /// Height of the frames
+    pub height: i32,
+    /// Frame rate numerator
+    pub framerate_numerator: i32,
+    /// Frame rate denominator
+    pub framerate_denominator: i32,
+}
+
+impl CameraSpec {
+    /// Get the frame rate in frames per second, or 0 if it's unknown.
+    pub fn fps(&self) -> f32 {
+        if self.framerate_denominator == 0 {
+            0.0
+        } else {
+            self.framerate_numerator as f32 / self.framerate_denominator as f32
+        }
+    }
+}
+
+impl fmt::Debug for CameraSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CameraSpec")
+            .field("format", &self.format.0)
+            .field("colorspace", &self.colorspace.0)
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("framerate_numerator", &self.framerate_numerator)
+            .field("framerate_denominator", &self.framerate_denominator)
+            .finish()
+    }
+}
+
+impl From<SDL_CameraSpec> for CameraSpec {
+    fn from(value: SDL_CameraSpec) -> Self {
+        Self {
+            format: value.format,
+            colorspace: value.colorspace,
+            width: value.width,
+            height: value.height,
+            framerate_numerator: value.framerate_numerator,
+            framerate_denominator: value.framerate_denominator,
+        }
+    }
+}
+
+impl From<CameraSpec> for SDL_CameraSpec {
+    fn from(value: CameraSpec) -> Self {
+        Self {
+            format: value.format,
+            colorspace: value.colorspace,
+            width: value.width,
+            height: value.height,
+            framerate_numerator: value.framerate_numerator,
+            framerate_denominator: value.framerate_denominator,
+        }
+    }
+}
+
+/// Get the instance IDs of the connected cameras. See [`SDL_GetCameras`].
+pub fn cameras() -> Result<Vec<SDL_CameraID>> {
+    let mut count: c_int = 0;
+    let ids = unsafe { SDL_GetCameras(&mut count) };
+    if ids.is_null() {
+        return Err(Error::from_sdl());
+    }
+    let vec = unsafe { slice::from_raw_parts(ids, count as usize) }.to_vec();
+    unsafe { SDL_free(ids.cast()) };
+    Ok(vec)
+}
+
+/// Get the name of a camera. See [`SDL_GetCameraName`].
+pub fn camera_name(id: SDL_CameraID) -> Option<String> {
+    let name = unsafe { SDL_GetCameraName(id) };
+    (!name.is_null()).then(|| {
+        unsafe { CStr::from_ptr(name) }
+            .to_string_lossy()
+            .into_owned()
+    })
+}
+
+/// Get the position of a camera. See [`SDL_GetCameraPosition`].
+pub fn camera_position(id: SDL_CameraID) -> SDL_CameraPosition {
+    unsafe { SDL_GetCameraPosition(id) }
+}
+
+/// Get the formats a camera supports. See [`SDL_GetCameraSupportedFormats`].
+///
+/// The list may be empty if the camera doesn't report its formats.
+pub fn supported_formats(id: SDL_CameraID) -> Result<Vec<CameraSpec>> {
+    let mut count: c_int = 0;
+    let specs = unsafe { SDL_GetCameraSupportedFormats(id, &mut count) };
+    if specs.is_null() {
+        return Err(Error::from_sdl());
+    }
+    let vec = unsafe { slice::from_raw_parts(specs, count as usize) }
+        .iter()
+        .map(|spec| CameraSpec::from(unsafe { **spec }))
+        .collect();
+    unsafe { SDL_free(specs.cast()) };
+    Ok(vec)
+}
+
+/// Preferences for selecting a camera format from the supported formats.
+///
+/// The format closest to the requested resolution is selected, and of those, the one with
+/// the closest frame rate. Formats with a different pixel format are never selected if
+/// one is requested.
+#[derive(Clone, Copy, Default, PartialEq)]
+pub struct FormatRequest {
+    /// Requested resolution
+    pub size: Option<(i32, i32)>,
+    /// Requested frame rate in frames per second
+    pub fps: Option<f32>,
+    /// Required pixel format
+    pub format: Option<SDL_PixelFormat>,
+}
+
+impl FormatRequest {
+    /// Request no particular format. Selects the first supported format.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request a resolution.
+    pub fn size(mut self, width: i32, height: i32) -> Self {
+        self.size = Some((width, height));
+        self
+    }
+
+    /// Request a frame rate.
+    pub fn fps(mut self, fps: f32) -> Self {
+        self.fps = Some(fps);
+        self
+    }
+
+    /// Require a pixel format.
+    pub fn format(mut self, format: SDL_PixelFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Select the best matching format from `formats`.
+    pub fn select(&self, formats: &[CameraSpec]) -> Option<CameraSpec> {
+        let size_distance = |spec: &CameraSpec| {
+            self.size.map_or(0, |(w, h)| {
+                (spec.width as i64 - w as i64).abs() + (spec.height as i64 - h as i64).abs()
+            })
+        };
+        let fps_distance = |spec: &CameraSpec| self.fps.map_or(0.0, |fps| (spec.fps() - fps).abs());
+        formats
+            .iter()
+            .filter(|spec| self.format.is_none() || self.format == Some(spec.format))
+            .min_by(|a, b| {
+                size_distance(a)
+                    .cmp(&size_distance(b))
+                    .then(fps_distance(a).total_cmp(&fps_distance(b)))
+            })
+            .copied()
+    }
+}
+
+impl fmt::Debug for FormatRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FormatRequest")
+            .field("size", &self.size)
+            .field("fps", &self.fps)
+            .field("format", &self.format.map(|format| format.0))
+            .finish()
+    }
+}
+
+/// Whether the user has approved access to a camera.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CameraPermission {
+    /// The user hasn't decided yet
+    Pending,
+    /// Access was approved
+    Approved,
+    /// Access was denied
+    Denied,
+}
+
+/// The result of [`Camera::poll_frame`].
+pub enum FramePoll<'c> {
+    /// A new frame
+    Frame(Frame<'c>),
+    /// No new frame is available yet
+    NotReady,
+    /// The user hasn't approved access to the camera yet
+    PermissionPending,
+}
+
+/// An open camera. It's closed when this is dropped.
+pub struct Camera {
+    ptr: NonNull<SDL_Camera>,
+}
+
+impl Camera {
+    /// Open a camera. See [`SDL_OpenCamera`].
+    ///
+    /// If `spec` is `None`, SDL picks a format. SDL converts frames if the camera doesn't
+    /// support `spec` directly.
+    pub fn open(id: SDL_CameraID, spec: Option<&CameraSpec>) -> Result<Self> {
+        let spec = spec.map(|spec| SDL_CameraSpec::from(*spec));
+        let spec_ptr = spec.as_ref().map_or(ptr::null(), |spec| spec as *const _);
+        NonNull::new(unsafe { SDL_OpenCamera(id, spec_ptr) })
+            .map(|ptr| Self { ptr })
+            .ok_or_else(Error::from_sdl)
+    }
+
+    /// Open a camera with the supported format that best matches `request`.
+    ///
+    /// If the camera doesn't report any formats, SDL picks one.
+    pub fn open_with(id: SDL_CameraID, request: &FormatRequest) -> Result<Self> {
+        let formats = supported_formats(id)?;
+        let spec = request.select(&formats);
+        if spec.is_none() && !formats.is_empty() {
+            return Err(Error::new("no matching camera format"));
+        }
+        Self::open(id, spec.as_ref())
+    }
+
+    /// Get the raw camera pointer. The camera is owned by `self`.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *mut SDL_Camera {
+        self.ptr.as_ptr()
+    }
+
+    /// Get the instance ID of the camera.
+    pub fn id(&self) -> SDL_CameraID {
+        unsafe { SDL_GetCameraID(self.as_ptr()) }
+    }
+
+    /// Get whether the user has approved access to the camera.
+    /// See [`SDL_GetCameraPermissionState`].
+    pub fn permission(&self) -> CameraPermission {
+        match unsafe { SDL_GetCameraPermissionState(self.as_ptr()) } {
+            0 => CameraPermission::Pending,
+            p if p > 0 => CameraPermission::Approved,
+            _ => CameraPermission::Denied,
+        }
+    }
+
+    /// Get the format of the frames. This is only known after access was approved.
+    /// See [`SDL_GetCameraFormat`].
+    pub fn format(&self) -> Result<CameraSpec> {
+        let mut spec = SDL_CameraSpec {
+            format: SDL_PixelFormat::UNKNOWN,
+            colorspace: SDL_Colorspace::UNKNOWN,
+            width: 0,
+            height: 0,
+            framerate_numerator: 0,
+            framerate_denominator: 0,
+        };
+        if unsafe { SDL_GetCameraFormat(self.as_ptr(), &mut spec) } {
+            Ok(spec.into())
+        } else {
+            Err(Error::from_sdl())
+        }
+    }
+
+    /// Get the next frame, if one is available. See [`SDL_AcquireCameraFrame`].
+    ///
+    /// Returns an error if access to the camera was denied.
+    pub fn poll_frame(&self) -> Result<FramePoll<'_>> {
+        match self.permission() {
+            CameraPermission::Pending => return Ok(FramePoll::PermissionPending),
+            CameraPermission::Denied => return Err(Error::new("camera access was denied")),
+            CameraPermission::Approved => (),
+        }
+        let mut timestamp_ns = 0;
+        let surface = unsafe { SDL_AcquireCameraFrame(self.as_ptr(), &mut timestamp_ns) };
+        Ok(match NonNull::new(surface) {
+            Some(surface) => FramePoll::Frame(Frame {
+                camera: self,
+                surface: ManuallyDrop::new(unsafe { Surface::from_raw(surface) }),
+                timestamp_ns,
+            }),
+            None => FramePoll::NotReady,
+        })
+    }
+
+    /// Iterate over the frames that are available now.
+    ///
+    /// The iterator ends when no more frames are ready or access hasn't been approved yet.
+    /// If access was denied, it yields the error once and then ends.
+    pub fn frames(&self) -> Frames<'_> {
+        Frames {
+            camera: self,
+            done: false,
+        }
+    }
+}
+
+impl Drop for Camera {
+    fn drop(&mut self) {
+        unsafe { SDL_CloseCamera(self.as_ptr()) }
+    }
+}
+
+/// Iterator over available frames. See [`Camera::frames`].
+pub struct Frames<'c> {
+    camera: &'c Camera,
+    done: bool,
+}
+
+impl<'c> Iterator for Frames<'c> {
+    type Item = Result<Frame<'c>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.camera.poll_frame() {
+            Ok(FramePoll::Frame(frame)) => Some(Ok(frame)),
+            Ok(FramePoll::NotReady | FramePoll::PermissionPending) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+impl FusedIterator for Frames<'_> {}
+
+/// A frame from a [`Camera`]. It's released back to the camera when this is dropped.
+///
+/// This derefs to the [`Surface`] with the frame's pixels.
+pub struct Frame<'c> {
+    camera: &'c Camera,
+    surface: ManuallyDrop<Surface>,
+    timestamp_ns: u64,
+}
+
+impl Frame<'_> {
+    /// Get the timestamp of the frame in nanoseconds, or 0 if it's unknown.
+    #[inline(always)]
+    pub fn timestamp_ns(&self) -> u64 {
+        self.timestamp_ns
+    }
+
+    /// Get the surface with the frame's pixels.
+    #[inline(always)]
+    pub fn surface(&self) -> &Surface {
+        &self.surface
+    }
+
+    /// Lock the frame's surface for direct access to its pixels. See [`Surface::lock`].
+    ///
+    /// The surface itself isn't available mutably, because it belongs to the camera.
+    pub fn lock(&mut self) -> Result<SurfaceLock<'_>> {
+        self.surface.lock()
+    }
+}
+
+impl Deref for Frame<'_> {
+    type Target = Surface;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.surface
+    }
+}
+
+impl Drop for Frame<'_> {
+    fn drop(&mut self) {
+        unsafe { SDL_ReleaseCameraFrame(self.camera.as_ptr(), self.surface.as_ptr()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec(format: SDL_PixelFormat, width: i32, height: i32, num: i32, den: i32) -> CameraSpec {
+        CameraSpec {
+            format,
+            colorspace: SDL_Colorspace::SRGB,
+            width,
+            height,
+            framerate_numerator: num,
+            framerate_denominator: den,
+        }
+    }
+
+    #[test]
+    fn fps() {
+        assert_eq!(spec(SDL_PixelFormat::NV12, 1, 1, 30, 1).fps(), 30.0);
+        assert_eq!(
+            spec(SDL_PixelFormat::NV12, 1, 1, 30000, 1001).fps(),
+            30000.0 / 1001.0
+        );
+        assert_eq!(spec(SDL_PixelFormat::NV12, 1, 1, 30, 0).fps(), 0.0);
+        assert_eq!(spec(SDL_PixelFormat::NV12, 1, 1, 0, 0).fps(), 0.0);
+    }
+
+    #[test]
+    fn select() {
+        let nv12 = SDL_PixelFormat::NV12;
+        let yuy2 = SDL_PixelFormat::YUY2;
+        let formats = [
+            spec(nv12, 640, 480, 60, 1),
+            spec(nv12, 1280, 720, 30, 1),
+            spec(yuy2, 1280, 720, 60, 1),
+            spec(yuy2, 1920, 1080, 30, 1),
+        ];
+
+        assert_eq!(FormatRequest::new().select(&[]), None);
+        assert_eq!(FormatRequest::new().size(640, 480).select(&[]), None);
+        // without preferences, the first format wins
+        assert_eq!(FormatRequest::new().select(&formats), Some(formats[0]));
+
+        // the resolution is matched before the frame rate
+        let request = FormatRequest::new().size(1280, 720).fps(60.0);
+        assert_eq!(request.select(&formats), Some(formats[2]));
+        let request = FormatRequest::new().size(1920, 1080).fps(60.0);
+        assert_eq!(request.select(&formats), Some(formats[3]));
+        assert_eq!(
+            FormatRequest::new()
+                .size(700, 500)
+                .fps(30.0)
+                .select(&formats),
+            Some(formats[0])
+        );
+        assert_eq!(
+            FormatRequest::new().fps(31.0).select(&formats),
+            Some(formats[1])
+        );
+
+        // the pixel format is a requirement, not a preference
+        let request = FormatRequest::new().size(1920, 1080).format(nv12);
+        assert_eq!(request.select(&formats), Some(formats[1]));
+        let request = FormatRequest::new().size(640, 480).format(yuy2);
+        assert_eq!(request.select(&formats), Some(formats[2]));
+        let request = FormatRequest::new().format(SDL_PixelFormat::RGBA32);
+        assert_eq!(request.select(&formats), None);
+
+        // an unknown frame rate counts as 0 fps
+        let formats = [spec(nv12, 640, 480, 30, 0), spec(nv12, 640, 480, 15, 1)];
+        assert_eq!(
+            FormatRequest::new().fps(10.0).select(&formats),
+            Some(formats[1])
+        );
+        assert_eq!(
+            FormatRequest::new().fps(1.0).select(&formats),
+            Some(formats[0])
+        );
+    }
+}
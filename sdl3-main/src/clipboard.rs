@@ -0,0 +1,204 @@
+//! Safe wrappers for the clipboard API in [`sdl3_sys::clipboard`].
+//!
+//! [`set_clipboard_data`] offers data in several MIME types at once, produced on demand by a
+//! Rust closure. The closure is owned by SDL until the clipboard is cleared or replaced, at
+//! which point it's dropped.
+//!
+//! ```no_run
+//! use std::borrow::Cow;
+//! use sdl3_main::clipboard::set_clipboard_data;
+//!
+//! let rows = vec![(1u32, "one"), (2, "two")];
+//! set_clipboard_data(
+//!     &["text/plain", "text/html", "application/x-my-rows"],
+//!     move |mime| match mime {
+//!         "text/plain" => Some(Cow::Borrowed(b"one, two".as_slice())),
+//!         "text/html" => Some(Cow::Borrowed(b"<b>one</b>, <b>two</b>".as_slice())),
+//!         "application/x-my-rows" => Some(Cow::Owned(
+//!             rows.iter().flat_map(|(n, _)| n.to_le_bytes()).collect(),
+//!         )),
+//!         _ => None,
+//!     },
+//! )?;
+//! # Ok::<(), sdl3_main::Error>(())
+//! ```
+
+use crate::{
+    error::{check, guard},
+    Error, Result,
+};
+use alloc::{borrow::Cow, boxed::Box, ffi::CString, string::String, vec::Vec};
+use core::{
+    cell::{Cell, RefCell},
+    ffi::{c_char, c_void, CStr},
+    ptr, slice,
+};
+use sdl3_sys::{
+    clipboard::{
+        SDL_ClearClipboardData, SDL_GetClipboardData, SDL_GetClipboardMimeTypes,
+        SDL_GetClipboardText, SDL_HasClipboardData, SDL_HasClipboardText, SDL_SetClipboardData,
+        SDL_SetClipboardText,
+    },
+    init::{SDL_WasInit, SDL_INIT_VIDEO},
+    stdinc::SDL_free,
+};
+
+type ProviderFn = dyn Fn(&str) -> Option<Cow<'static, [u8]>> + Send;
+
+struct Provider {
+    provide: Box<ProviderFn>,
+    // the last data returned to SDL, which must stay valid until the next call or cleanup
+    last: RefCell<Option<Cow<'static, [u8]>>>,
+    // set while `provide` runs, in case it reads the clipboard and SDL calls back into it
+    busy: Cell<bool>,
+}
+
+unsafe extern "C" fn data_callback(
+    userdata: *mut c_void,
+    mime_type: *const c_char,
+    size: *mut usize,
+) -> *const c_void {
+    let provider = unsafe { &*(userdata as *const Provider) };
+    unsafe { *size = 0 };
+    if mime_type.is_null() || provider.busy.get() {
+        return ptr::null();
+    }
+    let Ok(mime_type) = unsafe { CStr::from_ptr(mime_type) }.to_str() else {
+        return ptr::null();
+    };
+    *provider.last.borrow_mut() = None;
+    provider.busy.set(true);
+    let data = guard(None, || (provider.provide)(mime_type));
+    provider.busy.set(false);
+    let last = &mut *provider.last.borrow_mut();
+    *last = data;
+    match last {
+        Some(data) => {
+            unsafe { *size = data.len() };
+            data.as_ptr() as *const c_void
+        }
+        None => ptr::null(),
+    }
+}
+
+unsafe extern "C" fn cleanup_callback(userdata: *mut c_void) {
+    drop(unsafe { Box::from_raw(userdata as *mut Provider) });
+}
+
+/// Offer data on the clipboard in each of `mime_types`. See [`SDL_SetClipboardData`].
+///
+/// `provide` is called with the requested MIME type when another application, or this one,
+/// reads the clipboard, and returns the data for that type or `None` if it's unavailable.
+/// SDL calls it on the main thread. The closure is dropped when the clipboard is cleared or
+/// replaced. If it reads the clipboard itself, SDL doesn't get any data from the nested call.
+///
+/// The video subsystem must be initialized.
+pub fn set_clipboard_data<F>(mime_types: &[&str], provide: F) -> Result<()>
+where
+    F: Fn(&str) -> Option<Cow<'static, [u8]>> + Send + 'static,
+{
+    if mime_types.is_empty() {
+        return Err(Error::new("no mime types to offer"));
+    }
+    let mime_types = mime_types
+        .iter()
+        .map(|&mime| CString::new(mime).map_err(|_| Error::new("mime type contains nul")))
+        .collect::<Result<Vec<_>>>()?;
+    // SDL copies the mime types
+    let mut mime_type_ptrs: Vec<*const c_char> = mime_types.iter().map(|m| m.as_ptr()).collect();
+    // SDL doesn't call the cleanup callback if it fails before taking ownership of the
+    // provider, so check for that here
+    if unsafe { SDL_WasInit(SDL_INIT_VIDEO) } == 0 {
+        return Err(Error::new(
+            "video subsystem must be initialized to set clipboard data",
+        ));
+    }
+    let userdata = Box::into_raw(Box::new(Provider {
+        provide: Box::new(provide),
+        last: RefCell::new(None),
+        busy: Cell::new(false),
+    }));
+    // SDL only fails without taking ownership here if it runs out of memory, in which case
+    // the provider is leaked rather than risk a double free
+    check(unsafe {
+        SDL_SetClipboardData(
+            Some(data_callback),
+            Some(cleanup_callback),
+            userdata as *mut c_void,
+            mime_type_ptrs.as_mut_ptr(),
+            mime_type_ptrs.len(),
+        )
+    })
+}
+
+/// Clear the clipboard data, dropping any provider set by [`set_clipboard_data`].
+/// See [`SDL_ClearClipboardData`].
+#[inline]
+pub fn clear_clipboard_data() -> Result<()> {
+    check(unsafe { SDL_ClearClipboardData() })
+}
+
+/// Get the clipboard data for `mime_type`. See [`SDL_GetClipboardData`].
+pub fn clipboard_data(mime_type: &str) -> Result<Vec<u8>> {
+    let mime_type = CString::new(mime_type).map_err(|_| Error::new("mime type contains nul"))?;
+    let mut size = 0;
+    let data = unsafe { SDL_GetClipboardData(mime_type.as_ptr(), &mut size) };
+    if data.is_null() {
+        return Err(Error::from_sdl());
+    }
+    let result = unsafe { slice::from_raw_parts(data as *const u8, size) }.to_vec();
+    unsafe { SDL_free(data) };
+    Ok(result)
+}
+
+/// Check if there's clipboard data available for `mime_type`. See [`SDL_HasClipboardData`].
+pub fn has_clipboard_data(mime_type: &str) -> bool {
+    CString::new(mime_type).is_ok_and(|mime| unsafe { SDL_HasClipboardData(mime.as_ptr()) })
+}
+
+/// Get the MIME types the current clipboard data is available in.
+/// See [`SDL_GetClipboardMimeTypes`].
+pub fn clipboard_mime_types() -> Result<Vec<String>> {
+    let mut count = 0;
+    let list = unsafe { SDL_GetClipboardMimeTypes(&mut count) };
+    if list.is_null() {
+        return Err(Error::from_sdl());
+    }
+    let result = unsafe { slice::from_raw_parts(list, count) }
+        .iter()
+        .map(|&mime| {
+            unsafe { CStr::from_ptr(mime) }
+                .to_string_lossy()
+                .into_owned()
+        })
+        .collect();
+    unsafe { SDL_free(list as *mut c_void) };
+    Ok(result)
+}
+
+/// Put UTF-8 text on the clipboard. See [`SDL_SetClipboardText`].
+pub fn set_clipboard_text(text: &str) -> Result<()> {
+    let text = CString::new(text).map_err(|_| Error::new("text contains nul"))?;
+    check(unsafe { SDL_SetClipboardText(text.as_ptr()) })
+}
+
+/// Get UTF-8 text from the clipboard. See [`SDL_GetClipboardText`].
+///
+/// Returns an empty string if there's no text on the clipboard.
+pub fn clipboard_text() -> Result<String> {
+    let text = unsafe { SDL_GetClipboardText() };
+    if text.is_null() {
+        return Err(Error::from_sdl());
+    }
+    let result = unsafe { CStr::from_ptr(text) }
+        .to_string_lossy()
+        .into_owned();
+    unsafe { SDL_free(text as *mut c_void) };
+    Ok(result)
+}
+
+/// Check if there's text on the clipboard. See [`SDL_HasClipboardText`].
+#[inline]
+pub fn has_clipboard_text() -> bool {
+    unsafe { SDL_HasClipboardText() }
+}
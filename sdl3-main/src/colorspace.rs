@@ -0,0 +1,538 @@
+//! Helpers for working with [`SDL_Colorspace`]: decoding the packed colorspace fields,
+//! transfer functions and YCbCr matrices.
+//!
+//! Transfer functions operate on normalized values in the range `0.0..=1.0`, except for the
+//! PQ functions, which convert to and from absolute luminance in nits. The power and log
+//! functions come from SDL's stdinc, so these work without `std`.
+
+use core::fmt;
+use sdl3_sys::{
+    pixels::{
+        SDL_ChromaLocation, SDL_ColorPrimaries, SDL_ColorRange, SDL_ColorType, SDL_Colorspace,
+        SDL_MatrixCoefficients, SDL_TransferCharacteristics, SDL_COLORSPACECHROMA,
+        SDL_COLORSPACEMATRIX, SDL_COLORSPACEPRIMARIES, SDL_COLORSPACERANGE, SDL_COLORSPACETRANSFER,
+        SDL_COLORSPACETYPE, SDL_DEFINE_COLORSPACE,
+    },
+    stdinc::{SDL_expf, SDL_logf, SDL_powf},
+};
+
+/// The fields of an [`SDL_Colorspace`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ColorspaceFields {
+    /// The color type (RGB or YCbCr)
+    pub color_type: SDL_ColorType,
+    /// Limited or full range
+    pub range: SDL_ColorRange,
+    /// Color primaries
+    pub primaries: SDL_ColorPrimaries,
+    /// Transfer characteristics
+    pub transfer: SDL_TransferCharacteristics,
+    /// Matrix coefficients for YCbCr conversion
+    pub matrix: SDL_MatrixCoefficients,
+    /// Chroma sample location
+    pub chroma: SDL_ChromaLocation,
+}
+
+impl ColorspaceFields {
+    /// Decode the fields of a colorspace.
+    pub const fn decode(colorspace: SDL_Colorspace) -> Self {
+        Self {
+            color_type: SDL_COLORSPACETYPE(colorspace),
+            range: SDL_COLORSPACERANGE(colorspace),
+            primaries: SDL_COLORSPACEPRIMARIES(colorspace),
+            transfer: SDL_COLORSPACETRANSFER(colorspace),
+            matrix: SDL_COLORSPACEMATRIX(colorspace),
+            chroma: SDL_COLORSPACECHROMA(colorspace),
+        }
+    }
+
+    /// Pack the fields into a colorspace, like `SDL_DEFINE_COLORSPACE`.
+    pub const fn encode(&self) -> SDL_Colorspace {
+        SDL_DEFINE_COLORSPACE(
+            self.color_type,
+            self.range,
+            self.primaries,
+            self.transfer,
+            self.matrix,
+            self.chroma,
+        )
+    }
+}
+
+impl fmt::Debug for ColorspaceFields {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ColorspaceFields")
+            .field("color_type", &self.color_type.0)
+            .field("range", &self.range.0)
+            .field("primaries", &self.primaries.0)
+            .field("transfer", &self.transfer.0)
+            .field("matrix", &self.matrix.0)
+            .field("chroma", &self.chroma.0)
+            .finish()
+    }
+}
+
+impl From<SDL_Colorspace> for ColorspaceFields {
+    #[inline(always)]
+    fn from(value: SDL_Colorspace) -> Self {
+        Self::decode(value)
+    }
+}
+
+impl From<ColorspaceFields> for SDL_Colorspace {
+    #[inline(always)]
+    fn from(value: ColorspaceFields) -> Self {
+        value.encode()
+    }
+}
+
+#[inline(always)]
+fn powf(x: f32, y: f32) -> f32 {
+    unsafe { SDL_powf(x, y) }
+}
+
+/// Convert an sRGB encoded value to linear.
+pub fn srgb_to_linear(v: f32) -> f32 {
+    if v <= 0.04045 {
+        v / 12.92
+    } else {
+        powf((v + 0.055) / 1.055, 2.4)
+    }
+}
+
+/// Convert a linear value to sRGB encoding.
+pub fn linear_to_srgb(v: f32) -> f32 {
+    if v <= 0.0031308 {
+        v * 12.92
+    } else {
+        1.055 * powf(v, 1.0 / 2.4) - 0.055
+    }
+}
+
+/// Convert a BT.601/BT.709/BT.2020 encoded value to linear.
+pub fn bt709_to_linear(v: f32) -> f32 {
+    if v < 0.081 {
+        v / 4.5
+    } else {
+        powf((v + 0.099) / 1.099, 1.0 / 0.45)
+    }
+}
+
+/// Convert a linear value to BT.601/BT.709/BT.2020 encoding.
+pub fn linear_to_bt709(v: f32) -> f32 {
+    if v < 0.018 {
+        v * 4.5
+    } else {
+        1.099 * powf(v, 0.45) - 0.099
+    }
+}
+
+const PQ_M1: f32 = 2610.0 / 16384.0;
+const PQ_M2: f32 = 2523.0 / 4096.0 * 128.0;
+const PQ_C1: f32 = 3424.0 / 4096.0;
+const PQ_C2: f32 = 2413.0 / 4096.0 * 32.0;
+const PQ_C3: f32 = 2392.0 / 4096.0 * 32.0;
+
+/// Convert a PQ (SMPTE ST 2084) encoded value to luminance in nits (`0.0..=10000.0`).
+pub fn pq_to_nits(v: f32) -> f32 {
+    let p = powf(v.max(0.0), 1.0 / PQ_M2);
+    let num = (p - PQ_C1).max(0.0);
+    let den = PQ_C2 - PQ_C3 * p;
+    10000.0 * powf(num / den, 1.0 / PQ_M1)
+}
+
+/// Convert luminance in nits (`0.0..=10000.0`) to PQ (SMPTE ST 2084) encoding.
+pub fn nits_to_pq(nits: f32) -> f32 {
+    let y = powf((nits / 10000.0).max(0.0), PQ_M1);
+    powf((PQ_C1 + PQ_C2 * y) / (1.0 + PQ_C3 * y), PQ_M2)
+}
+
+const HLG_A: f32 = 0.178_832_77;
+const HLG_B: f32 = 1.0 - 4.0 * HLG_A;
+const HLG_C: f32 = 0.559_910_7;
+
+/// Convert an HLG (ARIB STD-B67) encoded value to normalized scene linear light.
+pub fn hlg_to_linear(v: f32) -> f32 {
+    if v <= 0.5 {
+        v * v / 3.0
+    } else {
+        (unsafe { SDL_expf((v - HLG_C) / HLG_A) } + HLG_B) / 12.0
+    }
+}
+
+/// Convert normalized scene linear light to HLG (ARIB STD-B67) encoding.
+pub fn linear_to_hlg(v: f32) -> f32 {
+    if v <= 1.0 / 12.0 {
+        powf(3.0 * v.max(0.0), 0.5)
+    } else {
+        HLG_A * unsafe { SDL_logf(12.0 * v - HLG_B) } + HLG_C
+    }
+}
+
+/// Convert an encoded value to linear using the transfer function `transfer`.
+///
+/// PQ values are converted so that `1.0` is 10000 nits. Returns `None` for unsupported
+/// transfer characteristics.
+pub fn transfer_to_linear(transfer: SDL_TransferCharacteristics, v: f32) -> Option<f32> {
+    Some(match transfer {
+        SDL_TransferCharacteristics::SRGB => srgb_to_linear(v),
+        SDL_TransferCharacteristics::BT709
+        | SDL_TransferCharacteristics::BT601
+        | SDL_TransferCharacteristics::BT2020_10BIT
+        | SDL_TransferCharacteristics::BT2020_12BIT => bt709_to_linear(v),
+        SDL_TransferCharacteristics::GAMMA22 => powf(v, 2.2),
+        SDL_TransferCharacteristics::GAMMA28 => powf(v, 2.8),
+        SDL_TransferCharacteristics::LINEAR => v,
+        SDL_TransferCharacteristics::PQ => pq_to_nits(v) / 10000.0,
+        SDL_TransferCharacteristics::HLG => hlg_to_linear(v),
+        _ => return None,
+    })
+}
+
+/// Convert a linear value to an encoded value using the transfer function `transfer`.
+///
+/// This is the inverse of [`transfer_to_linear`].
+pub fn linear_to_transfer(transfer: SDL_TransferCharacteristics, v: f32) -> Option<f32> {
+    Some(match transfer {
+        SDL_TransferCharacteristics::SRGB => linear_to_srgb(v),
+        SDL_TransferCharacteristics::BT709
+        | SDL_TransferCharacteristics::BT601
+        | SDL_TransferCharacteristics::BT2020_10BIT
+        | SDL_TransferCharacteristics::BT2020_12BIT => linear_to_bt709(v),
+        SDL_TransferCharacteristics::GAMMA22 => powf(v, 1.0 / 2.2),
+        SDL_TransferCharacteristics::GAMMA28 => powf(v, 1.0 / 2.8),
+        SDL_TransferCharacteristics::LINEAR => v,
+        SDL_TransferCharacteristics::PQ => nits_to_pq(v * 10000.0),
+        SDL_TransferCharacteristics::HLG => linear_to_hlg(v),
+        _ => return None,
+    })
+}
+
+/// Luma coefficients for converting between RGB and YCbCr.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct YuvMatrix {
+    /// Red luma coefficient
+    pub kr: f32,
+    /// Blue luma coefficient
+    pub kb: f32,
+}
+
+impl YuvMatrix {
+    /// ITU-R BT.601
+    pub const BT601: Self = Self::new(0.299, 0.114);
+
+    /// ITU-R BT.709
+    pub const BT709: Self = Self::new(0.2126, 0.0722);
+
+    /// ITU-R BT.2020 non-constant luminance
+    pub const BT2020_NCL: Self = Self::new(0.2627, 0.0593);
+
+    /// Create a matrix from red and blue luma coefficients.
+    #[inline]
+    pub const fn new(kr: f32, kb: f32) -> Self {
+        Self { kr, kb }
+    }
+
+    /// Get the matrix for `SDL_MatrixCoefficients`, or `None` if it's unsupported.
+    pub const fn from_coefficients(matrix: SDL_MatrixCoefficients) -> Option<Self> {
+        match matrix {
+            SDL_MatrixCoefficients::BT601 | SDL_MatrixCoefficients::BT470BG => Some(Self::BT601),
+            SDL_MatrixCoefficients::BT709 => Some(Self::BT709),
+            SDL_MatrixCoefficients::BT2020_NCL => Some(Self::BT2020_NCL),
+            _ => None,
+        }
+    }
+
+    /// Get the matrix used by a YCbCr colorspace, or `None` if it's unsupported.
+    #[inline]
+    pub const fn from_colorspace(colorspace: SDL_Colorspace) -> Option<Self> {
+        Self::from_coefficients(SDL_COLORSPACEMATRIX(colorspace))
+    }
+
+    /// Convert normalized RGB to YCbCr, with Y in `0.0..=1.0` and Cb/Cr in `-0.5..=0.5`.
+    pub fn rgb_to_yuv(&self, [r, g, b]: [f32; 3]) -> [f32; 3] {
+        let kg = 1.0 - self.kr - self.kb;
+        let y = self.kr * r + kg * g + self.kb * b;
+        let u = (b - y) / (2.0 * (1.0 - self.kb));
+        let v = (r - y) / (2.0 * (1.0 - self.kr));
+        [y, u, v]
+    }
+
+    /// Convert YCbCr, with Y in `0.0..=1.0` and Cb/Cr in `-0.5..=0.5`, to normalized RGB.
+    pub fn yuv_to_rgb(&self, [y, u, v]: [f32; 3]) -> [f32; 3] {
+        let kg = 1.0 - self.kr - self.kb;
+        let r = y + 2.0 * (1.0 - self.kr) * v;
+        let b = y + 2.0 * (1.0 - self.kb) * u;
+        let g = (y - self.kr * r - self.kb * b) / kg;
+        [r, g, b]
+    }
+
+    /// Convert 8-bit RGB to 8-bit YCbCr in the given range.
+    pub fn rgb8_to_yuv8(&self, rgb: [u8; 3], range: SDL_ColorRange) -> [u8; 3] {
+        let [y, u, v] = self.rgb_to_yuv(rgb.map(|c| c as f32 / 255.0));
+        let (ys, yo, cs) = range_scale(range);
+        [
+            to_u8(y * ys + yo),
+            to_u8(u * cs + 128.0),
+            to_u8(v * cs + 128.0),
+        ]
+    }
+
+    /// Convert 8-bit YCbCr in the given range to 8-bit RGB.
+    pub fn yuv8_to_rgb8(&self, [y, u, v]: [u8; 3], range: SDL_ColorRange) -> [u8; 3] {
+        let (ys, yo, cs) = range_scale(range);
+        let yuv = [
+            (y as f32 - yo) / ys,
+            (u as f32 - 128.0) / cs,
+            (v as f32 - 128.0) / cs,
+        ];
+        self.yuv_to_rgb(yuv).map(|c| to_u8(c * 255.0))
+    }
+}
+
+// (luma scale, luma offset, chroma scale) for 8-bit values
+const fn range_scale(range: SDL_ColorRange) -> (f32, f32, f32) {
+    if range.0 == SDL_ColorRange::FULL.0 {
+        (255.0, 0.0, 255.0)
+    } else {
+        (219.0, 16.0, 224.0)
+    }
+}
+
+#[inline]
+fn to_u8(v: f32) -> u8 {
+    (v.clamp(0.0, 255.0) + 0.5) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ffi::{c_void, CStr};
+    use sdl3_sys::{
+        error::SDL_GetError,
+        pixels::SDL_PixelFormat,
+        properties::{SDL_CreateProperties, SDL_DestroyProperties, SDL_SetFloatProperty},
+        surface::{
+            SDL_ConvertPixelsAndColorspace, SDL_PROP_SURFACE_HDR_HEADROOM_FLOAT,
+            SDL_PROP_SURFACE_SDR_WHITE_POINT_FLOAT,
+        },
+    };
+
+    const COLORSPACES: [SDL_Colorspace; 10] = [
+        SDL_Colorspace::SRGB,
+        SDL_Colorspace::SRGB_LINEAR,
+        SDL_Colorspace::HDR10,
+        SDL_Colorspace::JPEG,
+        SDL_Colorspace::BT601_LIMITED,
+        SDL_Colorspace::BT601_FULL,
+        SDL_Colorspace::BT709_LIMITED,
+        SDL_Colorspace::BT709_FULL,
+        SDL_Colorspace::BT2020_LIMITED,
+        SDL_Colorspace::BT2020_FULL,
+    ];
+
+    const MATRICES: [(SDL_Colorspace, YuvMatrix); 6] = [
+        (SDL_Colorspace::BT601_LIMITED, YuvMatrix::BT601),
+        (SDL_Colorspace::BT601_FULL, YuvMatrix::BT601),
+        (SDL_Colorspace::BT709_LIMITED, YuvMatrix::BT709),
+        (SDL_Colorspace::BT709_FULL, YuvMatrix::BT709),
+        (SDL_Colorspace::BT2020_LIMITED, YuvMatrix::BT2020_NCL),
+        (SDL_Colorspace::BT2020_FULL, YuvMatrix::BT2020_NCL),
+    ];
+
+    const COLORS: [[u8; 3]; 8] = [
+        [0, 0, 0],
+        [255, 255, 255],
+        [255, 0, 0],
+        [0, 255, 0],
+        [0, 0, 255],
+        [128, 128, 128],
+        [12, 200, 99],
+        [250, 130, 5],
+    ];
+
+    const LEVELS: [f32; 9] = [0.0, 0.01, 0.04, 0.1, 0.25, 0.5, 0.58, 0.75, 1.0];
+
+    fn sdl_error() -> &'static str {
+        unsafe { CStr::from_ptr(SDL_GetError()) }
+            .to_str()
+            .unwrap_or("?")
+    }
+
+    // convert gray values in `colorspace` to linear with SDL, with a white point and headroom
+    // chosen so that no scaling or tone mapping is applied
+    fn sdl_to_linear(colorspace: SDL_Colorspace, white_point: f32) -> Option<[f32; 9]> {
+        let src = LEVELS.map(|v| [v, v, v, 1.0]);
+        let mut dst = [[0.0_f32; 4]; 9];
+        let pitch = size_of_val(&src) as i32;
+        unsafe {
+            let src_props = SDL_CreateProperties();
+            let dst_props = SDL_CreateProperties();
+            let headroom = 10000.0 / white_point;
+            SDL_SetFloatProperty(
+                src_props,
+                SDL_PROP_SURFACE_SDR_WHITE_POINT_FLOAT,
+                white_point,
+            );
+            SDL_SetFloatProperty(src_props, SDL_PROP_SURFACE_HDR_HEADROOM_FLOAT, headroom);
+            SDL_SetFloatProperty(dst_props, SDL_PROP_SURFACE_HDR_HEADROOM_FLOAT, headroom);
+            let ok = SDL_ConvertPixelsAndColorspace(
+                LEVELS.len() as i32,
+                1,
+                SDL_PixelFormat::RGBA128_FLOAT,
+                colorspace,
+                src_props,
+                src.as_ptr() as *const c_void,
+                pitch,
+                SDL_PixelFormat::RGBA128_FLOAT,
+                SDL_Colorspace::SRGB_LINEAR,
+                dst_props,
+                dst.as_mut_ptr() as *mut c_void,
+                pitch,
+            );
+            SDL_DestroyProperties(src_props);
+            SDL_DestroyProperties(dst_props);
+            ok.then(|| dst.map(|p| p[1]))
+        }
+    }
+
+    fn check_transfer(colorspace: SDL_Colorspace, linear: &[f32; 9]) {
+        let transfer = SDL_COLORSPACETRANSFER(colorspace);
+        for (i, &v) in LEVELS.iter().enumerate() {
+            let expected = transfer_to_linear(transfer, v).unwrap();
+            assert!(
+                (expected - linear[i]).abs() <= 1e-3 * expected.max(1e-2),
+                "transfer {}: {v} -> {} (expected {expected})",
+                transfer.0,
+                linear[i]
+            );
+            let back = linear_to_transfer(transfer, expected).unwrap();
+            assert!((back - v).abs() < 1e-4, "transfer {}: {v}", transfer.0);
+        }
+    }
+
+    #[test]
+    fn transfer_matches_sdl() {
+        let linear = sdl_to_linear(SDL_Colorspace::SRGB, 1.0)
+            .unwrap_or_else(|| panic!("couldn't convert sRGB: {}", sdl_error()));
+        check_transfer(SDL_Colorspace::SRGB, &linear);
+
+        // with a white point of 10000 nits, 1.0 in linear space is 10000 nits, like
+        // `transfer_to_linear` uses for PQ
+        let linear = sdl_to_linear(SDL_Colorspace::HDR10, 10000.0)
+            .unwrap_or_else(|| panic!("couldn't convert PQ: {}", sdl_error()));
+        check_transfer(SDL_Colorspace::HDR10, &linear);
+
+        let hlg = ColorspaceFields {
+            transfer: SDL_TransferCharacteristics::HLG,
+            ..ColorspaceFields::decode(SDL_Colorspace::SRGB)
+        }
+        .encode();
+        // not every version of SDL converts from HLG
+        if let Some(linear) = sdl_to_linear(hlg, 1.0) {
+            check_transfer(hlg, &linear);
+        }
+    }
+
+    #[test]
+    fn matrices_match_sdl() {
+        for (colorspace, matrix) in MATRICES {
+            assert!(YuvMatrix::from_colorspace(colorspace) == Some(matrix));
+            let range = SDL_COLORSPACERANGE(colorspace);
+            for rgb in COLORS {
+                // two identical pixels, so the shared chroma of YUY2 isn't averaged
+                let src = [rgb, rgb];
+                let mut yuy2 = [0_u8; 4];
+                let ok = unsafe {
+                    SDL_ConvertPixelsAndColorspace(
+                        2,
+                        1,
+                        SDL_PixelFormat::RGB24,
+                        SDL_Colorspace::SRGB,
+                        0,
+                        src.as_ptr() as *const c_void,
+                        6,
+                        SDL_PixelFormat::YUY2,
+                        colorspace,
+                        0,
+                        yuy2.as_mut_ptr() as *mut c_void,
+                        4,
+                    )
+                };
+                assert!(ok, "couldn't convert to YUV: {}", sdl_error());
+                let [y, u, _, v] = yuy2;
+                let expected = matrix.rgb8_to_yuv8(rgb, range);
+                for (a, b) in expected.into_iter().zip([y, u, v]) {
+                    assert!(
+                        a.abs_diff(b) <= 2,
+                        "{:#x} {rgb:?}: {expected:?} != {:?}",
+                        colorspace.0,
+                        [y, u, v]
+                    );
+                }
+
+                let mut back = [[0_u8; 3]; 2];
+                let ok = unsafe {
+                    SDL_ConvertPixelsAndColorspace(
+                        2,
+                        1,
+                        SDL_PixelFormat::YUY2,
+                        colorspace,
+                        0,
+                        yuy2.as_ptr() as *const c_void,
+                        4,
+                        SDL_PixelFormat::RGB24,
+                        SDL_Colorspace::SRGB,
+                        0,
+                        back.as_mut_ptr() as *mut c_void,
+                        6,
+                    )
+                };
+                assert!(ok, "couldn't convert from YUV: {}", sdl_error());
+                let expected = matrix.yuv8_to_rgb8([y, u, v], range);
+                for (a, b) in expected.into_iter().zip(back[0]) {
+                    assert!(
+                        a.abs_diff(b) <= 2,
+                        "{:#x} {:?}: {expected:?} != {:?}",
+                        colorspace.0,
+                        [y, u, v],
+                        back[0]
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fields() {
+        for colorspace in COLORSPACES {
+            let fields = ColorspaceFields::decode(colorspace);
+            assert!(fields.encode() == colorspace);
+        }
+        let fields = ColorspaceFields::decode(SDL_Colorspace::HDR10);
+        assert!(fields.transfer == SDL_TransferCharacteristics::PQ);
+        assert!(fields.range == SDL_ColorRange::FULL);
+        assert!(YuvMatrix::from_colorspace(SDL_Colorspace::SRGB).is_none());
+    }
+
+    #[test]
+    fn matrix_round_trip() {
+        for (_, matrix) in MATRICES {
+            for rgb in COLORS {
+                let rgb = rgb.map(|c| c as f32 / 255.0);
+                let back = matrix.yuv_to_rgb(matrix.rgb_to_yuv(rgb));
+                for (a, b) in rgb.into_iter().zip(back) {
+                    assert!((a - b).abs() < 1e-5);
+                }
+            }
+            let [y, u, v] = matrix.rgb_to_yuv([1.0, 1.0, 1.0]);
+            assert!((y - 1.0).abs() < 1e-6 && u.abs() < 1e-6 && v.abs() < 1e-6);
+        }
+        let white = YuvMatrix::BT709.rgb8_to_yuv8([255; 3], SDL_ColorRange::LIMITED);
+        assert_eq!(white, [235, 128, 128]);
+        let black = YuvMatrix::BT709.rgb8_to_yuv8([0; 3], SDL_ColorRange::LIMITED);
+        assert_eq!(black, [16, 128, 128]);
+        let white = YuvMatrix::BT601.rgb8_to_yuv8([255; 3], SDL_ColorRange::FULL);
+        assert_eq!(white, [255, 128, 128]);
+    }
+}
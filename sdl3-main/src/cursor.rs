@@ -0,0 +1,382 @@
+//! Owned mouse cursors and a guard for temporarily changing the active cursor.
+//!
+//! Monochrome cursors can be drawn as ASCII art, one string per row:
+//!
+//! | Character      | Pixel                                  |
+//! | -------------- | -------------------------------------- |
+//! | `X` or `#`     | black                                  |
+//! | `.`            | white                                  |
+//! | space          | transparent                            |
+//! | `o`            | inverted, if the platform supports it  |
+//!
+//! ```
+//! use sdl3_main::cursor::MonoCursorData;
+//!
+//! let data = MonoCursorData::from_ascii(&[
+//!     "X       ",
+//!     "XX      ",
+//!     "X.X     ",
+//!     "XXXX    ",
+//! ])
+//! .unwrap();
+//! assert_eq!((data.width(), data.height()), (8, 4));
+//! assert_eq!(data.data()[2], 0b1010_0000);
+//! assert_eq!(data.mask()[2], 0b1110_0000);
+//! ```
+//!
+//! Call [`Cursor::push`] to make a cursor active until the returned [`CursorGuard`] is
+//! dropped. Guards can be nested with [`CursorGuard::push`], so a UI widget can change
+//! the cursor while hovered and have the previous one restored when it's done.
+
+use crate::{error::check, surface::Surface, Error, Result};
+use alloc::vec::Vec;
+use core::{
+    fmt::{self, Display},
+    marker::PhantomData,
+    ptr::NonNull,
+};
+use sdl3_sys::{
+    mouse::{
+        SDL_CreateColorCursor, SDL_CreateCursor, SDL_CreateSystemCursor, SDL_Cursor,
+        SDL_DestroyCursor, SDL_GetCursor, SDL_GetDefaultCursor, SDL_SetCursor, SDL_SystemCursor,
+    },
+    pixels::SDL_PIXELFORMAT_RGBA32,
+};
+
+/// Packed bitmaps for a monochrome cursor, in the format [`SDL_CreateCursor`] takes.
+///
+/// Each row is padded to a multiple of 8 pixels with transparent pixels.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MonoCursorData {
+    data: Vec<u8>,
+    mask: Vec<u8>,
+    width: i32,
+    height: i32,
+}
+
+impl MonoCursorData {
+    /// Pack ASCII art into cursor bitmaps. See the [module documentation](self) for the
+    /// characters. Rows can have different lengths; short rows are padded with transparent
+    /// pixels.
+    pub fn from_ascii(rows: &[&str]) -> Result<Self, CursorArtError> {
+        let err = |row, column, kind| CursorArtError { row, column, kind };
+        if rows.is_empty() {
+            return Err(err(0, 0, CursorArtErrorKind::Empty));
+        }
+        let max_width = rows.iter().map(|r| r.chars().count()).max().unwrap_or(0);
+        if max_width == 0 {
+            return Err(err(0, 0, CursorArtErrorKind::Empty));
+        }
+        let row_bytes = max_width.div_ceil(8);
+        let (Ok(width), Ok(height)) = (i32::try_from(row_bytes * 8), i32::try_from(rows.len()))
+        else {
+            return Err(err(0, 0, CursorArtErrorKind::TooLarge));
+        };
+        let mut data = alloc::vec![0; row_bytes * rows.len()];
+        let mut mask = alloc::vec![0; row_bytes * rows.len()];
+        for (y, row) in rows.iter().enumerate() {
+            for (x, ch) in row.chars().enumerate() {
+                let (d, m) = match ch {
+                    'X' | '#' => (true, true),
+                    '.' => (false, true),
+                    ' ' => (false, false),
+                    'o' => (true, false),
+                    _ => return Err(err(y, x, CursorArtErrorKind::InvalidChar(ch))),
+                };
+                let i = y * row_bytes + x / 8;
+                let bit = 0x80 >> (x % 8);
+                if d {
+                    data[i] |= bit;
+                }
+                if m {
+                    mask[i] |= bit;
+                }
+            }
+        }
+        Ok(Self {
+            data,
+            mask,
+            width,
+            height,
+        })
+    }
+
+    /// Get the width in pixels. This is always a multiple of 8.
+    #[inline(always)]
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    /// Get the height in pixels.
+    #[inline(always)]
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    /// Get the data bitmap, one bit per pixel, most significant bit first.
+    #[inline(always)]
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Get the mask bitmap, one bit per pixel, most significant bit first.
+    #[inline(always)]
+    pub fn mask(&self) -> &[u8] {
+        &self.mask
+    }
+}
+
+/// An owned cursor. The cursor is destroyed when this is dropped.
+///
+/// Cursors must only be used on the main thread, so this is neither `Send` nor `Sync`.
+pub struct Cursor {
+    ptr: NonNull<SDL_Cursor>,
+    _not_send_sync: PhantomData<*const ()>,
+}
+
+impl Cursor {
+    /// Create a monochrome cursor. See [`SDL_CreateCursor`].
+    pub fn from_mono(data: &MonoCursorData, hot_x: i32, hot_y: i32) -> Result<Self> {
+        unsafe {
+            Self::from_created(SDL_CreateCursor(
+                data.data.as_ptr(),
+                data.mask.as_ptr(),
+                data.width,
+                data.height,
+                hot_x,
+                hot_y,
+            ))
+        }
+    }
+
+    /// Create a monochrome cursor from ASCII art. See [`MonoCursorData::from_ascii`].
+    pub fn from_ascii(rows: &[&str], hot_x: i32, hot_y: i32) -> Result<Self> {
+        Self::from_mono(&MonoCursorData::from_ascii(rows)?, hot_x, hot_y)
+    }
+
+    /// Create a color cursor from a surface. See [`SDL_CreateColorCursor`].
+    pub fn from_surface(surface: &Surface, hot_x: i32, hot_y: i32) -> Result<Self> {
+        unsafe { Self::from_created(SDL_CreateColorCursor(surface.as_ptr(), hot_x, hot_y)) }
+    }
+
+    /// Create a color cursor from RGBA pixels with 8 bits per channel, in row order without
+    /// padding.
+    pub fn from_rgba(
+        width: i32,
+        height: i32,
+        pixels: &[u8],
+        hot_x: i32,
+        hot_y: i32,
+    ) -> Result<Self> {
+        let row_len = usize::try_from(width).unwrap_or(0) * 4;
+        if row_len == 0 || pixels.len() != row_len * usize::try_from(height).unwrap_or(0) {
+            return Err(Error::new("pixel data doesn't match the cursor size"));
+        }
+        let mut surface = Surface::new(width, height, SDL_PIXELFORMAT_RGBA32)?;
+        {
+            let mut lock = surface.lock()?;
+            for (y, src) in pixels.chunks_exact(row_len).enumerate() {
+                if let Some(dst) = lock.row_mut(y) {
+                    dst[..row_len].copy_from_slice(src);
+                }
+            }
+        }
+        Self::from_surface(&surface, hot_x, hot_y)
+    }
+
+    /// Create a system cursor. See [`SDL_CreateSystemCursor`].
+    pub fn system(id: SDL_SystemCursor) -> Result<Self> {
+        unsafe { Self::from_created(SDL_CreateSystemCursor(id)) }
+    }
+
+    unsafe fn from_created(ptr: *mut SDL_Cursor) -> Result<Self> {
+        match NonNull::new(ptr) {
+            Some(ptr) => Ok(unsafe { Self::from_raw(ptr) }),
+            None => Err(Error::from_sdl()),
+        }
+    }
+
+    /// Take ownership of a cursor.
+    ///
+    /// # Safety
+    /// `ptr` must be a valid cursor that isn't owned by anything else
+    #[inline(always)]
+    pub unsafe fn from_raw(ptr: NonNull<SDL_Cursor>) -> Self {
+        Self {
+            ptr,
+            _not_send_sync: PhantomData,
+        }
+    }
+
+    /// Get the raw pointer to the cursor.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *mut SDL_Cursor {
+        self.ptr.as_ptr()
+    }
+
+    /// Make this the active cursor until the returned guard is dropped, at which point the
+    /// previously active cursor is restored. See [`SDL_SetCursor`] and [`CursorGuard`].
+    pub fn push(&self) -> Result<CursorGuard<'_>> {
+        CursorGuard::new(self)
+    }
+}
+
+impl fmt::Debug for Cursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Cursor").field(&self.ptr).finish()
+    }
+}
+
+impl Drop for Cursor {
+    fn drop(&mut self) {
+        unsafe { SDL_DestroyCursor(self.as_ptr()) };
+    }
+}
+
+/// Keeps a cursor active. The previous cursor is restored when this is dropped, or the
+/// default cursor if the previous one has been destroyed since.
+///
+/// Nested guards borrow their parent, so they're always dropped in the right order. Guards
+/// from separate calls to [`Cursor::push`] aren't tied to each other though, so if they're
+/// dropped out of order, a cursor that's no longer pushed is restored. Keep a single
+/// top-level guard and nest the others with [`CursorGuard::push`] to avoid this.
+#[derive(Debug)]
+#[must_use = "the previous cursor is restored when the guard is dropped"]
+pub struct CursorGuard<'a> {
+    previous: *mut SDL_Cursor,
+    _cursor: PhantomData<&'a Cursor>,
+}
+
+impl<'a> CursorGuard<'a> {
+    fn new(cursor: &'a Cursor) -> Result<Self> {
+        let mut previous = unsafe { SDL_GetCursor() };
+        if previous.is_null() {
+            previous = unsafe { SDL_GetDefaultCursor() };
+        }
+        check(unsafe { SDL_SetCursor(cursor.as_ptr()) })?;
+        Ok(Self {
+            previous,
+            _cursor: PhantomData,
+        })
+    }
+
+    /// Make another cursor active until the returned guard is dropped, at which point this
+    /// guard's cursor is restored.
+    pub fn push<'b>(&'b mut self, cursor: &'b Cursor) -> Result<CursorGuard<'b>> {
+        CursorGuard::new(cursor)
+    }
+}
+
+impl Drop for CursorGuard<'_> {
+    fn drop(&mut self) {
+        // SDL refuses cursors that no longer exist
+        if !unsafe { SDL_SetCursor(self.previous) } {
+            unsafe { SDL_SetCursor(SDL_GetDefaultCursor()) };
+        }
+    }
+}
+
+/// An error from parsing cursor ASCII art.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CursorArtError {
+    /// Row where the error was detected
+    pub row: usize,
+
+    /// Column (in characters) where the error was detected
+    pub column: usize,
+
+    /// What went wrong
+    pub kind: CursorArtErrorKind,
+}
+
+impl Display for CursorArtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at row {}, column {}",
+            self.kind, self.row, self.column
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CursorArtError {}
+
+impl From<CursorArtError> for Error {
+    fn from(value: CursorArtError) -> Self {
+        Error::new(alloc::format!("invalid cursor art: {value}"))
+    }
+}
+
+/// The kind of a [`CursorArtError`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CursorArtErrorKind {
+    /// There are no pixels
+    Empty,
+
+    /// The cursor is too large
+    TooLarge,
+
+    /// A character isn't one of the supported pixel characters
+    InvalidChar(char),
+}
+
+impl Display for CursorArtErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty cursor"),
+            Self::TooLarge => f.write_str("cursor too large"),
+            Self::InvalidChar(ch) => write!(f, "invalid character {ch:?}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn padding() {
+        let data = MonoCursorData::from_ascii(&["X", "", "o.#X .X.X"]).unwrap();
+        assert_eq!((data.width(), data.height()), (16, 3));
+        assert_eq!(data.data(), [0x80, 0, 0, 0, 0b1011_0010, 0x80]);
+        assert_eq!(data.mask(), [0x80, 0, 0, 0, 0b0111_0111, 0x80]);
+
+        let data = MonoCursorData::from_ascii(&["........"]).unwrap();
+        assert_eq!((data.width(), data.height()), (8, 1));
+        assert_eq!(data.data(), [0]);
+        assert_eq!(data.mask(), [0xff]);
+    }
+
+    #[test]
+    fn errors() {
+        let err = |rows: &[&str]| MonoCursorData::from_ascii(rows).unwrap_err();
+        assert_eq!(
+            err(&["XX", "X.", "X?"]),
+            CursorArtError {
+                row: 2,
+                column: 1,
+                kind: CursorArtErrorKind::InvalidChar('?'),
+            }
+        );
+        // columns count characters, not bytes
+        assert_eq!(
+            err(&["é"]),
+            CursorArtError {
+                row: 0,
+                column: 0,
+                kind: CursorArtErrorKind::InvalidChar('é'),
+            }
+        );
+        assert_eq!(
+            err(&[" .ü"]),
+            CursorArtError {
+                row: 0,
+                column: 2,
+                kind: CursorArtErrorKind::InvalidChar('ü'),
+            }
+        );
+        assert_eq!(err(&[]).kind, CursorArtErrorKind::Empty);
+        assert_eq!(err(&["", ""]).kind, CursorArtErrorKind::Empty);
+    }
+}
@@ -0,0 +1,793 @@
+//! Displays and display modes from [`sdl3_sys::video`].
+//!
+//! [`Display`] wraps an [`SDL_DisplayID`] and copies what SDL returns into owned values, so
+//! the arrays SDL allocates are freed for you. [`ModeRequest`] picks the best of a set of
+//! [`DisplayMode`]s, and [`DisplayTracker`] keeps a list of connected displays up to date
+//! from `SDL_EVENT_DISPLAY_*` events.
+//!
+//! Mode selection doesn't call SDL, so it works with modes made by hand too:
+//!
+//! ```
+//! use sdl3_main::display::{DisplayMode, ModeRequest};
+//!
+//! let modes = [
+//!     DisplayMode::new(1280, 720, 60.0, 1.0),
+//!     DisplayMode::new(1920, 1080, 60.0, 1.0),
+//!     DisplayMode::new(1920, 1080, 144.0, 1.0),
+//!     DisplayMode::new(2560, 1440, 60.0, 1.0),
+//! ];
+//!
+//! let best = ModeRequest::new()
+//!     .size(1920, 1080)
+//!     .refresh_rate(120.0)
+//!     .best(modes.iter().cloned())
+//!     .unwrap();
+//! assert_eq!((best.width(), best.height(), best.refresh_rate()), (1920, 1080, 144.0));
+//!
+//! // without preferences the largest and fastest mode wins
+//! let best = ModeRequest::new().best(modes.iter().cloned()).unwrap();
+//! assert_eq!((best.width(), best.height()), (2560, 1440));
+//!
+//! // if nothing is large enough, the largest smaller mode is used
+//! let best = ModeRequest::new().size(3840, 2160).best(modes.iter().cloned()).unwrap();
+//! assert_eq!((best.width(), best.height()), (2560, 1440));
+//! ```
+
+use crate::{
+    error::check,
+    rect::{Point, Rect},
+    Error, Result,
+};
+use alloc::{string::String, vec::Vec};
+use core::{
+    cmp::Ordering,
+    ffi::{c_void, CStr},
+    fmt,
+    iter::FusedIterator,
+    mem::MaybeUninit,
+    ptr, slice,
+};
+use sdl3_sys::{
+    events::{
+        SDL_DisplayEvent, SDL_Event, SDL_EventType, SDL_EVENT_DISPLAY_ADDED,
+        SDL_EVENT_DISPLAY_CONTENT_SCALE_CHANGED, SDL_EVENT_DISPLAY_CURRENT_MODE_CHANGED,
+        SDL_EVENT_DISPLAY_DESKTOP_MODE_CHANGED, SDL_EVENT_DISPLAY_MOVED,
+        SDL_EVENT_DISPLAY_ORIENTATION, SDL_EVENT_DISPLAY_REMOVED,
+    },
+    pixels::{SDL_PixelFormat, SDL_PIXELFORMAT_UNKNOWN},
+    rect::SDL_Rect,
+    stdinc::SDL_free,
+    video::{
+        SDL_DisplayID, SDL_DisplayMode, SDL_DisplayOrientation,
+        SDL_GetClosestFullscreenDisplayMode, SDL_GetCurrentDisplayMode,
+        SDL_GetCurrentDisplayOrientation, SDL_GetDesktopDisplayMode, SDL_GetDisplayBounds,
+        SDL_GetDisplayContentScale, SDL_GetDisplayForPoint, SDL_GetDisplayForRect,
+        SDL_GetDisplayName, SDL_GetDisplayUsableBounds, SDL_GetDisplays,
+        SDL_GetFullscreenDisplayModes, SDL_GetNaturalDisplayOrientation, SDL_GetPrimaryDisplay,
+    },
+};
+
+/// A display, identified by its [`SDL_DisplayID`].
+///
+/// The ID stays the same while the display is connected. Queries fail once it's removed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Display(pub SDL_DisplayID);
+
+impl Display {
+    fn from_id(id: SDL_DisplayID) -> Result<Self> {
+        if id == 0 {
+            Err(Error::from_sdl())
+        } else {
+            Ok(Self(id))
+        }
+    }
+
+    /// Get the currently connected displays. See [`SDL_GetDisplays`].
+    pub fn all() -> Result<Vec<Display>> {
+        let mut count = 0;
+        let list = unsafe { SDL_GetDisplays(&mut count) };
+        if list.is_null() {
+            return Err(Error::from_sdl());
+        }
+        let displays = unsafe { slice::from_raw_parts(list, count as usize) }
+            .iter()
+            .map(|&id| Display(id))
+            .collect();
+        unsafe { SDL_free(list as *mut c_void) };
+        Ok(displays)
+    }
+
+    /// Get the primary display. See [`SDL_GetPrimaryDisplay`].
+    pub fn primary() -> Result<Display> {
+        Self::from_id(unsafe { SDL_GetPrimaryDisplay() })
+    }
+
+    /// Get the display containing a point. See [`SDL_GetDisplayForPoint`].
+    pub fn for_point(point: Point) -> Result<Display> {
+        Self::from_id(unsafe { SDL_GetDisplayForPoint(point.as_sdl()) })
+    }
+
+    /// Get the display that most of a rectangle is on. See [`SDL_GetDisplayForRect`].
+    pub fn for_rect(rect: &Rect) -> Result<Display> {
+        Self::from_id(unsafe { SDL_GetDisplayForRect(rect.as_sdl()) })
+    }
+
+    /// Get the display ID.
+    #[inline(always)]
+    pub fn id(self) -> SDL_DisplayID {
+        self.0
+    }
+
+    /// Get the name of the display.
+    pub fn name(self) -> Result<String> {
+        let name = unsafe { SDL_GetDisplayName(self.0) };
+        if name.is_null() {
+            return Err(Error::from_sdl());
+        }
+        Ok(unsafe { CStr::from_ptr(name) }
+            .to_string_lossy()
+            .into_owned())
+    }
+
+    /// Get the desktop area of the display. See [`SDL_GetDisplayBounds`].
+    pub fn bounds(self) -> Result<Rect> {
+        let mut rect = SDL_Rect {
+            x: 0,
+            y: 0,
+            w: 0,
+            h: 0,
+        };
+        check(unsafe { SDL_GetDisplayBounds(self.0, &mut rect) })?;
+        Ok(rect.into())
+    }
+
+    /// Get the desktop area of the display that isn't used by the system, like menu bars.
+    /// See [`SDL_GetDisplayUsableBounds`].
+    pub fn usable_bounds(self) -> Result<Rect> {
+        let mut rect = SDL_Rect {
+            x: 0,
+            y: 0,
+            w: 0,
+            h: 0,
+        };
+        check(unsafe { SDL_GetDisplayUsableBounds(self.0, &mut rect) })?;
+        Ok(rect.into())
+    }
+
+    /// Get the content scale of the display. See [`SDL_GetDisplayContentScale`].
+    pub fn content_scale(self) -> Result<f32> {
+        let scale = unsafe { SDL_GetDisplayContentScale(self.0) };
+        if scale == 0.0 {
+            Err(Error::from_sdl())
+        } else {
+            Ok(scale)
+        }
+    }
+
+    /// Get the orientation of the display when it's unrotated.
+    #[inline]
+    pub fn natural_orientation(self) -> SDL_DisplayOrientation {
+        unsafe { SDL_GetNaturalDisplayOrientation(self.0) }
+    }
+
+    /// Get the current orientation of the display.
+    #[inline]
+    pub fn current_orientation(self) -> SDL_DisplayOrientation {
+        unsafe { SDL_GetCurrentDisplayOrientation(self.0) }
+    }
+
+    /// Get the display mode of the desktop. See [`SDL_GetDesktopDisplayMode`].
+    pub fn desktop_mode(self) -> Result<DisplayMode> {
+        unsafe { DisplayMode::from_ptr(SDL_GetDesktopDisplayMode(self.0)) }
+    }
+
+    /// Get the current display mode, which differs from the desktop mode when a window is
+    /// fullscreen with another mode. See [`SDL_GetCurrentDisplayMode`].
+    pub fn current_mode(self) -> Result<DisplayMode> {
+        unsafe { DisplayMode::from_ptr(SDL_GetCurrentDisplayMode(self.0)) }
+    }
+
+    /// Get the fullscreen modes the display supports, from largest to smallest.
+    /// See [`SDL_GetFullscreenDisplayModes`].
+    pub fn fullscreen_modes(self) -> Result<DisplayModes> {
+        let mut count = 0;
+        let list = unsafe { SDL_GetFullscreenDisplayModes(self.0, &mut count) };
+        if list.is_null() {
+            return Err(Error::from_sdl());
+        }
+        Ok(DisplayModes {
+            list,
+            front: 0,
+            back: count as usize,
+        })
+    }
+
+    /// Let SDL pick the fullscreen mode closest to the given size and refresh rate.
+    /// See [`SDL_GetClosestFullscreenDisplayMode`].
+    pub fn closest_fullscreen_mode(
+        self,
+        width: i32,
+        height: i32,
+        refresh_rate: f32,
+        include_high_density_modes: bool,
+    ) -> Result<DisplayMode> {
+        let mut mode = MaybeUninit::uninit();
+        check(unsafe {
+            SDL_GetClosestFullscreenDisplayMode(
+                self.0,
+                width,
+                height,
+                refresh_rate,
+                include_high_density_modes,
+                mode.as_mut_ptr(),
+            )
+        })?;
+        Ok(DisplayMode(unsafe { mode.assume_init() }))
+    }
+
+    /// Pick the fullscreen mode that best matches `request`, or `None` if the display
+    /// has no fullscreen modes.
+    pub fn best_fullscreen_mode(self, request: &ModeRequest) -> Result<Option<DisplayMode>> {
+        Ok(request.best(self.fullscreen_modes()?))
+    }
+}
+
+/// A display mode. This is an owned copy of an [`SDL_DisplayMode`].
+pub struct DisplayMode(SDL_DisplayMode);
+
+impl DisplayMode {
+    /// Create a display mode that isn't associated with a display. This is mostly useful
+    /// for testing mode selection; SDL only accepts modes it returned itself.
+    pub const fn new(width: i32, height: i32, refresh_rate: f32, pixel_density: f32) -> Self {
+        Self(SDL_DisplayMode {
+            displayID: 0,
+            format: SDL_PIXELFORMAT_UNKNOWN,
+            w: width,
+            h: height,
+            pixel_density,
+            refresh_rate,
+            refresh_rate_numerator: 0,
+            refresh_rate_denominator: 0,
+            internal: ptr::null_mut(),
+        })
+    }
+
+    /// Copy a display mode from SDL.
+    ///
+    /// # Safety
+    /// `mode` must be null or point to a valid display mode
+    pub(crate) unsafe fn from_ptr(mode: *const SDL_DisplayMode) -> Result<Self> {
+        if mode.is_null() {
+            Err(Error::from_sdl())
+        } else {
+            Ok(Self(unsafe { ptr::read(mode) }))
+        }
+    }
+
+    /// Wrap a raw display mode.
+    #[inline(always)]
+    pub const fn from_raw(mode: SDL_DisplayMode) -> Self {
+        Self(mode)
+    }
+
+    /// Get the raw display mode.
+    #[inline(always)]
+    pub const fn as_raw(&self) -> &SDL_DisplayMode {
+        &self.0
+    }
+
+    /// Get the display this mode belongs to.
+    #[inline(always)]
+    pub const fn display(&self) -> Display {
+        Display(self.0.displayID)
+    }
+
+    /// Get the pixel format.
+    #[inline(always)]
+    pub const fn format(&self) -> SDL_PixelFormat {
+        self.0.format
+    }
+
+    /// Get the width in points.
+    #[inline(always)]
+    pub const fn width(&self) -> i32 {
+        self.0.w
+    }
+
+    /// Get the height in points.
+    #[inline(always)]
+    pub const fn height(&self) -> i32 {
+        self.0.h
+    }
+
+    /// Get the scale from points to pixels.
+    #[inline(always)]
+    pub const fn pixel_density(&self) -> f32 {
+        self.0.pixel_density
+    }
+
+    /// Get the size in pixels.
+    pub fn size_in_pixels(&self) -> (i32, i32) {
+        (
+            (self.0.w as f32 * self.0.pixel_density) as i32,
+            (self.0.h as f32 * self.0.pixel_density) as i32,
+        )
+    }
+
+    /// Get the refresh rate in Hz, or `0.0` if it's unspecified.
+    #[inline(always)]
+    pub const fn refresh_rate(&self) -> f32 {
+        self.0.refresh_rate
+    }
+
+    /// Get the precise refresh rate as a fraction, if it's specified.
+    pub const fn refresh_rate_fraction(&self) -> Option<(i32, i32)> {
+        if self.0.refresh_rate_numerator != 0 && self.0.refresh_rate_denominator != 0 {
+            Some((
+                self.0.refresh_rate_numerator,
+                self.0.refresh_rate_denominator,
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+impl Clone for DisplayMode {
+    fn clone(&self) -> Self {
+        // plain data; the internal pointer is owned by SDL and can be shared
+        Self(unsafe { ptr::read(&self.0) })
+    }
+}
+
+impl PartialEq for DisplayMode {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.displayID == other.0.displayID
+            && self.0.format == other.0.format
+            && self.0.w == other.0.w
+            && self.0.h == other.0.h
+            && self.0.pixel_density == other.0.pixel_density
+            && self.0.refresh_rate == other.0.refresh_rate
+            && self.0.refresh_rate_numerator == other.0.refresh_rate_numerator
+            && self.0.refresh_rate_denominator == other.0.refresh_rate_denominator
+    }
+}
+
+impl fmt::Debug for DisplayMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DisplayMode")
+            .field("display", &self.0.displayID)
+            .field("format", &self.0.format.0)
+            .field("width", &self.0.w)
+            .field("height", &self.0.h)
+            .field("pixel_density", &self.0.pixel_density)
+            .field("refresh_rate", &self.0.refresh_rate)
+            .finish()
+    }
+}
+
+impl From<DisplayMode> for SDL_DisplayMode {
+    #[inline(always)]
+    fn from(value: DisplayMode) -> Self {
+        value.0
+    }
+}
+
+/// Iterator over the fullscreen modes of a display, returned from
+/// [`Display::fullscreen_modes`]. Frees the list when dropped.
+pub struct DisplayModes {
+    list: *mut *mut SDL_DisplayMode,
+    front: usize,
+    back: usize,
+}
+
+impl DisplayModes {
+    fn get(&self, index: usize) -> DisplayMode {
+        DisplayMode(unsafe { ptr::read(*self.list.add(index)) })
+    }
+}
+
+impl Iterator for DisplayModes {
+    type Item = DisplayMode;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        (self.front < self.back).then(|| {
+            self.front += 1;
+            self.get(self.front - 1)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for DisplayModes {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        (self.front < self.back).then(|| {
+            self.back -= 1;
+            self.get(self.back)
+        })
+    }
+}
+
+impl ExactSizeIterator for DisplayModes {}
+impl FusedIterator for DisplayModes {}
+
+impl fmt::Debug for DisplayModes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DisplayModes")
+            .field("remaining", &(self.back - self.front))
+            .finish()
+    }
+}
+
+impl Drop for DisplayModes {
+    fn drop(&mut self) {
+        unsafe { SDL_free(self.list as *mut c_void) };
+    }
+}
+
+/// Preferences for picking a display mode with [`ModeRequest::best`].
+///
+/// Modes are compared by size first, then refresh rate, then pixel density:
+///
+/// - Size: the smallest mode that's at least as large in both dimensions is best, so an exact
+///   match wins. Then the largest mode that's smaller in both dimensions, then the largest of
+///   the rest. Without a preference, larger is better.
+/// - Refresh rate: closest is best, and the higher one wins a tie. Without a preference,
+///   higher is better.
+/// - Pixel density: closest is best, and the higher one wins a tie. Without a preference,
+///   higher is better.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ModeRequest {
+    size: Option<(i32, i32)>,
+    refresh_rate: Option<f32>,
+    pixel_density: Option<f32>,
+}
+
+impl ModeRequest {
+    /// Create a new `ModeRequest` without preferences.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            size: None,
+            refresh_rate: None,
+            pixel_density: None,
+        }
+    }
+
+    /// Prefer modes of this size, in points.
+    #[inline]
+    pub const fn size(mut self, width: i32, height: i32) -> Self {
+        self.size = Some((width, height));
+        self
+    }
+
+    /// Prefer modes with this refresh rate, in Hz.
+    #[inline]
+    pub const fn refresh_rate(mut self, refresh_rate: f32) -> Self {
+        self.refresh_rate = Some(refresh_rate);
+        self
+    }
+
+    /// Prefer modes with this pixel density.
+    #[inline]
+    pub const fn pixel_density(mut self, pixel_density: f32) -> Self {
+        self.pixel_density = Some(pixel_density);
+        self
+    }
+
+    /// Compare two modes. [`Ordering::Less`] means `a` is a better match than `b`.
+    pub fn compare(&self, a: &DisplayMode, b: &DisplayMode) -> Ordering {
+        self.size_key(a)
+            .cmp(&self.size_key(b))
+            .then_with(|| closest(self.refresh_rate, a.refresh_rate(), b.refresh_rate()))
+            .then_with(|| closest(self.pixel_density, a.pixel_density(), b.pixel_density()))
+    }
+
+    /// Get the mode that best matches this request, or `None` if there are no modes.
+    /// If several modes match equally well, the first one is returned.
+    pub fn best(&self, modes: impl IntoIterator<Item = DisplayMode>) -> Option<DisplayMode> {
+        modes.into_iter().fold(None, |best, mode| match best {
+            Some(best) if self.compare(&best, &mode).is_le() => Some(best),
+            _ => Some(mode),
+        })
+    }
+
+    // smaller is better
+    fn size_key(&self, mode: &DisplayMode) -> (u8, i64) {
+        let (mw, mh) = (mode.width(), mode.height());
+        let area = mw as i64 * mh as i64;
+        match self.size {
+            Some((w, h)) if mw >= w && mh >= h => (0, area),
+            Some((w, h)) if mw <= w && mh <= h => (1, -area),
+            Some(_) => (2, -area),
+            None => (0, -area),
+        }
+    }
+}
+
+// `Less` if `a` is closer to `target`, or higher if there's no target or it's a tie
+fn closest(target: Option<f32>, a: f32, b: f32) -> Ordering {
+    let by_distance = match target {
+        Some(t) => (a - t).abs().total_cmp(&(b - t).abs()),
+        None => Ordering::Equal,
+    };
+    by_distance.then_with(|| b.total_cmp(&a))
+}
+
+/// Cached information about a connected display. See [`DisplayTracker`].
+#[derive(Clone)]
+pub struct DisplayInfo {
+    /// The display
+    pub display: Display,
+
+    /// Name of the display
+    pub name: String,
+
+    /// Desktop area of the display
+    pub bounds: Rect,
+
+    /// Desktop area that isn't used by the system
+    pub usable_bounds: Rect,
+
+    /// Content scale
+    pub content_scale: f32,
+
+    /// Current orientation
+    pub orientation: SDL_DisplayOrientation,
+
+    /// Desktop display mode
+    pub desktop_mode: Option<DisplayMode>,
+
+    /// Current display mode
+    pub current_mode: Option<DisplayMode>,
+}
+
+impl DisplayInfo {
+    /// Query SDL for information about a display.
+    pub fn query(display: Display) -> Result<Self> {
+        Ok(Self {
+            display,
+            name: display.name()?,
+            bounds: display.bounds()?,
+            usable_bounds: display.usable_bounds()?,
+            content_scale: display.content_scale().unwrap_or(1.0),
+            orientation: display.current_orientation(),
+            desktop_mode: display.desktop_mode().ok(),
+            current_mode: display.current_mode().ok(),
+        })
+    }
+}
+
+impl fmt::Debug for DisplayInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DisplayInfo")
+            .field("display", &self.display)
+            .field("name", &self.name)
+            .field("bounds", &self.bounds)
+            .field("usable_bounds", &self.usable_bounds)
+            .field("content_scale", &self.content_scale)
+            .field("orientation", &self.orientation.0)
+            .field("desktop_mode", &self.desktop_mode)
+            .field("current_mode", &self.current_mode)
+            .finish()
+    }
+}
+
+/// A change reported by [`DisplayTracker::display_event`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DisplayChange {
+    /// A display was connected
+    Added(Display),
+
+    /// A display was disconnected
+    Removed(Display),
+
+    /// A display was moved, rotated, or changed mode or content scale
+    Changed(Display),
+}
+
+/// Keeps a list of connected displays and their properties up to date.
+///
+/// Create it after the video subsystem is initialized and pass it display events with
+/// [`DisplayTracker::handle_event`].
+#[derive(Clone, Debug)]
+pub struct DisplayTracker {
+    displays: Vec<DisplayInfo>,
+    primary: Option<Display>,
+}
+
+impl DisplayTracker {
+    /// Create a tracker with the currently connected displays.
+    pub fn new() -> Result<Self> {
+        let mut tracker = Self {
+            displays: Vec::new(),
+            primary: None,
+        };
+        tracker.refresh()?;
+        Ok(tracker)
+    }
+
+    /// Query all displays again.
+    pub fn refresh(&mut self) -> Result<()> {
+        self.displays = Display::all()?
+            .into_iter()
+            .map(DisplayInfo::query)
+            .collect::<Result<_>>()?;
+        self.primary = Display::primary().ok();
+        Ok(())
+    }
+
+    /// Get the connected displays, in the order SDL reported them.
+    #[inline]
+    pub fn displays(&self) -> &[DisplayInfo] {
+        &self.displays
+    }
+
+    /// Get the cached information for a display.
+    pub fn get(&self, display: Display) -> Option<&DisplayInfo> {
+        self.displays.iter().find(|info| info.display == display)
+    }
+
+    /// Get the primary display.
+    #[inline]
+    pub fn primary(&self) -> Option<Display> {
+        self.primary
+    }
+
+    /// Update the tracker from a display event. Returns what changed, or `None` if the event
+    /// isn't a display event or refers to a display that's already gone.
+    pub fn display_event(&mut self, event: &SDL_DisplayEvent) -> Option<DisplayChange> {
+        let display = Display(event.displayID);
+        let index = self.displays.iter().position(|i| i.display == display);
+        let change = match event.r#type {
+            SDL_EVENT_DISPLAY_REMOVED => {
+                self.displays.remove(index?);
+                DisplayChange::Removed(display)
+            }
+            SDL_EVENT_DISPLAY_ADDED
+            | SDL_EVENT_DISPLAY_ORIENTATION
+            | SDL_EVENT_DISPLAY_MOVED
+            | SDL_EVENT_DISPLAY_DESKTOP_MODE_CHANGED
+            | SDL_EVENT_DISPLAY_CURRENT_MODE_CHANGED
+            | SDL_EVENT_DISPLAY_CONTENT_SCALE_CHANGED => {
+                // the display may already be gone if events are handled late
+                let info = DisplayInfo::query(display).ok()?;
+                match index {
+                    Some(index) => {
+                        self.displays[index] = info;
+                        DisplayChange::Changed(display)
+                    }
+                    None => {
+                        self.displays.push(info);
+                        DisplayChange::Added(display)
+                    }
+                }
+            }
+            _ => return None,
+        };
+        self.primary = Display::primary().ok();
+        Some(change)
+    }
+
+    /// Update the tracker from an event. See [`DisplayTracker::display_event`].
+    ///
+    /// # Safety
+    /// `event` must be a valid event, like the ones you get from SDL
+    pub unsafe fn handle_event(&mut self, event: &SDL_Event) -> Option<DisplayChange> {
+        match SDL_EventType(unsafe { event.r#type }) {
+            SDL_EVENT_DISPLAY_ADDED
+            | SDL_EVENT_DISPLAY_REMOVED
+            | SDL_EVENT_DISPLAY_ORIENTATION
+            | SDL_EVENT_DISPLAY_MOVED
+            | SDL_EVENT_DISPLAY_DESKTOP_MODE_CHANGED
+            | SDL_EVENT_DISPLAY_CURRENT_MODE_CHANGED
+            | SDL_EVENT_DISPLAY_CONTENT_SCALE_CHANGED => {
+                self.display_event(unsafe { &event.display })
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn best(request: ModeRequest, modes: &[DisplayMode]) -> (i32, i32, f32, f32) {
+        let mode = request.best(modes.iter().cloned()).unwrap();
+        (
+            mode.width(),
+            mode.height(),
+            mode.refresh_rate(),
+            mode.pixel_density(),
+        )
+    }
+
+    #[test]
+    fn size() {
+        let modes = [
+            DisplayMode::new(800, 600, 60.0, 1.0),
+            DisplayMode::new(1280, 720, 60.0, 1.0),
+            DisplayMode::new(1280, 1024, 60.0, 1.0),
+            DisplayMode::new(1920, 1080, 60.0, 1.0),
+            DisplayMode::new(2560, 1080, 60.0, 1.0),
+            DisplayMode::new(2560, 1440, 60.0, 1.0),
+        ];
+        let size = |w, h| {
+            let (w, h, ..) = best(ModeRequest::new().size(w, h), &modes);
+            (w, h)
+        };
+        assert_eq!(size(1920, 1080), (1920, 1080));
+        // smallest that fits
+        assert_eq!(size(1300, 700), (1920, 1080));
+        assert_eq!(size(1000, 1000), (1280, 1024));
+        // largest that's smaller in both dimensions, not the closest in area
+        assert_eq!(size(3840, 2160), (2560, 1440));
+        assert_eq!(size(1279, 1500), (800, 600));
+        // largest of the rest
+        assert_eq!(size(700, 1500), (2560, 1440));
+        assert_eq!(best(ModeRequest::new(), &modes).0, 2560);
+    }
+
+    #[test]
+    fn refresh_rate_and_density() {
+        let modes = [
+            DisplayMode::new(1920, 1080, 60.0, 1.0),
+            DisplayMode::new(1920, 1080, 144.0, 1.0),
+            DisplayMode::new(1920, 1080, 120.0, 2.0),
+            DisplayMode::new(1920, 1080, 100.0, 1.0),
+            DisplayMode::new(1920, 1080, 140.0, 1.0),
+        ];
+        assert_eq!(best(ModeRequest::new(), &modes).2, 144.0);
+        assert_eq!(best(ModeRequest::new().refresh_rate(90.0), &modes).2, 100.0);
+        // ties go to the higher rate
+        assert_eq!(
+            best(ModeRequest::new().refresh_rate(130.0), &modes).2,
+            140.0
+        );
+        assert_eq!(
+            best(ModeRequest::new().refresh_rate(120.0), &modes),
+            (1920, 1080, 120.0, 2.0)
+        );
+        assert_eq!(
+            best(
+                ModeRequest::new().refresh_rate(60.0).pixel_density(2.0),
+                &modes
+            )
+            .2,
+            60.0
+        );
+        // refresh rate is compared before density, even without a preference
+        assert_eq!(
+            best(ModeRequest::new().pixel_density(2.0), &modes),
+            (1920, 1080, 144.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn order() {
+        let modes = [
+            DisplayMode::new(1920, 1080, 60.0, 1.0),
+            DisplayMode::new(1280, 720, 144.0, 2.0),
+        ];
+        // size is compared before refresh rate and density
+        let request = ModeRequest::new()
+            .size(1280, 720)
+            .refresh_rate(60.0)
+            .pixel_density(1.0);
+        assert_eq!(best(request, &modes).0, 1280);
+
+        // equal modes keep the first one
+        let modes = [
+            DisplayMode::new(1920, 1080, 60.0, 1.0),
+            DisplayMode::new(1920, 1080, 60.0, 1.0),
+        ];
+        let mode = ModeRequest::new().best(modes.iter().cloned()).unwrap();
+        assert!(mode == modes[0]);
+        assert!(ModeRequest::new().best([]).is_none());
+    }
+}
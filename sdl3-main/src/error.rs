@@ -0,0 +1,96 @@
+use alloc::{borrow::ToOwned, string::String};
+use core::{
+    ffi::{c_int, CStr},
+    fmt::{self, Display},
+};
+use sdl3_sys::error::SDL_GetError;
+
+/// Result type for the wrappers in this crate.
+pub type Result<T, E = Error> = core::result::Result<T, E>;
+
+/// An error reported by SDL, or by one of the wrappers in this crate.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Error(String);
+
+impl Error {
+    /// Create a new error with a custom message.
+    #[inline]
+    pub fn new(message: impl Into<String>) -> Self {
+        Self(message.into())
+    }
+
+    /// Create an error from the current SDL error message of this thread
+    /// (see [`SDL_GetError`]).
+    pub fn from_sdl() -> Self {
+        let msg = unsafe { SDL_GetError() };
+        if msg.is_null() {
+            Self::new("unknown SDL error")
+        } else {
+            Self(
+                unsafe { CStr::from_ptr(msg) }
+                    .to_string_lossy()
+                    .into_owned(),
+            )
+        }
+    }
+
+    /// Get the error message.
+    #[inline(always)]
+    pub fn message(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for Error {
+    #[inline]
+    fn from(value: &str) -> Self {
+        Self(value.to_owned())
+    }
+}
+
+impl From<String> for Error {
+    #[inline(always)]
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// Turn the return value of an SDL function that returns `bool` into a `Result`.
+#[inline]
+pub(crate) fn check(ok: bool) -> Result<()> {
+    if ok {
+        Ok(())
+    } else {
+        Err(Error::from_sdl())
+    }
+}
+
+/// Convert a slice length to a `c_int` for passing to SDL.
+#[inline]
+pub(crate) fn len_to_c_int(len: usize) -> Result<c_int> {
+    c_int::try_from(len).map_err(|_| Error::new("too many elements"))
+}
+
+/// Call `f`, returning `default` if it panics. Panics can't unwind into SDL, so callbacks
+/// called by SDL use this. Without `std` a panic aborts instead.
+#[inline]
+pub(crate) fn guard<T>(default: T, f: impl FnOnce() -> T) -> T {
+    #[cfg(feature = "std")]
+    {
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)).unwrap_or(default)
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        let _ = default;
+        f()
+    }
+}
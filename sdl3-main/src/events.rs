@@ -0,0 +1,348 @@
+//! Event watches, filters and queue access from [`sdl3_sys::events`] with Rust closures.
+//!
+//! [`EventWatch`] calls a closure for every event added to the queue until it's dropped.
+//! Watches are called on the thread that pushed the event, which may not be the main
+//! thread, so the closure must be `Send + Sync`. Panics in the closure are caught before
+//! they reach SDL.
+//!
+//! ```no_run
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//! use std::sync::Arc;
+//! use sdl3_main::events::{peek_events, EventWatch};
+//! use sdl3_sys::events::{SDL_EVENT_WINDOW_FIRST, SDL_EVENT_WINDOW_LAST, SDL_EVENT_WINDOW_RESIZED};
+//!
+//! let resizes = Arc::new(AtomicUsize::new(0));
+//! let counter = resizes.clone();
+//! let watch = EventWatch::new(move |event| {
+//!     if unsafe { event.r#type } == SDL_EVENT_WINDOW_RESIZED.0 {
+//!         counter.fetch_add(1, Ordering::Relaxed);
+//!     }
+//!     true
+//! })?;
+//!
+//! let window_events = peek_events(SDL_EVENT_WINDOW_FIRST..=SDL_EVENT_WINDOW_LAST, 16)?;
+//! println!("{} window events queued", window_events.len());
+//! drop(watch);
+//! println!("{} resizes seen", resizes.load(Ordering::Relaxed));
+//! # Ok::<(), sdl3_main::Error>(())
+//! ```
+
+use crate::{
+    error::{check, guard},
+    Error, Result,
+};
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    ffi::{c_int, c_void},
+    fmt,
+    ops::RangeInclusive,
+    ptr::{self, NonNull},
+};
+use sdl3_sys::events::{
+    SDL_AddEventWatch, SDL_Event, SDL_EventAction, SDL_EventType, SDL_FilterEvents, SDL_PeepEvents,
+    SDL_RemoveEventWatch, SDL_ADDEVENT, SDL_GETEVENT, SDL_PEEKEVENT,
+};
+
+type WatchFn = dyn Fn(&SDL_Event) -> bool + Send + Sync;
+
+unsafe extern "C" fn watch_callback(userdata: *mut c_void, event: *mut SDL_Event) -> bool {
+    let f = unsafe { &*(userdata as *const Box<WatchFn>) };
+    guard(true, || f(unsafe { &*event }))
+}
+
+/// Calls a closure for every event added to the event queue. The watch is removed when
+/// this is dropped. See [`SDL_AddEventWatch`].
+///
+/// The return value of the closure is ignored by SDL for watches. It's a `bool` so the same
+/// closures can be used with [`set_event_filter`] and [`filter_events`].
+#[must_use = "the watch is removed when this is dropped"]
+pub struct EventWatch {
+    f: NonNull<Box<WatchFn>>,
+}
+
+// the closure is Send + Sync, and SDL serializes adding, removing and calling watches
+unsafe impl Send for EventWatch {}
+unsafe impl Sync for EventWatch {}
+
+impl EventWatch {
+    /// Add an event watch.
+    pub fn new(f: impl Fn(&SDL_Event) -> bool + Send + Sync + 'static) -> Result<Self> {
+        let f: Box<Box<WatchFn>> = Box::new(Box::new(f));
+        let f = NonNull::from(Box::leak(f));
+        if unsafe { SDL_AddEventWatch(Some(watch_callback), f.as_ptr() as *mut c_void) } {
+            Ok(Self { f })
+        } else {
+            drop(unsafe { Box::from_raw(f.as_ptr()) });
+            Err(Error::from_sdl())
+        }
+    }
+}
+
+impl fmt::Debug for EventWatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("EventWatch").field(&self.f).finish()
+    }
+}
+
+impl Drop for EventWatch {
+    fn drop(&mut self) {
+        // SDL won't call the watch again once this returns
+        unsafe { SDL_RemoveEventWatch(Some(watch_callback), self.f.as_ptr() as *mut c_void) };
+        drop(unsafe { Box::from_raw(self.f.as_ptr()) });
+    }
+}
+
+#[cfg(feature = "std")]
+static FILTER: std::sync::Mutex<Option<Box<Box<WatchFn>>>> = std::sync::Mutex::new(None);
+
+/// Set the event filter. Events are dropped from the queue if the filter returns `false`.
+/// This replaces any previous filter. See [`SDL_SetEventFilter`](sdl3_sys::events::SDL_SetEventFilter).
+///
+/// The filter may be called on any thread that adds events.
+#[cfg(feature = "std")]
+pub fn set_event_filter(f: impl Fn(&SDL_Event) -> bool + Send + Sync + 'static) {
+    let mut filter = FILTER.lock().unwrap();
+    let f: Box<Box<WatchFn>> = Box::new(Box::new(f));
+    unsafe {
+        sdl3_sys::events::SDL_SetEventFilter(
+            Some(watch_callback),
+            &*f as *const Box<WatchFn> as *mut c_void,
+        )
+    };
+    // SDL won't call the previous filter again, so it can be dropped now
+    *filter = Some(f);
+}
+
+/// Remove the filter set with [`set_event_filter`].
+#[cfg(feature = "std")]
+pub fn clear_event_filter() {
+    let mut filter = FILTER.lock().unwrap();
+    unsafe { sdl3_sys::events::SDL_SetEventFilter(None, core::ptr::null_mut()) };
+    *filter = None;
+}
+
+/// Run `f` on every event in the queue, removing the events it returns `false` for.
+/// See [`SDL_FilterEvents`].
+pub fn filter_events(mut f: impl FnMut(&SDL_Event) -> bool) {
+    unsafe extern "C" fn callback<F: FnMut(&SDL_Event) -> bool>(
+        userdata: *mut c_void,
+        event: *mut SDL_Event,
+    ) -> bool {
+        let f = unsafe { &mut *(userdata as *mut F) };
+        guard(true, || f(unsafe { &*event }))
+    }
+
+    fn filter<F: FnMut(&SDL_Event) -> bool>(f: &mut F) {
+        unsafe { SDL_FilterEvents(Some(callback::<F>), f as *mut F as *mut c_void) };
+    }
+
+    filter(&mut f)
+}
+
+fn peep(
+    events: &mut Vec<SDL_Event>,
+    max: usize,
+    action: SDL_EventAction,
+    types: RangeInclusive<SDL_EventType>,
+) -> Result<usize> {
+    let (min_type, max_type) = (types.start().0, types.end().0);
+    // without an array, SDL returns how many events match, so `max` can be clamped to that
+    // before allocating
+    let queued = unsafe { SDL_PeepEvents(ptr::null_mut(), 0, SDL_PEEKEVENT, min_type, max_type) };
+    if queued < 0 {
+        return Err(Error::from_sdl());
+    }
+    let max = max.min(queued as usize);
+    if max == 0 {
+        return Ok(0);
+    }
+    events.reserve(max);
+    let spare = events.spare_capacity_mut();
+    let count = unsafe {
+        SDL_PeepEvents(
+            spare.as_mut_ptr() as *mut SDL_Event,
+            spare.len().min(max) as c_int,
+            action,
+            min_type,
+            max_type,
+        )
+    };
+    if count < 0 {
+        return Err(Error::from_sdl());
+    }
+    let count = count as usize;
+    unsafe { events.set_len(events.len() + count) };
+    Ok(count)
+}
+
+/// Get up to `max` events with types in `types` from the front of the queue without
+/// removing them. See [`SDL_PeepEvents`].
+///
+/// This doesn't pump events; call `SDL_PumpEvents` first if needed.
+pub fn peek_events(types: RangeInclusive<SDL_EventType>, max: usize) -> Result<Vec<SDL_Event>> {
+    let mut events = Vec::new();
+    peep(&mut events, max, SDL_PEEKEVENT, types)?;
+    Ok(events)
+}
+
+/// Remove up to `max` events with types in `types` from the front of the queue and return
+/// them. See [`SDL_PeepEvents`].
+///
+/// This doesn't pump events; call `SDL_PumpEvents` first if needed.
+pub fn get_events(types: RangeInclusive<SDL_EventType>, max: usize) -> Result<Vec<SDL_Event>> {
+    let mut events = Vec::new();
+    peep(&mut events, max, SDL_GETEVENT, types)?;
+    Ok(events)
+}
+
+/// Like [`get_events`], but appends to `events` so its allocation can be reused.
+/// Returns the number of events added.
+pub fn get_events_into(
+    events: &mut Vec<SDL_Event>,
+    types: RangeInclusive<SDL_EventType>,
+    max: usize,
+) -> Result<usize> {
+    peep(events, max, SDL_GETEVENT, types)
+}
+
+/// Add events to the back of the queue, bypassing the event filter and watches.
+/// Returns the number of events added. See [`SDL_PeepEvents`].
+pub fn add_events(events: &[SDL_Event]) -> Result<usize> {
+    let count = unsafe {
+        SDL_PeepEvents(
+            events.as_ptr() as *mut SDL_Event,
+            events.len().min(c_int::MAX as usize) as c_int,
+            SDL_ADDEVENT,
+            0,
+            u32::MAX,
+        )
+    };
+    check(count >= 0)?;
+    Ok(count as usize)
+}
+
+// the filter functions and catching panics need std
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use alloc::sync::Arc;
+    use core::{
+        mem,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+    use sdl3_sys::{
+        events::{SDL_PushEvent, SDL_RegisterEvents},
+        init::{SDL_Init, SDL_QuitSubSystem, SDL_INIT_EVENTS},
+    };
+
+    // tests register their own event types, so they only look at their own events
+    fn register(count: i32) -> u32 {
+        let t = unsafe { SDL_RegisterEvents(count) };
+        assert_ne!(t, 0);
+        t
+    }
+
+    fn user_event(t: u32, code: i32) -> SDL_Event {
+        let mut event: SDL_Event = unsafe { mem::zeroed() };
+        event.user.r#type = t;
+        event.user.code = code;
+        event
+    }
+
+    fn push(t: u32, code: i32) -> bool {
+        unsafe { SDL_PushEvent(&mut user_event(t, code)) }
+    }
+
+    fn codes(events: &[SDL_Event]) -> Vec<i32> {
+        events.iter().map(|e| unsafe { e.user.code }).collect()
+    }
+
+    fn only(t: u32) -> RangeInclusive<SDL_EventType> {
+        SDL_EventType(t)..=SDL_EventType(t)
+    }
+
+    #[test]
+    fn watch() {
+        assert!(unsafe { SDL_Init(SDL_INIT_EVENTS) });
+        let t = register(2);
+        let seen = Arc::new(AtomicUsize::new(0));
+        let counter = seen.clone();
+        let watch = EventWatch::new(move |event| {
+            if unsafe { event.r#type } == t {
+                counter.fetch_add(1, Ordering::Relaxed);
+            }
+            true
+        })
+        .unwrap();
+        assert!(push(t, 0));
+        assert!(push(t, 1));
+        assert_eq!(seen.load(Ordering::Relaxed), 2);
+
+        let panicking = EventWatch::new(move |event| {
+            if unsafe { event.r#type } == t + 1 {
+                panic!("watch panicked");
+            }
+            true
+        })
+        .unwrap();
+        assert!(push(t + 1, 0));
+        assert!(push(t, 2));
+        assert_eq!(seen.load(Ordering::Relaxed), 3);
+        drop(panicking);
+
+        // events added with add_events bypass watches
+        assert_eq!(add_events(&[user_event(t, 3)]).unwrap(), 1);
+        assert_eq!(seen.load(Ordering::Relaxed), 3);
+
+        drop(watch);
+        assert!(push(t, 4));
+        assert_eq!(seen.load(Ordering::Relaxed), 3);
+
+        assert_eq!(
+            codes(&get_events(SDL_EventType(t)..=SDL_EventType(t + 1), 100).unwrap()),
+            [0, 1, 0, 2, 3, 4]
+        );
+        unsafe { SDL_QuitSubSystem(SDL_INIT_EVENTS) };
+    }
+
+    #[test]
+    fn filter_peek_get() {
+        assert!(unsafe { SDL_Init(SDL_INIT_EVENTS) });
+        let t = register(1);
+
+        set_event_filter(move |event| unsafe { event.r#type != t || event.user.code != 1 });
+        assert!(push(t, 0));
+        assert!(!push(t, 1));
+        clear_event_filter();
+        assert!(push(t, 1));
+
+        assert_eq!(codes(&peek_events(only(t), 10).unwrap()), [0, 1]);
+        assert_eq!(codes(&peek_events(only(t), 1).unwrap()), [0]);
+        assert_eq!(codes(&peek_events(only(t), 0).unwrap()), []);
+        // huge limits only allocate for the events that are there
+        let events = peek_events(only(t), usize::MAX).unwrap();
+        assert_eq!(codes(&events), [0, 1]);
+        assert!(events.capacity() < 100);
+
+        assert_eq!(codes(&get_events(only(t), 1).unwrap()), [0]);
+        assert_eq!(codes(&get_events(only(t), usize::MAX).unwrap()), [1]);
+        assert!(get_events(only(t), usize::MAX).unwrap().is_empty());
+
+        for code in 0..3 {
+            assert!(push(t, code));
+        }
+        filter_events(|event| unsafe { event.r#type != t || event.user.code != 1 });
+        // a panic keeps the event
+        filter_events(|event| {
+            if unsafe { event.r#type } == t {
+                panic!("filter panicked");
+            }
+            true
+        });
+        let mut events = Vec::with_capacity(1);
+        events.push(user_event(0, -1));
+        assert_eq!(get_events_into(&mut events, only(t), 10).unwrap(), 2);
+        assert_eq!(codes(&events), [-1, 0, 2]);
+        unsafe { SDL_QuitSubSystem(SDL_INIT_EVENTS) };
+    }
+}
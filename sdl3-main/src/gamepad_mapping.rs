@@ -0,0 +1,830 @@
+//! Parser and serializer for gamepad mapping strings.
+//!
+//! Mapping strings use the format of [SDL_GameControllerDB]: a GUID, a name and a list of
+//! `key:value` fields, separated by commas. For example:
+//!
+//! ```text
+//! 030000005e0400008e02000000007801,Xbox 360 Controller,a:b0,b:b1,leftx:a0,lefty:a1,platform:Linux,
+//! ```
+//!
+//! Most fields bind a gamepad button or axis to an input on the joystick. Other fields are
+//! the `platform`, `hint` conditions, and a few fields that are only preserved, like `crc`
+//! and `type`.
+//!
+//! SDL silently ignores invalid mapping lines, so use [`GamepadMapping::from_str`] or
+//! [`parse_database`] to validate them first.
+//!
+//! [SDL_GameControllerDB]: https://github.com/mdqinc/SDL_GameControllerDB
+
+use crate::{Error, Result};
+use alloc::{
+    ffi::CString,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    ffi::CStr,
+    fmt::{self, Display},
+    str::FromStr,
+};
+use sdl3_sys::{
+    gamepad::{
+        SDL_AddGamepadMapping, SDL_Gamepad, SDL_GamepadAxis, SDL_GamepadButton,
+        SDL_GetGamepadMapping,
+    },
+    stdinc::SDL_free,
+};
+
+const BUTTON_NAMES: [&str; SDL_GamepadButton::COUNT.0 as usize] = [
+    "a",
+    "b",
+    "x",
+    "y",
+    "back",
+    "guide",
+    "start",
+    "leftstick",
+    "rightstick",
+    "leftshoulder",
+    "rightshoulder",
+    "dpup",
+    "dpdown",
+    "dpleft",
+    "dpright",
+    "misc1",
+    "paddle1",
+    "paddle2",
+    "paddle3",
+    "paddle4",
+    "touchpad",
+    "misc2",
+    "misc3",
+    "misc4",
+    "misc5",
+    "misc6",
+];
+
+const AXIS_NAMES: [&str; SDL_GamepadAxis::COUNT.0 as usize] = [
+    "leftx",
+    "lefty",
+    "rightx",
+    "righty",
+    "lefttrigger",
+    "righttrigger",
+];
+
+// fields that SDL recognizes but that don't bind anything
+const OTHER_KEYS: [&str; 5] = ["crc", "type", "face", "sdk>=", "sdk<="];
+
+/// Get the name of a gamepad button as used in mapping strings.
+pub fn button_name(button: SDL_GamepadButton) -> Option<&'static str> {
+    BUTTON_NAMES.get(usize::try_from(button.0).ok()?).copied()
+}
+
+/// Get a gamepad button from its name in mapping strings.
+pub fn button_from_name(name: &str) -> Option<SDL_GamepadButton> {
+    let i = BUTTON_NAMES.iter().position(|n| *n == name)?;
+    Some(SDL_GamepadButton(i as _))
+}
+
+/// Get the name of a gamepad axis as used in mapping strings.
+pub fn axis_name(axis: SDL_GamepadAxis) -> Option<&'static str> {
+    AXIS_NAMES.get(usize::try_from(axis.0).ok()?).copied()
+}
+
+/// Get a gamepad axis from its name in mapping strings.
+pub fn axis_from_name(name: &str) -> Option<SDL_GamepadAxis> {
+    let i = AXIS_NAMES.iter().position(|n| *n == name)?;
+    Some(SDL_GamepadAxis(i as _))
+}
+
+/// A parsed gamepad mapping.
+///
+/// [`Display`] writes the mapping in the same format that [`FromStr`] parses.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GamepadMapping {
+    /// The joystick GUID as 32 hex digits, or `xinput`
+    pub guid: String,
+
+    /// The name of the gamepad
+    pub name: String,
+
+    /// The fields of the mapping, in order
+    pub fields: Vec<MappingField>,
+}
+
+impl GamepadMapping {
+    /// Get the current mapping of an open gamepad (see
+    /// [`SDL_GetGamepadMapping`]).
+    ///
+    /// # Safety
+    /// `gamepad` must be a valid, open gamepad.
+    pub unsafe fn for_gamepad(gamepad: *mut SDL_Gamepad) -> Result<Self> {
+        let mapping = unsafe { SDL_GetGamepadMapping(gamepad) };
+        if mapping.is_null() {
+            return Err(Error::from_sdl());
+        }
+        let parsed = unsafe { CStr::from_ptr(mapping) }
+            .to_str()
+            .map_err(|_| Error::new("gamepad mapping isn't valid UTF-8"))
+            .and_then(|s| s.parse().map_err(Error::from));
+        unsafe { SDL_free(mapping.cast()) };
+        parsed
+    }
+
+    /// Add this mapping to SDL (see [`SDL_AddGamepadMapping`]).
+    ///
+    /// Returns `true` if a new mapping was added, or `false` if an existing mapping was
+    /// updated.
+    ///
+    /// Returns an error without adding the mapping if a binding has a gamepad button or axis
+    /// that doesn't have a name.
+    pub fn add(&self) -> Result<bool> {
+        if self.bindings().any(|b| b.target.name().is_none()) {
+            return Err(Error::new(
+                "gamepad mapping has an unknown gamepad button or axis",
+            ));
+        }
+        let mapping = CString::new(self.to_string())
+            .map_err(|_| Error::new("gamepad mapping contains a nul byte"))?;
+        match unsafe { SDL_AddGamepadMapping(mapping.as_ptr()) } {
+            -1 => Err(Error::from_sdl()),
+            0 => Ok(false),
+            _ => Ok(true),
+        }
+    }
+
+    /// Get the platform this mapping is for, if it has a `platform` field.
+    pub fn platform(&self) -> Option<&str> {
+        self.fields.iter().find_map(|f| match f {
+            MappingField::Platform(p) => Some(p.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Iterate over the bindings in this mapping.
+    pub fn bindings(&self) -> impl Iterator<Item = &Binding> {
+        self.fields.iter().filter_map(|f| match f {
+            MappingField::Binding(b) => Some(b),
+            _ => None,
+        })
+    }
+
+    /// Iterate over the hint conditions in this mapping.
+    pub fn hints(&self) -> impl Iterator<Item = &HintCondition> {
+        self.fields.iter().filter_map(|f| match f {
+            MappingField::Hint(h) => Some(h),
+            _ => None,
+        })
+    }
+
+    /// Get the binding for a gamepad button, if any.
+    pub fn button(&self, button: SDL_GamepadButton) -> Option<&Binding> {
+        self.bindings()
+            .find(|b| matches!(b.target, Target::Button(t) if t == button))
+    }
+
+    /// Get the binding for a gamepad axis, if any.
+    ///
+    /// This only returns bindings for the full axis. Half axis bindings like `+leftx` can be
+    /// found with [`bindings`](Self::bindings).
+    pub fn axis(&self, axis: SDL_GamepadAxis) -> Option<&Binding> {
+        self.bindings().find(
+            |b| matches!(b.target, Target::Axis { axis: t, range: AxisRange::Full } if t == axis),
+        )
+    }
+}
+
+impl FromStr for GamepadMapping {
+    type Err = MappingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = |offset, kind| MappingError { offset, kind };
+
+        let Some((guid, rest)) = s.split_once(',') else {
+            return Err(err(s.len(), MappingErrorKind::MissingName));
+        };
+        if !(guid == "xinput" || guid.len() == 32 && guid.bytes().all(|b| b.is_ascii_hexdigit())) {
+            return Err(err(0, MappingErrorKind::InvalidGuid));
+        }
+
+        let name_offset = guid.len() + 1;
+        let (name, rest) = rest.split_once(',').unwrap_or((rest, ""));
+        if name.is_empty() {
+            return Err(err(name_offset, MappingErrorKind::MissingName));
+        }
+
+        let mut fields = Vec::new();
+        let mut offset = name_offset + name.len() + 1;
+        for field in rest.split(',') {
+            let field_offset = offset;
+            offset += field.len() + 1;
+            if field.is_empty() {
+                continue;
+            }
+            let Some((key, value)) = field.split_once(':') else {
+                return Err(err(field_offset, MappingErrorKind::MissingValue));
+            };
+            let value_offset = field_offset + key.len() + 1;
+            let field = match key {
+                "platform" => MappingField::Platform(value.into()),
+                "hint" => MappingField::Hint(
+                    HintCondition::parse(value)
+                        .ok_or(err(value_offset, MappingErrorKind::InvalidHint))?,
+                ),
+                _ if OTHER_KEYS.contains(&key) => MappingField::Other {
+                    key: key.into(),
+                    value: value.into(),
+                },
+                _ => MappingField::Binding(Binding {
+                    target: Target::parse(key)
+                        .ok_or(err(field_offset, MappingErrorKind::UnknownTarget))?,
+                    source: Source::parse(value)
+                        .ok_or(err(value_offset, MappingErrorKind::InvalidSource))?,
+                }),
+            };
+            fields.push(field);
+        }
+
+        Ok(Self {
+            guid: guid.into(),
+            name: name.into(),
+            fields,
+        })
+    }
+}
+
+impl Display for GamepadMapping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},", self.guid, self.name)?;
+        for field in &self.fields {
+            write!(f, "{field},")?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse a mapping database, like `gamecontrollerdb.txt`.
+///
+/// Empty lines and lines starting with `#` are skipped. The iterator yields the line number
+/// (starting at 1) of each mapping together with the parse result, so a single invalid line
+/// doesn't prevent using the rest of the database.
+pub fn parse_database(
+    text: &str,
+) -> impl Iterator<Item = (usize, Result<GamepadMapping, MappingError>)> + '_ {
+    text.lines().enumerate().filter_map(|(i, line)| {
+        let line = line.trim();
+        (!line.is_empty() && !line.starts_with('#')).then(|| (i + 1, line.parse()))
+    })
+}
+
+/// A field of a [`GamepadMapping`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MappingField {
+    /// Binds a gamepad button or axis to a joystick input
+    Binding(Binding),
+
+    /// `platform:` field; the mapping is only used on this platform
+    Platform(String),
+
+    /// `hint:` field; the mapping is only used if the hint condition is true
+    Hint(HintCondition),
+
+    /// A field that's preserved but not interpreted, like `crc` or `type`
+    Other {
+        /// The key of the field
+        key: String,
+
+        /// The value of the field
+        value: String,
+    },
+}
+
+impl Display for MappingField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Binding(binding) => write!(f, "{binding}"),
+            Self::Platform(platform) => write!(f, "platform:{platform}"),
+            Self::Hint(hint) => write!(f, "hint:{hint}"),
+            Self::Other { key, value } => write!(f, "{key}:{value}"),
+        }
+    }
+}
+
+/// A hint condition, like `hint:!SDL_GAMECONTROLLER_USE_BUTTON_LABELS:=1`.
+///
+/// The mapping is used if the boolean value of the hint is `true`, or `false` if the
+/// condition is negated. If the hint isn't set, `default` is used as its value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HintCondition {
+    /// `true` if the condition is negated with `!`
+    pub negate: bool,
+
+    /// The name of the hint
+    pub name: String,
+
+    /// The value to use if the hint isn't set
+    pub default: Option<String>,
+}
+
+impl HintCondition {
+    fn parse(s: &str) -> Option<Self> {
+        let (negate, s) = match s.strip_prefix('!') {
+            Some(s) => (true, s),
+            None => (false, s),
+        };
+        let (name, default) = match s.split_once(":=") {
+            Some((name, default)) => (name, Some(default.into())),
+            None => (s, None),
+        };
+        (!name.is_empty()).then(|| Self {
+            negate,
+            name: name.into(),
+            default,
+        })
+    }
+}
+
+impl Display for HintCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.negate {
+            f.write_str("!")?;
+        }
+        f.write_str(&self.name)?;
+        if let Some(default) = &self.default {
+            write!(f, ":={default}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Binds a gamepad button or axis to a joystick input, like `a:b0` or `+lefty:-a1`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Binding {
+    /// The gamepad button or axis
+    pub target: Target,
+
+    /// The joystick input
+    pub source: Source,
+}
+
+impl Display for Binding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.target, self.source)
+    }
+}
+
+/// Which part of an axis is used.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum AxisRange {
+    /// The whole axis
+    #[default]
+    Full,
+
+    /// The positive half of the axis (`+` prefix)
+    Positive,
+
+    /// The negative half of the axis (`-` prefix)
+    Negative,
+}
+
+impl AxisRange {
+    fn split(s: &str) -> (Self, &str) {
+        if let Some(s) = s.strip_prefix('+') {
+            (Self::Positive, s)
+        } else if let Some(s) = s.strip_prefix('-') {
+            (Self::Negative, s)
+        } else {
+            (Self::Full, s)
+        }
+    }
+
+    const fn prefix(self) -> &'static str {
+        match self {
+            Self::Full => "",
+            Self::Positive => "+",
+            Self::Negative => "-",
+        }
+    }
+}
+
+/// The gamepad side of a [`Binding`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Target {
+    /// A gamepad button
+    Button(SDL_GamepadButton),
+
+    /// A gamepad axis, or half of it
+    Axis {
+        /// The axis
+        axis: SDL_GamepadAxis,
+
+        /// The part of the axis
+        range: AxisRange,
+    },
+}
+
+impl Target {
+    /// Get the name of the gamepad button or axis, without the `+`/`-` prefix of half axes.
+    /// Returns `None` if it's not a known button or axis.
+    pub fn name(&self) -> Option<&'static str> {
+        match self {
+            Self::Button(button) => button_name(*button),
+            Self::Axis { axis, .. } => axis_name(*axis),
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        let (range, name) = AxisRange::split(s);
+        if let Some(axis) = axis_from_name(name) {
+            Some(Self::Axis { axis, range })
+        } else if range == AxisRange::Full {
+            button_from_name(name).map(Self::Button)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Debug for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = self.name().unwrap_or("?");
+        match self {
+            Self::Button(_) => f.debug_tuple("Button").field(&name).finish(),
+            Self::Axis { range, .. } => f
+                .debug_struct("Axis")
+                .field("axis", &name)
+                .field("range", range)
+                .finish(),
+        }
+    }
+}
+
+// Unknown buttons and axes are written as `?`, which isn't valid in a mapping
+impl Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = self.name().unwrap_or("?");
+        match self {
+            Self::Button(_) => f.write_str(name),
+            Self::Axis { range, .. } => write!(f, "{}{name}", range.prefix()),
+        }
+    }
+}
+
+/// The joystick side of a [`Binding`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Source {
+    /// A joystick button (`b0`)
+    Button(u32),
+
+    /// A joystick axis (`a0`, `+a0`, `-a0`, `a0~`)
+    Axis {
+        /// The axis index
+        axis: u32,
+
+        /// The part of the axis
+        range: AxisRange,
+
+        /// `true` if the axis is inverted (`~` suffix)
+        inverted: bool,
+    },
+
+    /// A joystick hat direction (`h0.1`)
+    Hat {
+        /// The hat index
+        hat: u32,
+
+        /// The direction mask (`SDL_HAT_*`)
+        mask: u8,
+    },
+}
+
+impl Source {
+    fn parse(s: &str) -> Option<Self> {
+        fn index(s: &str) -> Option<u32> {
+            // reject signs and other things `u32::from_str` would accept
+            if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+                None
+            } else {
+                s.parse().ok()
+            }
+        }
+
+        let (range, s) = AxisRange::split(s);
+        if let Some(axis) = s.strip_prefix('a') {
+            let (axis, inverted) = match axis.strip_suffix('~') {
+                Some(axis) => (axis, true),
+                None => (axis, false),
+            };
+            Some(Self::Axis {
+                axis: index(axis)?,
+                range,
+                inverted,
+            })
+        } else if range != AxisRange::Full {
+            None
+        } else if let Some(button) = s.strip_prefix('b') {
+            Some(Self::Button(index(button)?))
+        } else if let Some(hat) = s.strip_prefix('h') {
+            let (hat, mask) = hat.split_once('.')?;
+            Some(Self::Hat {
+                hat: index(hat)?,
+                mask: u8::try_from(index(mask)?).ok()?,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Button(button) => write!(f, "b{button}"),
+            Self::Axis {
+                axis,
+                range,
+                inverted,
+            } => write!(
+                f,
+                "{}a{axis}{}",
+                range.prefix(),
+                if *inverted { "~" } else { "" }
+            ),
+            Self::Hat { hat, mask } => write!(f, "h{hat}.{mask}"),
+        }
+    }
+}
+
+/// An error from parsing a [`GamepadMapping`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MappingError {
+    /// Byte offset in the mapping string where the error was detected.
+    pub offset: usize,
+
+    /// What went wrong.
+    pub kind: MappingErrorKind,
+}
+
+impl Display for MappingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at offset {}", self.kind, self.offset)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MappingError {}
+
+impl From<MappingError> for Error {
+    fn from(value: MappingError) -> Self {
+        Error::new(alloc::format!("invalid gamepad mapping: {value}"))
+    }
+}
+
+/// The kind of a [`MappingError`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MappingErrorKind {
+    /// The GUID isn't 32 hex digits or `xinput`
+    InvalidGuid,
+
+    /// The mapping has no name
+    MissingName,
+
+    /// A field has no `:` separating the key and value
+    MissingValue,
+
+    /// A field key isn't a known gamepad button or axis
+    UnknownTarget,
+
+    /// A binding value isn't a valid joystick button, axis or hat
+    InvalidSource,
+
+    /// A `hint:` field is empty
+    InvalidHint,
+}
+
+impl Display for MappingErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::InvalidGuid => "invalid GUID",
+            Self::MissingName => "missing name",
+            Self::MissingValue => "missing value",
+            Self::UnknownTarget => "unknown gamepad button or axis",
+            Self::InvalidSource => "invalid joystick input",
+            Self::InvalidHint => "invalid hint condition",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const XBOX: &str = "030000005e0400008e02000000007801,Xbox 360 Controller,a:b0,b:b1,dpup:h0.1,leftx:a0,+lefty:-a1,righttrigger:a5~,platform:Linux,hint:!SDL_GAMECONTROLLER_USE_BUTTON_LABELS:=1,crc:1234,";
+
+    fn parse_err(s: &str) -> MappingError {
+        s.parse::<GamepadMapping>().unwrap_err()
+    }
+
+    #[test]
+    fn round_trip() {
+        let mapping: GamepadMapping = XBOX.parse().unwrap();
+        assert_eq!(mapping.to_string(), XBOX);
+        assert_eq!(mapping.to_string().parse::<GamepadMapping>(), Ok(mapping));
+
+        // the trailing comma is optional, but always written
+        let mapping: GamepadMapping = "xinput,XInput Controller,a:b0".parse().unwrap();
+        assert_eq!(mapping.to_string(), "xinput,XInput Controller,a:b0,");
+    }
+
+    #[test]
+    fn fields() {
+        let mapping: GamepadMapping = XBOX.parse().unwrap();
+        assert_eq!(mapping.guid, "030000005e0400008e02000000007801");
+        assert_eq!(mapping.name, "Xbox 360 Controller");
+        assert_eq!(mapping.platform(), Some("Linux"));
+        assert_eq!(
+            mapping.button(SDL_GamepadButton::SOUTH).map(|b| b.source),
+            Some(Source::Button(0))
+        );
+        assert_eq!(
+            mapping.button(SDL_GamepadButton::DPAD_UP).map(|b| b.source),
+            Some(Source::Hat { hat: 0, mask: 1 })
+        );
+        assert_eq!(
+            mapping
+                .axis(SDL_GamepadAxis::RIGHT_TRIGGER)
+                .map(|b| b.source),
+            Some(Source::Axis {
+                axis: 5,
+                range: AxisRange::Full,
+                inverted: true
+            })
+        );
+        // half axis bindings aren't returned by `axis`
+        assert_eq!(mapping.axis(SDL_GamepadAxis::LEFTY), None);
+        assert!(mapping.bindings().any(|b| *b
+            == Binding {
+                target: Target::Axis {
+                    axis: SDL_GamepadAxis::LEFTY,
+                    range: AxisRange::Positive
+                },
+                source: Source::Axis {
+                    axis: 1,
+                    range: AxisRange::Negative,
+                    inverted: false
+                }
+            }));
+        assert_eq!(
+            mapping.hints().collect::<Vec<_>>(),
+            [&HintCondition {
+                negate: true,
+                name: "SDL_GAMECONTROLLER_USE_BUTTON_LABELS".into(),
+                default: Some("1".into())
+            }]
+        );
+    }
+
+    #[test]
+    fn error_positions() {
+        let guid = "030000005e0400008e02000000007801";
+        let err = |offset, kind| MappingError { offset, kind };
+
+        assert_eq!(
+            parse_err("0300,Pad,a:b0"),
+            err(0, MappingErrorKind::InvalidGuid)
+        );
+        assert_eq!(parse_err(guid), err(32, MappingErrorKind::MissingName));
+        assert_eq!(
+            parse_err(&alloc::format!("{guid},,a:b0")),
+            err(33, MappingErrorKind::MissingName)
+        );
+        assert_eq!(
+            parse_err(&alloc::format!("{guid},Pad,a:b0,b")),
+            err(42, MappingErrorKind::MissingValue)
+        );
+        assert_eq!(
+            parse_err(&alloc::format!("{guid},Pad,a:b0,jump:b1")),
+            err(42, MappingErrorKind::UnknownTarget)
+        );
+        // buttons can't be split in halves
+        assert_eq!(
+            parse_err(&alloc::format!("{guid},Pad,+a:b0")),
+            err(37, MappingErrorKind::UnknownTarget)
+        );
+        assert_eq!(
+            parse_err(&alloc::format!("{guid},Pad,a:b0,b:x1")),
+            err(44, MappingErrorKind::InvalidSource)
+        );
+        assert_eq!(
+            parse_err(&alloc::format!("{guid},Pad,a:b+1")),
+            err(39, MappingErrorKind::InvalidSource)
+        );
+        assert_eq!(
+            parse_err(&alloc::format!("{guid},Pad,dpup:h0.256")),
+            err(42, MappingErrorKind::InvalidSource)
+        );
+        assert_eq!(
+            parse_err(&alloc::format!("{guid},Pad,hint:!")),
+            err(42, MappingErrorKind::InvalidHint)
+        );
+    }
+
+    #[test]
+    fn unknown_target() {
+        let button = Target::Button(SDL_GamepadButton(99));
+        let axis = Target::Axis {
+            axis: SDL_GamepadAxis(99),
+            range: AxisRange::Negative,
+        };
+        assert_eq!(button.name(), None);
+        assert_eq!(button.to_string(), "?");
+        assert_eq!(axis.to_string(), "-?");
+
+        let mapping = GamepadMapping {
+            guid: "xinput".into(),
+            name: "Pad".into(),
+            fields: alloc::vec![MappingField::Binding(Binding {
+                target: button,
+                source: Source::Button(0),
+            })],
+        };
+        assert_eq!(mapping.to_string(), "xinput,Pad,?:b0,");
+        assert!(mapping.add().is_err());
+        assert_eq!(
+            mapping.to_string().parse::<GamepadMapping>(),
+            Err(MappingError {
+                offset: 11,
+                kind: MappingErrorKind::UnknownTarget
+            })
+        );
+    }
+
+    #[test]
+    fn names() {
+        for i in 0..SDL_GamepadButton::COUNT.0 {
+            let button = SDL_GamepadButton(i);
+            assert!(button_from_name(button_name(button).unwrap()) == Some(button));
+        }
+        for i in 0..SDL_GamepadAxis::COUNT.0 {
+            let axis = SDL_GamepadAxis(i);
+            assert!(axis_from_name(axis_name(axis).unwrap()) == Some(axis));
+        }
+        assert_eq!(button_name(SDL_GamepadButton::COUNT), None);
+        assert_eq!(button_name(SDL_GamepadButton(-1)), None);
+    }
+
+    #[test]
+    fn database() {
+        let db = alloc::format!("# comment\n\n{XBOX}\nbad line\n");
+        let lines: Vec<_> = parse_database(&db)
+            .map(|(line, m)| (line, m.is_ok()))
+            .collect();
+        assert_eq!(lines, [(3, true), (4, false)]);
+    }
+
+    #[test]
+    fn sdl_round_trip() {
+        use sdl3_sys::{
+            gamepad::SDL_GetGamepadMappingForGUID,
+            guid::SDL_StringToGUID,
+            init::{SDL_Init, SDL_QuitSubSystem, SDL_INIT_GAMEPAD},
+            platform::SDL_GetPlatform,
+        };
+
+        assert!(unsafe { SDL_Init(SDL_INIT_GAMEPAD) });
+        let platform = unsafe { CStr::from_ptr(SDL_GetPlatform()) }
+            .to_str()
+            .unwrap();
+        let mapping: GamepadMapping = alloc::format!(
+            "03000000de1200003412000000000000,Round Trip Pad,a:b0,b:b1,dpup:h0.1,leftx:a0,+lefty:-a1,righttrigger:a5~,platform:{platform},"
+        )
+        .parse()
+        .unwrap();
+        mapping.add().unwrap();
+
+        let guid = CString::new(mapping.guid.as_str()).unwrap();
+        let raw = unsafe { SDL_GetGamepadMappingForGUID(SDL_StringToGUID(guid.as_ptr())) };
+        assert!(!raw.is_null());
+        let back: GamepadMapping = unsafe { CStr::from_ptr(raw) }
+            .to_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+        unsafe { SDL_free(raw.cast()) };
+
+        // SDL may drop, move or add the platform field
+        let without_platform = |m: &GamepadMapping| -> Vec<MappingField> {
+            m.fields
+                .iter()
+                .filter(|f| !matches!(f, MappingField::Platform(_)))
+                .cloned()
+                .collect()
+        };
+        assert_eq!(back.guid.to_ascii_lowercase(), mapping.guid);
+        assert_eq!(back.name, mapping.name);
+        assert_eq!(without_platform(&back), without_platform(&mapping));
+        assert!(back.platform().is_none_or(|p| p == platform));
+        unsafe { SDL_QuitSubSystem(SDL_INIT_GAMEPAD) };
+    }
+}
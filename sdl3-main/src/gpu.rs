@@ -0,0 +1,673 @@
+//! Helpers for the SDL GPU API.
+//!
+//! # Shader bundles
+//!
+//! A [`ShaderBundle`] holds one shader compiled to several shader formats, together with
+//! the resource counts SDL needs to create it. At runtime, the bundle picks the variant
+//! that the GPU device supports, so you don't have to write loading code per backend.
+//!
+//! Bundles can be stored in files using this format. All integers are little endian `u32`s.
+//!
+//! | Size | Description |
+//! | ---- | ----------- |
+//! | 4 | Magic bytes: `SGSB` |
+//! | 4 | Format version: `1` |
+//! | 4 | Shader stage: `0` for vertex, `1` for fragment (same as [`SDL_GPUShaderStage`]) |
+//! | 4 | Number of samplers |
+//! | 4 | Number of storage textures |
+//! | 4 | Number of storage buffers |
+//! | 4 | Number of uniform buffers |
+//! | 4 | Number of variants that follow |
+//!
+//! Each variant is stored as:
+//!
+//! | Size | Description |
+//! | ---- | ----------- |
+//! | 4 | Shader format: a single `SDL_GPU_SHADERFORMAT_*` flag |
+//! | 4 | Length of the entry point name in bytes (`E`) |
+//! | `E` | Entry point name as UTF-8, without a nul terminator |
+//! | 4 | Length of the shader code in bytes (`C`) |
+//! | `C` | Shader code |
+//!
+//! Variants are stored in order of preference. If a device supports more than one of the
+//! formats in a bundle, the first one is used.
+
+use crate::{Error, Result};
+use alloc::{ffi::CString, vec::Vec};
+use core::{
+    ffi::CStr,
+    fmt::{self, Display},
+    ptr::{self, NonNull},
+    slice,
+};
+use sdl3_sys::{
+    gpu::{
+        SDL_CreateGPUShader, SDL_GPUDevice, SDL_GPUShader, SDL_GPUShaderCreateInfo,
+        SDL_GPUShaderFormat, SDL_GPUShaderStage, SDL_GPUSupportsShaderFormats,
+        SDL_GetGPUShaderFormats, SDL_ReleaseGPUShader, SDL_GPU_SHADERFORMAT_DXBC,
+        SDL_GPU_SHADERFORMAT_DXIL, SDL_GPU_SHADERFORMAT_METALLIB, SDL_GPU_SHADERFORMAT_MSL,
+        SDL_GPU_SHADERFORMAT_PRIVATE, SDL_GPU_SHADERFORMAT_SPIRV,
+    },
+    iostream::SDL_LoadFile,
+    stdinc::SDL_free,
+};
+
+const MAGIC: [u8; 4] = *b"SGSB";
+const VERSION: u32 = 1;
+
+const KNOWN_FORMATS: SDL_GPUShaderFormat = SDL_GPU_SHADERFORMAT_PRIVATE
+    | SDL_GPU_SHADERFORMAT_SPIRV
+    | SDL_GPU_SHADERFORMAT_DXBC
+    | SDL_GPU_SHADERFORMAT_DXIL
+    | SDL_GPU_SHADERFORMAT_MSL
+    | SDL_GPU_SHADERFORMAT_METALLIB;
+
+/// This is the Rust enum equivalent to [`SDL_GPUShaderStage`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ShaderStage {
+    Vertex,
+    Fragment,
+}
+
+impl From<ShaderStage> for SDL_GPUShaderStage {
+    #[inline(always)]
+    fn from(value: ShaderStage) -> Self {
+        match value {
+            ShaderStage::Vertex => Self::VERTEX,
+            ShaderStage::Fragment => Self::FRAGMENT,
+        }
+    }
+}
+
+impl TryFrom<SDL_GPUShaderStage> for ShaderStage {
+    type Error = SDL_GPUShaderStage;
+
+    #[inline]
+    fn try_from(value: SDL_GPUShaderStage) -> Result<Self, Self::Error> {
+        match value {
+            SDL_GPUShaderStage::VERTEX => Ok(Self::Vertex),
+            SDL_GPUShaderStage::FRAGMENT => Ok(Self::Fragment),
+            _ => Err(value),
+        }
+    }
+}
+
+/// Resource counts of a shader, as required by [`SDL_GPUShaderCreateInfo`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ShaderReflection {
+    pub num_samplers: u32,
+    pub num_storage_textures: u32,
+    pub num_storage_buffers: u32,
+    pub num_uniform_buffers: u32,
+}
+
+/// A shader compiled to a single shader format.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ShaderVariant {
+    format: SDL_GPUShaderFormat,
+    entrypoint: CString,
+    code: Vec<u8>,
+}
+
+impl ShaderVariant {
+    /// Create a new shader variant.
+    ///
+    /// `format` must be exactly one of the `SDL_GPU_SHADERFORMAT_*` flags.
+    pub fn new(
+        format: SDL_GPUShaderFormat,
+        entrypoint: &str,
+        code: impl Into<Vec<u8>>,
+    ) -> Result<Self, BundleErrorKind> {
+        if format.count_ones() != 1 || format & KNOWN_FORMATS == 0 {
+            return Err(BundleErrorKind::InvalidFormat(format));
+        }
+        let entrypoint =
+            CString::new(entrypoint).map_err(|_| BundleErrorKind::InvalidEntrypoint)?;
+        Ok(Self {
+            format,
+            entrypoint,
+            code: code.into(),
+        })
+    }
+
+    /// The shader format of this variant (one of the `SDL_GPU_SHADERFORMAT_*` flags).
+    #[inline(always)]
+    pub fn format(&self) -> SDL_GPUShaderFormat {
+        self.format
+    }
+
+    /// The name of the entry point function.
+    #[inline(always)]
+    pub fn entrypoint(&self) -> &CStr {
+        &self.entrypoint
+    }
+
+    /// The compiled shader code.
+    #[inline(always)]
+    pub fn code(&self) -> &[u8] {
+        &self.code
+    }
+}
+
+/// A shader compiled to multiple shader formats. See the [module documentation](self).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ShaderBundle {
+    stage: ShaderStage,
+    reflection: ShaderReflection,
+    variants: Vec<ShaderVariant>,
+}
+
+impl ShaderBundle {
+    /// Create a new shader bundle with no variants.
+    pub fn new(stage: ShaderStage, reflection: ShaderReflection) -> Self {
+        Self {
+            stage,
+            reflection,
+            variants: Vec::new(),
+        }
+    }
+
+    /// Add a variant to the bundle. Variants added earlier are preferred.
+    ///
+    /// Fails with [`BundleErrorKind::DuplicateFormat`] if the bundle already has a variant
+    /// with the same format.
+    pub fn push_variant(&mut self, variant: ShaderVariant) -> Result<(), BundleErrorKind> {
+        if self.supported_formats() & variant.format != 0 {
+            return Err(BundleErrorKind::DuplicateFormat(variant.format));
+        }
+        self.variants.push(variant);
+        Ok(())
+    }
+
+    /// Builder version of [`ShaderBundle::push_variant`].
+    pub fn with_variant(mut self, variant: ShaderVariant) -> Result<Self, BundleErrorKind> {
+        self.push_variant(variant)?;
+        Ok(self)
+    }
+
+    /// The shader stage of this bundle.
+    #[inline(always)]
+    pub fn stage(&self) -> ShaderStage {
+        self.stage
+    }
+
+    /// The resource counts of this bundle.
+    #[inline(always)]
+    pub fn reflection(&self) -> &ShaderReflection {
+        &self.reflection
+    }
+
+    /// All variants in this bundle, in order of preference.
+    #[inline(always)]
+    pub fn variants(&self) -> &[ShaderVariant] {
+        &self.variants
+    }
+
+    /// All shader formats available in this bundle.
+    pub fn supported_formats(&self) -> SDL_GPUShaderFormat {
+        self.variants.iter().fold(0, |acc, v| acc | v.format)
+    }
+
+    /// Select the preferred variant out of the formats in `formats`.
+    ///
+    /// `formats` is a set of `SDL_GPU_SHADERFORMAT_*` flags, like the one returned
+    /// from [`SDL_GetGPUShaderFormats`].
+    pub fn select(&self, formats: SDL_GPUShaderFormat) -> Option<&ShaderVariant> {
+        self.variants.iter().find(|v| v.format & formats != 0)
+    }
+
+    /// Select the preferred variant for a GPU device.
+    ///
+    /// # Safety
+    /// `device` must be a valid GPU device
+    pub unsafe fn select_for_device(&self, device: *mut SDL_GPUDevice) -> Option<&ShaderVariant> {
+        self.select(unsafe { SDL_GetGPUShaderFormats(device) })
+    }
+
+    /// Select the preferred variant for a GPU driver, or for any available driver if `name` is
+    /// `None`, before creating a device. See [`SDL_GPUSupportsShaderFormats`].
+    pub fn select_for_driver(&self, name: Option<&CStr>) -> Option<&ShaderVariant> {
+        let name = name.map(|n| n.as_ptr()).unwrap_or(ptr::null());
+        self.variants
+            .iter()
+            .find(|v| unsafe { SDL_GPUSupportsShaderFormats(v.format, name) })
+    }
+
+    /// Get the create info for a variant of this bundle.
+    ///
+    /// The returned struct borrows from `variant`, which must outlive any use of it.
+    pub fn create_info(&self, variant: &ShaderVariant) -> SDL_GPUShaderCreateInfo {
+        SDL_GPUShaderCreateInfo {
+            code_size: variant.code.len(),
+            code: variant.code.as_ptr(),
+            entrypoint: variant.entrypoint.as_ptr(),
+            format: variant.format,
+            stage: self.stage.into(),
+            num_samplers: self.reflection.num_samplers,
+            num_storage_textures: self.reflection.num_storage_textures,
+            num_storage_buffers: self.reflection.num_storage_buffers,
+            num_uniform_buffers: self.reflection.num_uniform_buffers,
+            props: 0,
+        }
+    }
+
+    /// Create a shader on `device` from the preferred variant it supports.
+    ///
+    /// # Safety
+    /// `device` must be a valid GPU device that outlives the returned shader
+    pub unsafe fn create_shader(&self, device: *mut SDL_GPUDevice) -> Result<Shader> {
+        let Some(variant) = (unsafe { self.select_for_device(device) }) else {
+            return Err(Error::new(
+                "shader bundle has no variant supported by the GPU device",
+            ));
+        };
+        let info = self.create_info(variant);
+        match NonNull::new(unsafe { SDL_CreateGPUShader(device, &info) }) {
+            Some(shader) => Ok(Shader { device, shader }),
+            None => Err(Error::from_sdl()),
+        }
+    }
+
+    /// Parse a shader bundle from bytes in the bundle file format.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BundleError> {
+        let mut r = Reader { bytes, offset: 0 };
+
+        if r.take(4)? != MAGIC {
+            return Err(r.error_at(0, BundleErrorKind::BadMagic));
+        }
+        let version = r.u32()?;
+        if version != VERSION {
+            return Err(r.error_at(4, BundleErrorKind::UnsupportedVersion(version)));
+        }
+        let stage = r.u32()?;
+        let stage = i32::try_from(stage)
+            .ok()
+            .and_then(|s| ShaderStage::try_from(SDL_GPUShaderStage(s)).ok())
+            .ok_or(r.error_at(8, BundleErrorKind::InvalidStage(stage)))?;
+        let reflection = ShaderReflection {
+            num_samplers: r.u32()?,
+            num_storage_textures: r.u32()?,
+            num_storage_buffers: r.u32()?,
+            num_uniform_buffers: r.u32()?,
+        };
+        let mut bundle = Self::new(stage, reflection);
+
+        let n_variants = r.u32()?;
+        for _ in 0..n_variants {
+            let start = r.offset;
+            let format = r.u32()?;
+            let len = r.len()?;
+            let entrypoint = core::str::from_utf8(r.take(len)?)
+                .map_err(|_| r.error_at(start + 8, BundleErrorKind::InvalidEntrypoint))?;
+            let len = r.len()?;
+            let code = r.take(len)?;
+            ShaderVariant::new(format, entrypoint, code)
+                .and_then(|v| bundle.push_variant(v))
+                .map_err(|kind| r.error_at(start, kind))?;
+        }
+
+        if r.offset != bytes.len() {
+            return Err(r.error_at(r.offset, BundleErrorKind::TrailingData));
+        }
+        Ok(bundle)
+    }
+
+    /// Serialize this bundle to the bundle file format.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        let mut put = |v: u32| out.extend_from_slice(&v.to_le_bytes());
+        put(u32::from_le_bytes(MAGIC));
+        put(VERSION);
+        put(SDL_GPUShaderStage::from(self.stage).0 as u32);
+        put(self.reflection.num_samplers);
+        put(self.reflection.num_storage_textures);
+        put(self.reflection.num_storage_buffers);
+        put(self.reflection.num_uniform_buffers);
+        put(self.variants.len() as u32);
+        for v in self.variants.iter() {
+            let entrypoint = v.entrypoint.as_bytes();
+            out.extend_from_slice(&v.format.to_le_bytes());
+            out.extend_from_slice(&(entrypoint.len() as u32).to_le_bytes());
+            out.extend_from_slice(entrypoint);
+            out.extend_from_slice(&(v.code.len() as u32).to_le_bytes());
+            out.extend_from_slice(&v.code);
+        }
+        out
+    }
+
+    /// Load a shader bundle from a file, using [`SDL_LoadFile`].
+    pub fn load(path: &CStr) -> Result<Self> {
+        let mut size = 0;
+        let data = unsafe { SDL_LoadFile(path.as_ptr(), &mut size) };
+        if data.is_null() {
+            return Err(Error::from_sdl());
+        }
+        let result = Self::from_bytes(unsafe { slice::from_raw_parts(data as *const u8, size) });
+        unsafe { SDL_free(data) };
+        Ok(result?)
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn error_at(&self, offset: usize, kind: BundleErrorKind) -> BundleError {
+        BundleError { offset, kind }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], BundleError> {
+        match self.bytes.get(self.offset..).and_then(|b| b.get(..len)) {
+            Some(taken) => {
+                self.offset += len;
+                Ok(taken)
+            }
+            None => Err(self.error_at(self.bytes.len(), BundleErrorKind::UnexpectedEof)),
+        }
+    }
+
+    fn u32(&mut self) -> Result<u32, BundleError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn len(&mut self) -> Result<usize, BundleError> {
+        Ok(self.u32()? as usize)
+    }
+}
+
+/// An error from parsing or building a [`ShaderBundle`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BundleError {
+    /// Byte offset in the input where the error was detected.
+    pub offset: usize,
+
+    /// What went wrong.
+    pub kind: BundleErrorKind,
+}
+
+impl Display for BundleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at offset {}", self.kind, self.offset)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BundleError {}
+
+impl From<BundleError> for Error {
+    fn from(value: BundleError) -> Self {
+        Error::new(alloc::format!("invalid shader bundle: {value}"))
+    }
+}
+
+/// The kind of a [`BundleError`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BundleErrorKind {
+    /// The input doesn't start with the bundle magic bytes
+    BadMagic,
+
+    /// The bundle file format version isn't supported
+    UnsupportedVersion(u32),
+
+    /// The shader stage is unknown
+    InvalidStage(u32),
+
+    /// The shader format isn't exactly one known `SDL_GPU_SHADERFORMAT_*` flag
+    InvalidFormat(SDL_GPUShaderFormat),
+
+    /// More than one variant has the same shader format
+    DuplicateFormat(SDL_GPUShaderFormat),
+
+    /// The entry point name isn't valid UTF-8 or contains a nul byte
+    InvalidEntrypoint,
+
+    /// The input ended in the middle of the bundle
+    UnexpectedEof,
+
+    /// There's more data in the input after the end of the bundle
+    TrailingData,
+}
+
+impl Display for BundleErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BadMagic => f.write_str("bad magic bytes"),
+            Self::UnsupportedVersion(v) => write!(f, "unsupported version {v}"),
+            Self::InvalidStage(s) => write!(f, "invalid shader stage {s}"),
+            Self::InvalidFormat(fmt) => write!(f, "invalid shader format {fmt:#x}"),
+            Self::DuplicateFormat(fmt) => write!(f, "duplicate shader format {fmt:#x}"),
+            Self::InvalidEntrypoint => f.write_str("invalid entry point name"),
+            Self::UnexpectedEof => f.write_str("unexpected end of data"),
+            Self::TrailingData => f.write_str("trailing data"),
+        }
+    }
+}
+
+impl From<BundleErrorKind> for Error {
+    fn from(value: BundleErrorKind) -> Self {
+        Error::new(alloc::format!("invalid shader bundle: {value}"))
+    }
+}
+
+/// A shader created with [`ShaderBundle::create_shader`]. The shader is released when this is dropped.
+pub struct Shader {
+    device: *mut SDL_GPUDevice,
+    shader: NonNull<SDL_GPUShader>,
+}
+
+impl Shader {
+    /// Get the raw shader pointer.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *mut SDL_GPUShader {
+        self.shader.as_ptr()
+    }
+
+    /// Get the device this shader was created on.
+    #[inline(always)]
+    pub fn device(&self) -> *mut SDL_GPUDevice {
+        self.device
+    }
+
+    /// Take ownership of the raw shader pointer. It won't be released automatically.
+    #[inline]
+    pub fn into_raw(self) -> *mut SDL_GPUShader {
+        let shader = self.shader.as_ptr();
+        core::mem::forget(self);
+        shader
+    }
+}
+
+impl Drop for Shader {
+    fn drop(&mut self) {
+        unsafe { SDL_ReleaseGPUShader(self.device, self.shader.as_ptr()) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bundle() -> ShaderBundle {
+        let reflection = ShaderReflection {
+            num_samplers: 1,
+            num_storage_textures: 2,
+            num_storage_buffers: 3,
+            num_uniform_buffers: 4,
+        };
+        ShaderBundle::new(ShaderStage::Fragment, reflection)
+            .with_variant(
+                ShaderVariant::new(SDL_GPU_SHADERFORMAT_SPIRV, "main", [1, 2, 3]).unwrap(),
+            )
+            .unwrap()
+            .with_variant(ShaderVariant::new(SDL_GPU_SHADERFORMAT_DXIL, "", []).unwrap())
+            .unwrap()
+            .with_variant(
+                ShaderVariant::new(SDL_GPU_SHADERFORMAT_MSL, "main0", &b"kernel"[..]).unwrap(),
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        let bundle = bundle();
+        let bytes = bundle.to_bytes();
+        assert_eq!(&bytes[..4], b"SGSB");
+        assert_eq!(ShaderBundle::from_bytes(&bytes), Ok(bundle));
+
+        let empty = ShaderBundle::new(ShaderStage::Vertex, ShaderReflection::default());
+        assert_eq!(empty.to_bytes().len(), 32);
+        assert_eq!(ShaderBundle::from_bytes(&empty.to_bytes()), Ok(empty));
+    }
+
+    #[test]
+    fn truncated() {
+        let bytes = bundle().to_bytes();
+        for len in 0..bytes.len() {
+            assert_eq!(
+                ShaderBundle::from_bytes(&bytes[..len]),
+                Err(BundleError {
+                    offset: len,
+                    kind: BundleErrorKind::UnexpectedEof
+                })
+            );
+        }
+        let mut long = bytes.clone();
+        long.push(0);
+        assert_eq!(
+            ShaderBundle::from_bytes(&long),
+            Err(BundleError {
+                offset: bytes.len(),
+                kind: BundleErrorKind::TrailingData
+            })
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let bytes = bundle().to_bytes();
+        let err = |f: &dyn Fn(&mut Vec<u8>)| {
+            let mut bytes = bytes.clone();
+            f(&mut bytes);
+            ShaderBundle::from_bytes(&bytes).unwrap_err()
+        };
+        let error = |offset, kind| BundleError { offset, kind };
+
+        assert_eq!(err(&|b| b[0] = b'X'), error(0, BundleErrorKind::BadMagic));
+        assert_eq!(
+            err(&|b| b[4] = 2),
+            error(4, BundleErrorKind::UnsupportedVersion(2))
+        );
+        assert_eq!(
+            err(&|b| b[8] = 7),
+            error(8, BundleErrorKind::InvalidStage(7))
+        );
+        assert_eq!(
+            err(&|b| b[11] = 0x80),
+            error(8, BundleErrorKind::InvalidStage(0x8000_0001))
+        );
+        // the first variant starts at 32, and its entry point at 40
+        assert_eq!(
+            err(&|b| b[32] = 3),
+            error(32, BundleErrorKind::InvalidFormat(3))
+        );
+        assert_eq!(
+            err(&|b| b[40] = 0xff),
+            error(40, BundleErrorKind::InvalidEntrypoint)
+        );
+        assert_eq!(
+            err(&|b| b[40] = 0),
+            error(32, BundleErrorKind::InvalidEntrypoint)
+        );
+        // the second variant starts after the first one's 4 byte name and 3 bytes of code
+        assert_eq!(
+            err(&|b| b[51..55].copy_from_slice(&SDL_GPU_SHADERFORMAT_SPIRV.to_le_bytes())),
+            error(
+                51,
+                BundleErrorKind::DuplicateFormat(SDL_GPU_SHADERFORMAT_SPIRV)
+            )
+        );
+        assert_eq!(
+            err(&|b| b[28] = 4),
+            error(bytes.len(), BundleErrorKind::UnexpectedEof)
+        );
+        assert_eq!(
+            err(&|b| b[36] = 0xff),
+            error(bytes.len(), BundleErrorKind::UnexpectedEof)
+        );
+    }
+
+    #[test]
+    fn variants() {
+        assert_eq!(
+            ShaderVariant::new(
+                SDL_GPU_SHADERFORMAT_SPIRV | SDL_GPU_SHADERFORMAT_MSL,
+                "",
+                []
+            ),
+            Err(BundleErrorKind::InvalidFormat(
+                SDL_GPU_SHADERFORMAT_SPIRV | SDL_GPU_SHADERFORMAT_MSL
+            ))
+        );
+        assert_eq!(
+            ShaderVariant::new(0, "", []),
+            Err(BundleErrorKind::InvalidFormat(0))
+        );
+        assert_eq!(
+            ShaderVariant::new(1 << 20, "", []),
+            Err(BundleErrorKind::InvalidFormat(1 << 20))
+        );
+        assert_eq!(
+            ShaderVariant::new(SDL_GPU_SHADERFORMAT_SPIRV, "ma\0in", []),
+            Err(BundleErrorKind::InvalidEntrypoint)
+        );
+        let mut bundle = bundle();
+        assert_eq!(
+            bundle.push_variant(ShaderVariant::new(SDL_GPU_SHADERFORMAT_MSL, "x", []).unwrap()),
+            Err(BundleErrorKind::DuplicateFormat(SDL_GPU_SHADERFORMAT_MSL))
+        );
+        assert_eq!(bundle.variants().len(), 3);
+    }
+
+    #[test]
+    fn select() {
+        let bundle = bundle();
+        assert_eq!(
+            bundle.supported_formats(),
+            SDL_GPU_SHADERFORMAT_SPIRV | SDL_GPU_SHADERFORMAT_DXIL | SDL_GPU_SHADERFORMAT_MSL
+        );
+        let format = |formats| bundle.select(formats).map(|v| v.format());
+        assert_eq!(
+            format(SDL_GPU_SHADERFORMAT_MSL),
+            Some(SDL_GPU_SHADERFORMAT_MSL)
+        );
+        // the earlier variant wins
+        assert_eq!(
+            format(SDL_GPU_SHADERFORMAT_MSL | SDL_GPU_SHADERFORMAT_DXIL),
+            Some(SDL_GPU_SHADERFORMAT_DXIL)
+        );
+        assert_eq!(format(KNOWN_FORMATS), Some(SDL_GPU_SHADERFORMAT_SPIRV));
+        assert_eq!(
+            format(SDL_GPU_SHADERFORMAT_DXBC | SDL_GPU_SHADERFORMAT_METALLIB),
+            None
+        );
+        assert_eq!(format(0), None);
+
+        let variant = bundle.select(SDL_GPU_SHADERFORMAT_SPIRV).unwrap();
+        let info = bundle.create_info(variant);
+        assert_eq!(info.code_size, 3);
+        assert_eq!(info.format, SDL_GPU_SHADERFORMAT_SPIRV);
+        assert!(info.stage == SDL_GPUShaderStage::FRAGMENT);
+        assert_eq!(
+            [
+                info.num_samplers,
+                info.num_storage_textures,
+                info.num_storage_buffers,
+                info.num_uniform_buffers
+            ],
+            [1, 2, 3, 4]
+        );
+        assert_eq!(unsafe { CStr::from_ptr(info.entrypoint) }, c"main");
+    }
+}
@@ -0,0 +1,1138 @@
+//! Typed haptic effects and owned haptic devices.
+//!
+//! [`HapticEffect`] is a Rust enum for the [`SDL_HapticEffect`] union. Each kind of effect
+//! has its own struct with public fields and builder methods. Effects can be checked
+//! against what a device supports with [`HapticEffect::validate`] before creating them
+//! with [`Haptic::create_effect`], which returns an [`Effect`] that's destroyed on drop.
+//!
+//! All times are in milliseconds. Levels and magnitudes use the full range of their type.
+
+use crate::{error::check, Error, Result};
+use alloc::vec::Vec;
+use core::{
+    ffi::{c_int, CStr},
+    fmt::{self, Display},
+    ptr::NonNull,
+    slice,
+};
+use sdl3_sys::{
+    haptic::{
+        SDL_CloseHaptic, SDL_CreateHapticEffect, SDL_DestroyHapticEffect,
+        SDL_GetHapticEffectStatus, SDL_GetHapticFeatures, SDL_GetHapticID, SDL_GetHapticName,
+        SDL_GetHaptics, SDL_GetMaxHapticEffects, SDL_GetMaxHapticEffectsPlaying,
+        SDL_GetNumHapticAxes, SDL_Haptic, SDL_HapticCondition, SDL_HapticConstant,
+        SDL_HapticCustom, SDL_HapticDirection, SDL_HapticEffect, SDL_HapticID, SDL_HapticLeftRight,
+        SDL_HapticPeriodic, SDL_HapticRamp, SDL_InitHapticRumble, SDL_OpenHaptic,
+        SDL_OpenHapticFromJoystick, SDL_OpenHapticFromMouse, SDL_PauseHaptic, SDL_PlayHapticRumble,
+        SDL_ResumeHaptic, SDL_RunHapticEffect, SDL_SetHapticAutocenter, SDL_SetHapticGain,
+        SDL_StopHapticEffect, SDL_StopHapticEffects, SDL_StopHapticRumble, SDL_UpdateHapticEffect,
+        SDL_HAPTIC_CARTESIAN, SDL_HAPTIC_CONSTANT, SDL_HAPTIC_CUSTOM, SDL_HAPTIC_DAMPER,
+        SDL_HAPTIC_FRICTION, SDL_HAPTIC_INERTIA, SDL_HAPTIC_INFINITY, SDL_HAPTIC_LEFTRIGHT,
+        SDL_HAPTIC_POLAR, SDL_HAPTIC_RAMP, SDL_HAPTIC_SAWTOOTHDOWN, SDL_HAPTIC_SAWTOOTHUP,
+        SDL_HAPTIC_SINE, SDL_HAPTIC_SPHERICAL, SDL_HAPTIC_SPRING, SDL_HAPTIC_SQUARE,
+        SDL_HAPTIC_STEERING_AXIS, SDL_HAPTIC_TRIANGLE,
+    },
+    joystick::SDL_Joystick,
+    stdinc::SDL_free,
+};
+
+/// The direction of a haptic effect. See [`SDL_HapticDirection`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Direction in hundredths of a degree, where 0 is north and 9000 is east
+    Polar(i32),
+
+    /// Direction as an `[x, y, z]` vector
+    Cartesian([i32; 3]),
+
+    /// Direction as two angles in hundredths of a degree
+    Spherical([i32; 2]),
+
+    /// Use the steering axis of the device
+    SteeringAxis,
+}
+
+impl Default for Direction {
+    #[inline]
+    fn default() -> Self {
+        Self::Polar(0)
+    }
+}
+
+impl From<Direction> for SDL_HapticDirection {
+    fn from(value: Direction) -> Self {
+        let (r#type, dir) = match value {
+            Direction::Polar(angle) => (SDL_HAPTIC_POLAR, [angle, 0, 0]),
+            Direction::Cartesian(v) => (SDL_HAPTIC_CARTESIAN, v),
+            Direction::Spherical([a, b]) => (SDL_HAPTIC_SPHERICAL, [a, b, 0]),
+            Direction::SteeringAxis => (SDL_HAPTIC_STEERING_AXIS, [0; 3]),
+        };
+        Self { r#type, dir }
+    }
+}
+
+impl TryFrom<SDL_HapticDirection> for Direction {
+    type Error = HapticEffectError;
+
+    fn try_from(value: SDL_HapticDirection) -> Result<Self, Self::Error> {
+        let [a, b, c] = value.dir;
+        Ok(match value.r#type {
+            SDL_HAPTIC_POLAR => Self::Polar(a),
+            SDL_HAPTIC_CARTESIAN => Self::Cartesian([a, b, c]),
+            SDL_HAPTIC_SPHERICAL => Self::Spherical([a, b]),
+            SDL_HAPTIC_STEERING_AXIS => Self::SteeringAxis,
+            t => return Err(HapticEffectError::InvalidDirection(t)),
+        })
+    }
+}
+
+/// When and for how long an effect plays.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Timing {
+    /// Duration of the effect, or [`SDL_HAPTIC_INFINITY`]
+    pub length: u32,
+
+    /// Delay before the effect starts
+    pub delay: u16,
+
+    /// Button that triggers the effect, or 0
+    pub button: u16,
+
+    /// Minimum time between triggers
+    pub interval: u16,
+}
+
+impl Default for Timing {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            length: 1000,
+            delay: 0,
+            button: 0,
+            interval: 0,
+        }
+    }
+}
+
+/// Attack and fade of an effect.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Envelope {
+    /// Duration of the attack
+    pub attack_length: u16,
+
+    /// Level at the start of the attack
+    pub attack_level: u16,
+
+    /// Duration of the fade
+    pub fade_length: u16,
+
+    /// Level at the end of the fade
+    pub fade_level: u16,
+}
+
+macro_rules! builders {
+    ($ty:ident { $($field:ident),* }) => {
+        impl $ty {
+            $(builders!(@ $field);)*
+        }
+    };
+
+    (@ direction) => {
+        /// Set the direction of the effect.
+        #[inline]
+        pub fn direction(mut self, direction: Direction) -> Self {
+            self.direction = direction;
+            self
+        }
+    };
+
+    (@ timing) => {
+        /// Set the duration of the effect. Use [`SDL_HAPTIC_INFINITY`] to play it until stopped.
+        #[inline]
+        pub fn length(mut self, length: u32) -> Self {
+            self.timing.length = length;
+            self
+        }
+
+        /// Set the delay before the effect starts.
+        #[inline]
+        pub fn delay(mut self, delay: u16) -> Self {
+            self.timing.delay = delay;
+            self
+        }
+
+        /// Trigger the effect with a button, at most once per `interval`.
+        #[inline]
+        pub fn trigger(mut self, button: u16, interval: u16) -> Self {
+            self.timing.button = button;
+            self.timing.interval = interval;
+            self
+        }
+    };
+
+    (@ envelope) => {
+        /// Set the attack and fade of the effect.
+        #[inline]
+        pub fn envelope(mut self, envelope: Envelope) -> Self {
+            self.envelope = envelope;
+            self
+        }
+    };
+}
+
+/// A constant force effect.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ConstantEffect {
+    /// Direction of the effect
+    pub direction: Direction,
+    /// Timing of the effect
+    pub timing: Timing,
+    /// Strength of the effect
+    pub level: i16,
+    /// Attack and fade
+    pub envelope: Envelope,
+}
+
+impl ConstantEffect {
+    /// Create a constant effect with the given strength.
+    pub fn new(level: i16) -> Self {
+        Self {
+            level,
+            ..Default::default()
+        }
+    }
+}
+
+builders!(ConstantEffect {
+    direction,
+    timing,
+    envelope
+});
+
+/// The waveform of a [`PeriodicEffect`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Waveform {
+    /// [`SDL_HAPTIC_SINE`]
+    #[default]
+    Sine,
+    /// [`SDL_HAPTIC_SQUARE`]
+    Square,
+    /// [`SDL_HAPTIC_TRIANGLE`]
+    Triangle,
+    /// [`SDL_HAPTIC_SAWTOOTHUP`]
+    SawtoothUp,
+    /// [`SDL_HAPTIC_SAWTOOTHDOWN`]
+    SawtoothDown,
+}
+
+impl Waveform {
+    const fn to_sdl(self) -> u16 {
+        match self {
+            Self::Sine => SDL_HAPTIC_SINE,
+            Self::Square => SDL_HAPTIC_SQUARE,
+            Self::Triangle => SDL_HAPTIC_TRIANGLE,
+            Self::SawtoothUp => SDL_HAPTIC_SAWTOOTHUP,
+            Self::SawtoothDown => SDL_HAPTIC_SAWTOOTHDOWN,
+        }
+    }
+}
+
+/// A periodic effect.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PeriodicEffect {
+    /// Shape of the wave
+    pub waveform: Waveform,
+    /// Direction of the effect
+    pub direction: Direction,
+    /// Timing of the effect
+    pub timing: Timing,
+    /// Period of the wave
+    pub period: u16,
+    /// Peak value; negative values invert the wave
+    pub magnitude: i16,
+    /// Mean value of the wave
+    pub offset: i16,
+    /// Phase shift in hundredths of a degree
+    pub phase: u16,
+    /// Attack and fade
+    pub envelope: Envelope,
+}
+
+impl PeriodicEffect {
+    /// Create a periodic effect with the given waveform, period and magnitude.
+    pub fn new(waveform: Waveform, period: u16, magnitude: i16) -> Self {
+        Self {
+            waveform,
+            period,
+            magnitude,
+            ..Default::default()
+        }
+    }
+
+    /// Set the mean value of the wave.
+    #[inline]
+    pub fn offset(mut self, offset: i16) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Set the phase shift in hundredths of a degree.
+    #[inline]
+    pub fn phase(mut self, phase: u16) -> Self {
+        self.phase = phase;
+        self
+    }
+}
+
+builders!(PeriodicEffect {
+    direction,
+    timing,
+    envelope
+});
+
+/// The kind of a [`ConditionEffect`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ConditionKind {
+    /// [`SDL_HAPTIC_SPRING`]: based on axis position
+    #[default]
+    Spring,
+    /// [`SDL_HAPTIC_DAMPER`]: based on axis velocity
+    Damper,
+    /// [`SDL_HAPTIC_INERTIA`]: based on axis acceleration
+    Inertia,
+    /// [`SDL_HAPTIC_FRICTION`]: based on axis movement
+    Friction,
+}
+
+impl ConditionKind {
+    const fn to_sdl(self) -> u16 {
+        match self {
+            Self::Spring => SDL_HAPTIC_SPRING,
+            Self::Damper => SDL_HAPTIC_DAMPER,
+            Self::Inertia => SDL_HAPTIC_INERTIA,
+            Self::Friction => SDL_HAPTIC_FRICTION,
+        }
+    }
+}
+
+/// The parameters of a [`ConditionEffect`] for one axis.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ConditionAxis {
+    /// Level when the joystick is to the positive side
+    pub right_sat: u16,
+    /// Level when the joystick is to the negative side
+    pub left_sat: u16,
+    /// How fast to increase the force towards the positive side
+    pub right_coeff: i16,
+    /// How fast to increase the force towards the negative side
+    pub left_coeff: i16,
+    /// Size of the dead zone
+    pub deadband: u16,
+    /// Position of the dead zone
+    pub center: i16,
+}
+
+/// A condition effect, which depends on the position or movement of up to three axes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ConditionEffect {
+    /// Kind of condition
+    pub kind: ConditionKind,
+    /// Direction of the effect
+    pub direction: Direction,
+    /// Timing of the effect
+    pub timing: Timing,
+    /// Parameters per axis
+    pub axes: [ConditionAxis; 3],
+}
+
+impl ConditionEffect {
+    /// Create a condition effect with the same parameters for all axes.
+    pub fn new(kind: ConditionKind, axis: ConditionAxis) -> Self {
+        Self {
+            kind,
+            axes: [axis; 3],
+            ..Default::default()
+        }
+    }
+
+    /// Set the parameters of one axis. Axes other than 0, 1 and 2 are ignored.
+    #[inline]
+    pub fn axis(mut self, axis: usize, params: ConditionAxis) -> Self {
+        if let Some(axes) = self.axes.get_mut(axis) {
+            *axes = params;
+        }
+        self
+    }
+}
+
+builders!(ConditionEffect { direction, timing });
+
+/// A ramp effect, which changes linearly from `start` to `end`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RampEffect {
+    /// Direction of the effect
+    pub direction: Direction,
+    /// Timing of the effect
+    pub timing: Timing,
+    /// Level at the start
+    pub start: i16,
+    /// Level at the end
+    pub end: i16,
+    /// Attack and fade
+    pub envelope: Envelope,
+}
+
+impl RampEffect {
+    /// Create a ramp effect.
+    pub fn new(start: i16, end: i16) -> Self {
+        Self {
+            start,
+            end,
+            ..Default::default()
+        }
+    }
+}
+
+builders!(RampEffect {
+    direction,
+    timing,
+    envelope
+});
+
+/// A rumble effect with separate large (low frequency) and small (high frequency) motors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LeftRightEffect {
+    /// Duration of the effect, or [`SDL_HAPTIC_INFINITY`]
+    pub length: u32,
+    /// Strength of the large motor
+    pub large_magnitude: u16,
+    /// Strength of the small motor
+    pub small_magnitude: u16,
+}
+
+impl LeftRightEffect {
+    /// Create a left/right effect.
+    pub fn new(length: u32, large_magnitude: u16, small_magnitude: u16) -> Self {
+        Self {
+            length,
+            large_magnitude,
+            small_magnitude,
+        }
+    }
+}
+
+/// A custom effect with user defined samples.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CustomEffect {
+    /// Direction of the effect
+    pub direction: Direction,
+    /// Timing of the effect
+    pub timing: Timing,
+    /// Number of axes
+    pub channels: u8,
+    /// Duration of each sample
+    pub period: u16,
+    /// Samples, interleaved by channel. The length must be a multiple of `channels`.
+    pub data: Vec<u16>,
+    /// Attack and fade
+    pub envelope: Envelope,
+}
+
+impl CustomEffect {
+    /// Create a custom effect.
+    pub fn new(channels: u8, period: u16, data: Vec<u16>) -> Self {
+        Self {
+            channels,
+            period,
+            data,
+            ..Default::default()
+        }
+    }
+
+    fn samples(&self) -> Option<u16> {
+        let channels = self.channels as usize;
+        let samples = self.data.len().checked_div(channels)?;
+        (samples * channels == self.data.len())
+            .then(|| u16::try_from(samples).ok())
+            .flatten()
+    }
+}
+
+builders!(CustomEffect {
+    direction,
+    timing,
+    envelope
+});
+
+/// A haptic effect. See [`SDL_HapticEffect`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HapticEffect {
+    /// [`SDL_HAPTIC_CONSTANT`]
+    Constant(ConstantEffect),
+    /// [`SDL_HAPTIC_SINE`], [`SDL_HAPTIC_SQUARE`], [`SDL_HAPTIC_TRIANGLE`],
+    /// [`SDL_HAPTIC_SAWTOOTHUP`] or [`SDL_HAPTIC_SAWTOOTHDOWN`]
+    Periodic(PeriodicEffect),
+    /// [`SDL_HAPTIC_SPRING`], [`SDL_HAPTIC_DAMPER`], [`SDL_HAPTIC_INERTIA`] or
+    /// [`SDL_HAPTIC_FRICTION`]
+    Condition(ConditionEffect),
+    /// [`SDL_HAPTIC_RAMP`]
+    Ramp(RampEffect),
+    /// [`SDL_HAPTIC_LEFTRIGHT`]
+    LeftRight(LeftRightEffect),
+    /// [`SDL_HAPTIC_CUSTOM`]
+    Custom(CustomEffect),
+}
+
+impl HapticEffect {
+    /// Get the `SDL_HAPTIC_*` type of this effect.
+    pub fn effect_type(&self) -> u16 {
+        match self {
+            Self::Constant(_) => SDL_HAPTIC_CONSTANT,
+            Self::Periodic(e) => e.waveform.to_sdl(),
+            Self::Condition(e) => e.kind.to_sdl(),
+            Self::Ramp(_) => SDL_HAPTIC_RAMP,
+            Self::LeftRight(_) => SDL_HAPTIC_LEFTRIGHT,
+            Self::Custom(_) => SDL_HAPTIC_CUSTOM,
+        }
+    }
+
+    /// Check that this effect is well formed and supported by a device with the given
+    /// features (from [`SDL_GetHapticFeatures`]).
+    pub fn validate(&self, features: u32) -> Result<(), HapticEffectError> {
+        let ty = self.effect_type();
+        if features & ty as u32 == 0 {
+            return Err(HapticEffectError::Unsupported(ty));
+        }
+        if let Self::Custom(e) = self {
+            if e.samples().is_none() {
+                return Err(HapticEffectError::InvalidCustomData);
+            }
+        }
+        Ok(())
+    }
+
+    /// Convert this effect to an [`SDL_HapticEffect`].
+    ///
+    /// For custom effects, the result points to the sample data in `self`, so it's only
+    /// valid while `self` isn't modified or dropped.
+    pub fn to_sdl(&self) -> Result<SDL_HapticEffect, HapticEffectError> {
+        let r#type = self.effect_type();
+        Ok(match self {
+            Self::Constant(e) => SDL_HapticEffect {
+                constant: SDL_HapticConstant {
+                    r#type,
+                    direction: e.direction.into(),
+                    length: e.timing.length,
+                    delay: e.timing.delay,
+                    button: e.timing.button,
+                    interval: e.timing.interval,
+                    level: e.level,
+                    attack_length: e.envelope.attack_length,
+                    attack_level: e.envelope.attack_level,
+                    fade_length: e.envelope.fade_length,
+                    fade_level: e.envelope.fade_level,
+                },
+            },
+            Self::Periodic(e) => SDL_HapticEffect {
+                periodic: SDL_HapticPeriodic {
+                    r#type,
+                    direction: e.direction.into(),
+                    length: e.timing.length,
+                    delay: e.timing.delay,
+                    button: e.timing.button,
+                    interval: e.timing.interval,
+                    period: e.period,
+                    magnitude: e.magnitude,
+                    offset: e.offset,
+                    phase: e.phase,
+                    attack_length: e.envelope.attack_length,
+                    attack_level: e.envelope.attack_level,
+                    fade_length: e.envelope.fade_length,
+                    fade_level: e.envelope.fade_level,
+                },
+            },
+            Self::Condition(e) => SDL_HapticEffect {
+                condition: SDL_HapticCondition {
+                    r#type,
+                    direction: e.direction.into(),
+                    length: e.timing.length,
+                    delay: e.timing.delay,
+                    button: e.timing.button,
+                    interval: e.timing.interval,
+                    right_sat: e.axes.map(|a| a.right_sat),
+                    left_sat: e.axes.map(|a| a.left_sat),
+                    right_coeff: e.axes.map(|a| a.right_coeff),
+                    left_coeff: e.axes.map(|a| a.left_coeff),
+                    deadband: e.axes.map(|a| a.deadband),
+                    center: e.axes.map(|a| a.center),
+                },
+            },
+            Self::Ramp(e) => SDL_HapticEffect {
+                ramp: SDL_HapticRamp {
+                    r#type,
+                    direction: e.direction.into(),
+                    length: e.timing.length,
+                    delay: e.timing.delay,
+                    button: e.timing.button,
+                    interval: e.timing.interval,
+                    start: e.start,
+                    end: e.end,
+                    attack_length: e.envelope.attack_length,
+                    attack_level: e.envelope.attack_level,
+                    fade_length: e.envelope.fade_length,
+                    fade_level: e.envelope.fade_level,
+                },
+            },
+            Self::LeftRight(e) => SDL_HapticEffect {
+                leftright: SDL_HapticLeftRight {
+                    r#type,
+                    length: e.length,
+                    large_magnitude: e.large_magnitude,
+                    small_magnitude: e.small_magnitude,
+                },
+            },
+            Self::Custom(e) => SDL_HapticEffect {
+                custom: SDL_HapticCustom {
+                    r#type,
+                    direction: e.direction.into(),
+                    length: e.timing.length,
+                    delay: e.timing.delay,
+                    button: e.timing.button,
+                    interval: e.timing.interval,
+                    channels: e.channels,
+                    period: e.period,
+                    samples: e.samples().ok_or(HapticEffectError::InvalidCustomData)?,
+                    // SDL doesn't write to the data
+                    data: e.data.as_ptr() as *mut u16,
+                    attack_length: e.envelope.attack_length,
+                    attack_level: e.envelope.attack_level,
+                    fade_length: e.envelope.fade_length,
+                    fade_level: e.envelope.fade_level,
+                },
+            },
+        })
+    }
+
+    /// Convert an [`SDL_HapticEffect`] to a `HapticEffect`.
+    ///
+    /// # Safety
+    /// If `effect` is a custom effect with a non-null `data` pointer, it must point to
+    /// `channels * samples` valid `u16`s.
+    pub unsafe fn from_sdl(effect: &SDL_HapticEffect) -> Result<Self, HapticEffectError> {
+        // every variant of the union starts with the type, and all fields are plain data
+        let r#type = unsafe { effect.r#type };
+        let timing = |length, delay, button, interval| Timing {
+            length,
+            delay,
+            button,
+            interval,
+        };
+        let envelope = |attack_length, attack_level, fade_length, fade_level| Envelope {
+            attack_length,
+            attack_level,
+            fade_length,
+            fade_level,
+        };
+        Ok(match r#type {
+            SDL_HAPTIC_CONSTANT => {
+                let e = unsafe { effect.constant };
+                Self::Constant(ConstantEffect {
+                    direction: e.direction.try_into()?,
+                    timing: timing(e.length, e.delay, e.button, e.interval),
+                    level: e.level,
+                    envelope: envelope(
+                        e.attack_length,
+                        e.attack_level,
+                        e.fade_length,
+                        e.fade_level,
+                    ),
+                })
+            }
+            SDL_HAPTIC_SINE
+            | SDL_HAPTIC_SQUARE
+            | SDL_HAPTIC_TRIANGLE
+            | SDL_HAPTIC_SAWTOOTHUP
+            | SDL_HAPTIC_SAWTOOTHDOWN => {
+                let e = unsafe { effect.periodic };
+                Self::Periodic(PeriodicEffect {
+                    waveform: match r#type {
+                        SDL_HAPTIC_SINE => Waveform::Sine,
+                        SDL_HAPTIC_SQUARE => Waveform::Square,
+                        SDL_HAPTIC_TRIANGLE => Waveform::Triangle,
+                        SDL_HAPTIC_SAWTOOTHUP => Waveform::SawtoothUp,
+                        _ => Waveform::SawtoothDown,
+                    },
+                    direction: e.direction.try_into()?,
+                    timing: timing(e.length, e.delay, e.button, e.interval),
+                    period: e.period,
+                    magnitude: e.magnitude,
+                    offset: e.offset,
+                    phase: e.phase,
+                    envelope: envelope(
+                        e.attack_length,
+                        e.attack_level,
+                        e.fade_length,
+                        e.fade_level,
+                    ),
+                })
+            }
+            SDL_HAPTIC_SPRING | SDL_HAPTIC_DAMPER | SDL_HAPTIC_INERTIA | SDL_HAPTIC_FRICTION => {
+                let e = unsafe { effect.condition };
+                Self::Condition(ConditionEffect {
+                    kind: match r#type {
+                        SDL_HAPTIC_SPRING => ConditionKind::Spring,
+                        SDL_HAPTIC_DAMPER => ConditionKind::Damper,
+                        SDL_HAPTIC_INERTIA => ConditionKind::Inertia,
+                        _ => ConditionKind::Friction,
+                    },
+                    direction: e.direction.try_into()?,
+                    timing: timing(e.length, e.delay, e.button, e.interval),
+                    axes: core::array::from_fn(|i| ConditionAxis {
+                        right_sat: e.right_sat[i],
+                        left_sat: e.left_sat[i],
+                        right_coeff: e.right_coeff[i],
+                        left_coeff: e.left_coeff[i],
+                        deadband: e.deadband[i],
+                        center: e.center[i],
+                    }),
+                })
+            }
+            SDL_HAPTIC_RAMP => {
+                let e = unsafe { effect.ramp };
+                Self::Ramp(RampEffect {
+                    direction: e.direction.try_into()?,
+                    timing: timing(e.length, e.delay, e.button, e.interval),
+                    start: e.start,
+                    end: e.end,
+                    envelope: envelope(
+                        e.attack_length,
+                        e.attack_level,
+                        e.fade_length,
+                        e.fade_level,
+                    ),
+                })
+            }
+            SDL_HAPTIC_LEFTRIGHT => {
+                let e = unsafe { effect.leftright };
+                Self::LeftRight(LeftRightEffect {
+                    length: e.length,
+                    large_magnitude: e.large_magnitude,
+                    small_magnitude: e.small_magnitude,
+                })
+            }
+            SDL_HAPTIC_CUSTOM => {
+                let e = unsafe { effect.custom };
+                let len = e.channels as usize * e.samples as usize;
+                let data = if e.data.is_null() || len == 0 {
+                    Vec::new()
+                } else {
+                    unsafe { slice::from_raw_parts(e.data, len) }.to_vec()
+                };
+                Self::Custom(CustomEffect {
+                    direction: e.direction.try_into()?,
+                    timing: timing(e.length, e.delay, e.button, e.interval),
+                    channels: e.channels,
+                    period: e.period,
+                    data,
+                    envelope: envelope(
+                        e.attack_length,
+                        e.attack_level,
+                        e.fade_length,
+                        e.fade_level,
+                    ),
+                })
+            }
+            t => return Err(HapticEffectError::InvalidType(t)),
+        })
+    }
+}
+
+macro_rules! impl_from {
+    ($($variant:ident($ty:ty)),*) => {$(
+        impl From<$ty> for HapticEffect {
+            #[inline(always)]
+            fn from(value: $ty) -> Self {
+                Self::$variant(value)
+            }
+        }
+    )*};
+}
+
+impl_from!(
+    Constant(ConstantEffect),
+    Periodic(PeriodicEffect),
+    Condition(ConditionEffect),
+    Ramp(RampEffect),
+    LeftRight(LeftRightEffect),
+    Custom(CustomEffect)
+);
+
+/// An error from converting or validating a [`HapticEffect`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HapticEffectError {
+    /// The effect type isn't a known `SDL_HAPTIC_*` effect
+    InvalidType(u16),
+
+    /// The direction type isn't a known `SDL_HAPTIC_*` direction
+    InvalidDirection(u8),
+
+    /// The device doesn't support this effect type
+    Unsupported(u16),
+
+    /// The custom effect has no channels, or the sample data isn't a multiple of the
+    /// number of channels
+    InvalidCustomData,
+}
+
+impl Display for HapticEffectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidType(t) => write!(f, "invalid haptic effect type {t:#x}"),
+            Self::InvalidDirection(t) => write!(f, "invalid haptic direction type {t}"),
+            Self::Unsupported(t) => write!(f, "haptic effect type {t:#x} isn't supported"),
+            Self::InvalidCustomData => f.write_str("invalid custom haptic effect data"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HapticEffectError {}
+
+impl From<HapticEffectError> for Error {
+    fn from(value: HapticEffectError) -> Self {
+        Error::new(alloc::format!("{value}"))
+    }
+}
+
+/// An opened haptic device. It's closed when this is dropped.
+pub struct Haptic {
+    ptr: NonNull<SDL_Haptic>,
+}
+
+impl Haptic {
+    unsafe fn from_opened(ptr: *mut SDL_Haptic) -> Result<Self> {
+        NonNull::new(ptr)
+            .map(|ptr| Self { ptr })
+            .ok_or_else(Error::from_sdl)
+    }
+
+    /// Open a haptic device. See [`SDL_OpenHaptic`].
+    pub fn open(id: SDL_HapticID) -> Result<Self> {
+        unsafe { Self::from_opened(SDL_OpenHaptic(id)) }
+    }
+
+    /// Open the haptic device of the mouse. See [`SDL_OpenHapticFromMouse`].
+    pub fn open_from_mouse() -> Result<Self> {
+        unsafe { Self::from_opened(SDL_OpenHapticFromMouse()) }
+    }
+
+    /// Open the haptic device of a joystick. See [`SDL_OpenHapticFromJoystick`].
+    ///
+    /// # Safety
+    /// `joystick` must be a valid, open joystick, and must stay open while this exists.
+    pub unsafe fn open_from_joystick(joystick: *mut SDL_Joystick) -> Result<Self> {
+        unsafe { Self::from_opened(SDL_OpenHapticFromJoystick(joystick)) }
+    }
+
+    /// Get the raw haptic pointer. The device is owned by `self`.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *mut SDL_Haptic {
+        self.ptr.as_ptr()
+    }
+
+    /// Get the instance ID of the device.
+    pub fn id(&self) -> SDL_HapticID {
+        unsafe { SDL_GetHapticID(self.as_ptr()) }
+    }
+
+    /// Get the name of the device.
+    pub fn name(&self) -> Option<&CStr> {
+        let name = unsafe { SDL_GetHapticName(self.as_ptr()) };
+        (!name.is_null()).then(|| unsafe { CStr::from_ptr(name) })
+    }
+
+    /// Get the supported features, as a combination of `SDL_HAPTIC_*` flags.
+    pub fn features(&self) -> u32 {
+        unsafe { SDL_GetHapticFeatures(self.as_ptr()) }
+    }
+
+    /// Get the number of axes of the device.
+    pub fn num_axes(&self) -> Result<usize> {
+        let n = unsafe { SDL_GetNumHapticAxes(self.as_ptr()) };
+        usize::try_from(n).map_err(|_| Error::from_sdl())
+    }
+
+    /// Get the number of effects the device can store.
+    pub fn max_effects(&self) -> Result<usize> {
+        let n = unsafe { SDL_GetMaxHapticEffects(self.as_ptr()) };
+        usize::try_from(n).map_err(|_| Error::from_sdl())
+    }
+
+    /// Get the number of effects the device can play at the same time.
+    pub fn max_effects_playing(&self) -> Result<usize> {
+        let n = unsafe { SDL_GetMaxHapticEffectsPlaying(self.as_ptr()) };
+        usize::try_from(n).map_err(|_| Error::from_sdl())
+    }
+
+    /// Validate an effect against the features of this device and upload it.
+    /// See [`SDL_CreateHapticEffect`].
+    pub fn create_effect(&self, effect: &HapticEffect) -> Result<Effect<'_>> {
+        effect.validate(self.features())?;
+        // keep a copy of custom data alive, since SDL keeps the pointer
+        let effect = effect.clone();
+        let raw = effect.to_sdl()?;
+        let id = unsafe { SDL_CreateHapticEffect(self.as_ptr(), &raw) };
+        if id < 0 {
+            return Err(Error::from_sdl());
+        }
+        Ok(Effect {
+            haptic: self,
+            id,
+            effect,
+        })
+    }
+
+    /// Set the global gain, from 0 to 100. See [`SDL_SetHapticGain`].
+    pub fn set_gain(&self, gain: u8) -> Result<()> {
+        check(unsafe { SDL_SetHapticGain(self.as_ptr(), gain as c_int) })
+    }
+
+    /// Set the autocenter strength, from 0 (off) to 100. See [`SDL_SetHapticAutocenter`].
+    pub fn set_autocenter(&self, autocenter: u8) -> Result<()> {
+        check(unsafe { SDL_SetHapticAutocenter(self.as_ptr(), autocenter as c_int) })
+    }
+
+    /// Pause all effects. See [`SDL_PauseHaptic`].
+    pub fn pause(&self) -> Result<()> {
+        check(unsafe { SDL_PauseHaptic(self.as_ptr()) })
+    }
+
+    /// Resume all effects. See [`SDL_ResumeHaptic`].
+    pub fn resume(&self) -> Result<()> {
+        check(unsafe { SDL_ResumeHaptic(self.as_ptr()) })
+    }
+
+    /// Stop all effects. See [`SDL_StopHapticEffects`].
+    pub fn stop_all(&self) -> Result<()> {
+        check(unsafe { SDL_StopHapticEffects(self.as_ptr()) })
+    }
+
+    /// Initialize simple rumble. See [`SDL_InitHapticRumble`].
+    pub fn init_rumble(&self) -> Result<()> {
+        check(unsafe { SDL_InitHapticRumble(self.as_ptr()) })
+    }
+
+    /// Play a simple rumble, with `strength` from 0.0 to 1.0. See [`SDL_PlayHapticRumble`].
+    pub fn play_rumble(&self, strength: f32, length: u32) -> Result<()> {
+        check(unsafe { SDL_PlayHapticRumble(self.as_ptr(), strength, length) })
+    }
+
+    /// Stop the simple rumble. See [`SDL_StopHapticRumble`].
+    pub fn stop_rumble(&self) -> Result<()> {
+        check(unsafe { SDL_StopHapticRumble(self.as_ptr()) })
+    }
+}
+
+impl Drop for Haptic {
+    fn drop(&mut self) {
+        unsafe { SDL_CloseHaptic(self.as_ptr()) }
+    }
+}
+
+/// An effect uploaded to a [`Haptic`] device. It's destroyed when this is dropped.
+pub struct Effect<'h> {
+    haptic: &'h Haptic,
+    id: c_int,
+    effect: HapticEffect,
+}
+
+impl Effect<'_> {
+    /// Get the raw effect ID.
+    #[inline(always)]
+    pub fn id(&self) -> c_int {
+        self.id
+    }
+
+    /// Get the current parameters of the effect.
+    #[inline(always)]
+    pub fn effect(&self) -> &HapticEffect {
+        &self.effect
+    }
+
+    /// Run the effect `iterations` times, or until stopped if `None`.
+    /// See [`SDL_RunHapticEffect`].
+    pub fn run(&self, iterations: Option<u32>) -> Result<()> {
+        let iterations = iterations.unwrap_or(SDL_HAPTIC_INFINITY);
+        check(unsafe { SDL_RunHapticEffect(self.haptic.as_ptr(), self.id, iterations) })
+    }
+
+    /// Stop the effect. See [`SDL_StopHapticEffect`].
+    pub fn stop(&self) -> Result<()> {
+        check(unsafe { SDL_StopHapticEffect(self.haptic.as_ptr(), self.id) })
+    }
+
+    /// Returns `true` if the effect is playing. See [`SDL_GetHapticEffectStatus`].
+    ///
+    /// This requires the `SDL_HAPTIC_STATUS` feature.
+    pub fn is_playing(&self) -> bool {
+        unsafe { SDL_GetHapticEffectStatus(self.haptic.as_ptr(), self.id) }
+    }
+
+    /// Update the parameters of the effect. The effect type can't be changed.
+    /// See [`SDL_UpdateHapticEffect`].
+    pub fn update(&mut self, effect: &HapticEffect) -> Result<()> {
+        if effect.effect_type() != self.effect.effect_type() {
+            return Err(Error::new("can't change the type of a haptic effect"));
+        }
+        let effect = effect.clone();
+        let raw = effect.to_sdl()?;
+        check(unsafe { SDL_UpdateHapticEffect(self.haptic.as_ptr(), self.id, &raw) })?;
+        self.effect = effect;
+        Ok(())
+    }
+}
+
+impl Drop for Effect<'_> {
+    fn drop(&mut self) {
+        unsafe { SDL_DestroyHapticEffect(self.haptic.as_ptr(), self.id) }
+    }
+}
+
+/// Get the instance IDs of the connected haptic devices. See [`SDL_GetHaptics`].
+pub fn haptics() -> Result<Vec<SDL_HapticID>> {
+    let mut count = 0;
+    let ids = unsafe { SDL_GetHaptics(&mut count) };
+    if ids.is_null() {
+        return Err(Error::from_sdl());
+    }
+    let vec = unsafe { slice::from_raw_parts(ids, count as usize) }.to_vec();
+    unsafe { SDL_free(ids.cast()) };
+    Ok(vec)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn round_trip(effect: HapticEffect) {
+        let sdl = effect.to_sdl().unwrap();
+        assert_eq!(unsafe { sdl.r#type }, effect.effect_type());
+        assert_eq!(unsafe { HapticEffect::from_sdl(&sdl) }.unwrap(), effect);
+    }
+
+    #[test]
+    fn round_trip_variants() {
+        let envelope = Envelope {
+            attack_length: 1,
+            attack_level: 2,
+            fade_length: 3,
+            fade_level: 4,
+        };
+        round_trip(
+            ConstantEffect::new(-1234)
+                .direction(Direction::Cartesian([1, -2, 3]))
+                .length(SDL_HAPTIC_INFINITY)
+                .delay(5)
+                .trigger(6, 7)
+                .envelope(envelope)
+                .into(),
+        );
+        for waveform in [
+            Waveform::Sine,
+            Waveform::Square,
+            Waveform::Triangle,
+            Waveform::SawtoothUp,
+            Waveform::SawtoothDown,
+        ] {
+            round_trip(
+                PeriodicEffect::new(waveform, 100, -200)
+                    .offset(30)
+                    .phase(9000)
+                    .direction(Direction::Polar(18000))
+                    .envelope(envelope)
+                    .into(),
+            );
+        }
+        for kind in [
+            ConditionKind::Spring,
+            ConditionKind::Damper,
+            ConditionKind::Inertia,
+            ConditionKind::Friction,
+        ] {
+            let axis = |i| ConditionAxis {
+                right_sat: i,
+                left_sat: i + 1,
+                right_coeff: -(i as i16),
+                left_coeff: i as i16 * 2,
+                deadband: i + 2,
+                center: -(i as i16) * 3,
+            };
+            round_trip(
+                ConditionEffect::new(kind, axis(1))
+                    .axis(1, axis(10))
+                    .axis(2, axis(20))
+                    .direction(Direction::Spherical([100, 200]))
+                    .into(),
+            );
+        }
+        round_trip(
+            RampEffect::new(-100, 100)
+                .direction(Direction::SteeringAxis)
+                .envelope(envelope)
+                .into(),
+        );
+        round_trip(LeftRightEffect::new(500, 1000, 2000).into());
+        round_trip(
+            CustomEffect::new(2, 10, vec![1, 2, 3, 4, 5, 6])
+                .delay(3)
+                .envelope(envelope)
+                .into(),
+        );
+    }
+
+    #[test]
+    fn condition_axis() {
+        let axis = ConditionAxis {
+            right_sat: 1,
+            ..Default::default()
+        };
+        let effect = ConditionEffect::default().axis(2, axis).axis(3, axis);
+        assert_eq!(effect.axes, [Default::default(), Default::default(), axis]);
+    }
+
+    #[test]
+    fn invalid() {
+        let effect: HapticEffect = CustomEffect::new(2, 10, vec![1, 2, 3]).into();
+        assert_eq!(
+            effect.to_sdl().err(),
+            Some(HapticEffectError::InvalidCustomData)
+        );
+        assert_eq!(
+            effect.validate(SDL_HAPTIC_CUSTOM as u32),
+            Err(HapticEffectError::InvalidCustomData)
+        );
+        let effect: HapticEffect = CustomEffect::new(0, 10, vec![]).into();
+        assert!(effect.to_sdl().is_err());
+
+        let effect: HapticEffect = ConstantEffect::new(1).into();
+        assert_eq!(
+            effect.validate(SDL_HAPTIC_SINE as u32),
+            Err(HapticEffectError::Unsupported(SDL_HAPTIC_CONSTANT))
+        );
+        assert_eq!(effect.validate(SDL_HAPTIC_CONSTANT as u32), Ok(()));
+
+        let mut sdl = effect.to_sdl().unwrap();
+        sdl.r#type = 0x1000;
+        assert_eq!(
+            unsafe { HapticEffect::from_sdl(&sdl) },
+            Err(HapticEffectError::InvalidType(0x1000))
+        );
+        let mut sdl = effect.to_sdl().unwrap();
+        sdl.constant.direction.r#type = 99;
+        assert_eq!(
+            unsafe { HapticEffect::from_sdl(&sdl) },
+            Err(HapticEffectError::InvalidDirection(99))
+        );
+    }
+}
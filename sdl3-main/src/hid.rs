@@ -0,0 +1,780 @@
+//! Safe wrappers for the HID API in [`sdl3_sys::hidapi`].
+//!
+//! [`enumerate`] lists connected devices, and [`HidDevice`] owns an open device and provides
+//! report I/O. HID strings are wide strings ([`c_wchar_t`]), which are converted to
+//! [`String`]s. [`ReportDescriptorItems`] and [`usages`] parse report descriptors.
+//!
+//! SDL initializes the HID API when it's first used. Call
+//! [`SDL_hid_exit`](sdl3_sys::hidapi::SDL_hid_exit) to release its resources when you're done.
+
+use crate::{Error, Result};
+use alloc::{
+    ffi::CString,
+    string::String,
+    vec::{self, Vec},
+};
+use core::{
+    ffi::{c_int, CStr},
+    fmt::{self, Display},
+    mem::size_of,
+    ptr::{self, NonNull},
+    slice,
+    time::Duration,
+};
+use sdl3_sys::{
+    ffi::c_wchar_t,
+    hidapi::{
+        SDL_hid_bus_type, SDL_hid_close, SDL_hid_device, SDL_hid_device_info, SDL_hid_enumerate,
+        SDL_hid_free_enumeration, SDL_hid_get_device_info, SDL_hid_get_feature_report,
+        SDL_hid_get_indexed_string, SDL_hid_get_input_report, SDL_hid_get_manufacturer_string,
+        SDL_hid_get_product_string, SDL_hid_get_report_descriptor,
+        SDL_hid_get_serial_number_string, SDL_hid_open, SDL_hid_open_path, SDL_hid_read,
+        SDL_hid_read_timeout, SDL_hid_send_feature_report, SDL_hid_set_nonblocking, SDL_hid_write,
+    },
+};
+
+// max length of strings read from devices, in wide chars
+const MAX_STRING_LEN: usize = 256;
+
+// max size of a report descriptor according to hidapi
+const MAX_REPORT_DESCRIPTOR_SIZE: usize = 4096;
+
+/// Convert a slice of wide chars to a `String`, replacing invalid characters with
+/// U+FFFD. The slice ends at the first nul, if any.
+///
+/// `c_wchar_t` is UTF-16 on Windows and UTF-32 elsewhere.
+#[allow(clippy::unnecessary_cast)] // the type of `c_wchar_t` depends on the target
+pub fn wide_to_string(s: &[c_wchar_t]) -> String {
+    let len = s.iter().position(|&c| c == 0).unwrap_or(s.len());
+    let s = &s[..len];
+    if size_of::<c_wchar_t>() == 2 {
+        char::decode_utf16(s.iter().map(|&c| c as u16))
+            .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+            .collect()
+    } else {
+        s.iter()
+            .map(|&c| char::from_u32(c as u32).unwrap_or(char::REPLACEMENT_CHARACTER))
+            .collect()
+    }
+}
+
+/// Convert a nul terminated wide string to a `String`. Returns `None` if `s` is null.
+///
+/// # Safety
+/// `s` must be null or point to a nul terminated wide string.
+pub unsafe fn wide_ptr_to_string(s: *const c_wchar_t) -> Option<String> {
+    if s.is_null() {
+        return None;
+    }
+    let mut len = 0;
+    while unsafe { *s.add(len) } != 0 {
+        len += 1;
+    }
+    Some(wide_to_string(unsafe { slice::from_raw_parts(s, len) }))
+}
+
+/// Convert a `str` to a nul terminated wide string.
+#[allow(clippy::unnecessary_cast)] // the type of `c_wchar_t` depends on the target
+pub fn str_to_wide(s: &str) -> Vec<c_wchar_t> {
+    let mut wide: Vec<c_wchar_t> = if size_of::<c_wchar_t>() == 2 {
+        s.encode_utf16().map(|c| c as c_wchar_t).collect()
+    } else {
+        s.chars().map(|c| c as u32 as c_wchar_t).collect()
+    };
+    wide.push(0);
+    wide
+}
+
+/// Information about a HID device. See [`SDL_hid_device_info`].
+#[derive(Clone)]
+pub struct HidDeviceInfo {
+    /// Platform specific device path, for [`HidDevice::open_path`]
+    pub path: CString,
+    /// Vendor ID
+    pub vendor_id: u16,
+    /// Product ID
+    pub product_id: u16,
+    /// Serial number
+    pub serial_number: Option<String>,
+    /// Device release number in binary coded decimal
+    pub release_number: u16,
+    /// Manufacturer name
+    pub manufacturer: Option<String>,
+    /// Product name
+    pub product: Option<String>,
+    /// Usage page of this device or interface
+    pub usage_page: u16,
+    /// Usage of this device or interface
+    pub usage: u16,
+    /// USB interface number, or -1 if unknown
+    pub interface_number: i32,
+    /// USB interface class
+    pub interface_class: i32,
+    /// USB interface subclass
+    pub interface_subclass: i32,
+    /// USB interface protocol
+    pub interface_protocol: i32,
+    /// The bus the device is connected to
+    pub bus_type: SDL_hid_bus_type,
+}
+
+impl HidDeviceInfo {
+    /// Copy the info from an [`SDL_hid_device_info`].
+    ///
+    /// # Safety
+    /// The strings in `info` must be null or valid nul terminated strings.
+    pub unsafe fn from_sdl(info: &SDL_hid_device_info) -> Self {
+        Self {
+            path: if info.path.is_null() {
+                CString::default()
+            } else {
+                unsafe { CStr::from_ptr(info.path) }.into()
+            },
+            vendor_id: info.vendor_id,
+            product_id: info.product_id,
+            serial_number: unsafe { wide_ptr_to_string(info.serial_number) },
+            release_number: info.release_number,
+            manufacturer: unsafe { wide_ptr_to_string(info.manufacturer_string) },
+            product: unsafe { wide_ptr_to_string(info.product_string) },
+            usage_page: info.usage_page,
+            usage: info.usage,
+            interface_number: info.interface_number,
+            interface_class: info.interface_class,
+            interface_subclass: info.interface_subclass,
+            interface_protocol: info.interface_protocol,
+            bus_type: info.bus_type,
+        }
+    }
+}
+
+impl fmt::Debug for HidDeviceInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HidDeviceInfo")
+            .field("path", &self.path)
+            .field("vendor_id", &self.vendor_id)
+            .field("product_id", &self.product_id)
+            .field("serial_number", &self.serial_number)
+            .field("release_number", &self.release_number)
+            .field("manufacturer", &self.manufacturer)
+            .field("product", &self.product)
+            .field("usage_page", &self.usage_page)
+            .field("usage", &self.usage)
+            .field("interface_number", &self.interface_number)
+            .field("interface_class", &self.interface_class)
+            .field("interface_subclass", &self.interface_subclass)
+            .field("interface_protocol", &self.interface_protocol)
+            .field("bus_type", &self.bus_type.0)
+            .finish()
+    }
+}
+
+/// Enumerate connected HID devices matching `vendor_id` and `product_id`, where 0 matches
+/// any ID. See [`SDL_hid_enumerate`].
+pub fn enumerate(vendor_id: u16, product_id: u16) -> HidEnumeration {
+    let head = unsafe { SDL_hid_enumerate(vendor_id, product_id) };
+    HidEnumeration { head, next: head }
+}
+
+/// Iterator over the devices found by [`enumerate`]. The device list is freed when this is
+/// dropped.
+pub struct HidEnumeration {
+    head: *mut SDL_hid_device_info,
+    next: *mut SDL_hid_device_info,
+}
+
+impl Iterator for HidEnumeration {
+    type Item = HidDeviceInfo;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let info = unsafe { self.next.as_ref() }?;
+        self.next = info.next;
+        Some(unsafe { HidDeviceInfo::from_sdl(info) })
+    }
+}
+
+impl Drop for HidEnumeration {
+    fn drop(&mut self) {
+        if !self.head.is_null() {
+            unsafe { SDL_hid_free_enumeration(self.head) }
+        }
+    }
+}
+
+/// How long to wait for an input report.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HidTimeout {
+    /// Return immediately if no report is available
+    NonBlocking,
+
+    /// Wait until a report is available, unless the device has been put in nonblocking mode
+    /// with [`HidDevice::set_nonblocking`]
+    Blocking,
+
+    /// Wait at most this long. The duration is rounded down to milliseconds.
+    Wait(Duration),
+}
+
+fn check_len(len: c_int) -> Result<usize> {
+    usize::try_from(len).map_err(|_| Error::from_sdl())
+}
+
+/// An open HID device. It's closed when this is dropped.
+pub struct HidDevice {
+    ptr: NonNull<SDL_hid_device>,
+}
+
+unsafe impl Send for HidDevice {}
+
+impl HidDevice {
+    /// Open a device by vendor and product ID, and optionally serial number.
+    /// See [`SDL_hid_open`].
+    pub fn open(vendor_id: u16, product_id: u16, serial_number: Option<&str>) -> Result<Self> {
+        let serial = serial_number.map(str_to_wide);
+        let serial_ptr = serial.as_ref().map_or(ptr::null(), |s| s.as_ptr());
+        let ptr = unsafe { SDL_hid_open(vendor_id, product_id, serial_ptr) };
+        NonNull::new(ptr)
+            .map(|ptr| Self { ptr })
+            .ok_or_else(Error::from_sdl)
+    }
+
+    /// Open a device by its platform specific path. See [`SDL_hid_open_path`].
+    pub fn open_path(path: &CStr) -> Result<Self> {
+        let ptr = unsafe { SDL_hid_open_path(path.as_ptr()) };
+        NonNull::new(ptr)
+            .map(|ptr| Self { ptr })
+            .ok_or_else(Error::from_sdl)
+    }
+
+    /// Get the raw device pointer. The device is owned by `self`.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *mut SDL_hid_device {
+        self.ptr.as_ptr()
+    }
+
+    /// Write an output report. See [`SDL_hid_write`].
+    ///
+    /// The first byte of `data` must be the report ID, or 0 if the device only uses one
+    /// report. Returns the number of bytes written.
+    pub fn write(&mut self, data: &[u8]) -> Result<usize> {
+        check_len(unsafe { SDL_hid_write(self.as_ptr(), data.as_ptr(), data.len()) })
+    }
+
+    /// Read an input report into `buf`. See [`SDL_hid_read`] and [`SDL_hid_read_timeout`].
+    ///
+    /// Returns the number of bytes read, or 0 if no report arrived before the timeout.
+    pub fn read(&mut self, buf: &mut [u8], timeout: HidTimeout) -> Result<usize> {
+        let (dev, data, len) = (self.as_ptr(), buf.as_mut_ptr(), buf.len());
+        check_len(unsafe {
+            match timeout {
+                HidTimeout::NonBlocking => SDL_hid_read_timeout(dev, data, len, 0),
+                HidTimeout::Blocking => SDL_hid_read(dev, data, len),
+                HidTimeout::Wait(d) => SDL_hid_read_timeout(
+                    dev,
+                    data,
+                    len,
+                    d.as_millis().min(c_int::MAX as u128) as c_int,
+                ),
+            }
+        })
+    }
+
+    /// Set whether [`read`](Self::read) with [`HidTimeout::Blocking`] blocks.
+    /// See [`SDL_hid_set_nonblocking`].
+    pub fn set_nonblocking(&mut self, nonblocking: bool) -> Result<()> {
+        check_len(unsafe { SDL_hid_set_nonblocking(self.as_ptr(), nonblocking as c_int) })
+            .map(|_| ())
+    }
+
+    /// Send a feature report. See [`SDL_hid_send_feature_report`].
+    ///
+    /// The first byte of `data` must be the report ID, or 0. Returns the number of bytes
+    /// written.
+    pub fn send_feature_report(&mut self, data: &[u8]) -> Result<usize> {
+        check_len(unsafe { SDL_hid_send_feature_report(self.as_ptr(), data.as_ptr(), data.len()) })
+    }
+
+    /// Get a feature report. See [`SDL_hid_get_feature_report`].
+    ///
+    /// The report with ID `report_id` is read into `buf`, including the report ID in the
+    /// first byte. Returns the number of bytes read.
+    pub fn get_feature_report(&mut self, report_id: u8, buf: &mut [u8]) -> Result<usize> {
+        let first = buf
+            .first_mut()
+            .ok_or_else(|| Error::new("buffer is empty"))?;
+        *first = report_id;
+        check_len(unsafe { SDL_hid_get_feature_report(self.as_ptr(), buf.as_mut_ptr(), buf.len()) })
+    }
+
+    /// Get an input report. See [`SDL_hid_get_input_report`].
+    ///
+    /// The report with ID `report_id` is read into `buf`, including the report ID in the
+    /// first byte. Returns the number of bytes read.
+    pub fn get_input_report(&mut self, report_id: u8, buf: &mut [u8]) -> Result<usize> {
+        let first = buf
+            .first_mut()
+            .ok_or_else(|| Error::new("buffer is empty"))?;
+        *first = report_id;
+        check_len(unsafe { SDL_hid_get_input_report(self.as_ptr(), buf.as_mut_ptr(), buf.len()) })
+    }
+
+    fn string(
+        &self,
+        f: impl FnOnce(*mut SDL_hid_device, *mut c_wchar_t, usize) -> c_int,
+    ) -> Result<String> {
+        let mut buf: [c_wchar_t; MAX_STRING_LEN] = [0; MAX_STRING_LEN];
+        check_len(f(self.as_ptr(), buf.as_mut_ptr(), buf.len()))?;
+        Ok(wide_to_string(&buf))
+    }
+
+    /// Get the manufacturer name. See [`SDL_hid_get_manufacturer_string`].
+    pub fn manufacturer(&self) -> Result<String> {
+        self.string(|dev, buf, len| unsafe { SDL_hid_get_manufacturer_string(dev, buf, len) })
+    }
+
+    /// Get the product name. See [`SDL_hid_get_product_string`].
+    pub fn product(&self) -> Result<String> {
+        self.string(|dev, buf, len| unsafe { SDL_hid_get_product_string(dev, buf, len) })
+    }
+
+    /// Get the serial number. See [`SDL_hid_get_serial_number_string`].
+    pub fn serial_number(&self) -> Result<String> {
+        self.string(|dev, buf, len| unsafe { SDL_hid_get_serial_number_string(dev, buf, len) })
+    }
+
+    /// Get a string by its index. See [`SDL_hid_get_indexed_string`].
+    pub fn indexed_string(&self, index: i32) -> Result<String> {
+        self.string(|dev, buf, len| unsafe { SDL_hid_get_indexed_string(dev, index, buf, len) })
+    }
+
+    /// Get information about the device. See [`SDL_hid_get_device_info`].
+    pub fn info(&self) -> Result<HidDeviceInfo> {
+        let info = unsafe { SDL_hid_get_device_info(self.as_ptr()) };
+        match unsafe { info.as_ref() } {
+            Some(info) => Ok(unsafe { HidDeviceInfo::from_sdl(info) }),
+            None => Err(Error::from_sdl()),
+        }
+    }
+
+    /// Get the report descriptor of the device. See [`SDL_hid_get_report_descriptor`].
+    pub fn report_descriptor(&self) -> Result<Vec<u8>> {
+        let mut buf = vec::from_elem(0, MAX_REPORT_DESCRIPTOR_SIZE);
+        let len = check_len(unsafe {
+            SDL_hid_get_report_descriptor(self.as_ptr(), buf.as_mut_ptr(), buf.len())
+        })?;
+        buf.truncate(len);
+        Ok(buf)
+    }
+}
+
+impl Drop for HidDevice {
+    fn drop(&mut self) {
+        unsafe { SDL_hid_close(self.as_ptr()) };
+    }
+}
+
+/// The type of a report descriptor item.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ItemType {
+    /// Main items, like input, output, feature and collections
+    Main,
+    /// Global items, which apply to all following items
+    Global,
+    /// Local items, which apply to the next main item
+    Local,
+    /// Long items, which have no defined meaning
+    Long,
+}
+
+/// An item in a HID report descriptor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ReportDescriptorItem<'a> {
+    /// Byte offset of the item in the descriptor
+    pub offset: usize,
+    /// Item type
+    pub item_type: ItemType,
+    /// Item tag
+    pub tag: u8,
+    /// Item data
+    pub data: &'a [u8],
+}
+
+impl ReportDescriptorItem<'_> {
+    /// Get the data as an unsigned little endian integer.
+    pub fn value(&self) -> u32 {
+        self.data
+            .iter()
+            .take(4)
+            .rev()
+            .fold(0, |acc, &b| (acc << 8) | b as u32)
+    }
+}
+
+/// Iterator over the items in a HID report descriptor.
+#[derive(Clone, Debug)]
+pub struct ReportDescriptorItems<'a> {
+    desc: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> ReportDescriptorItems<'a> {
+    /// Iterate over the items in `desc`.
+    pub fn new(desc: &'a [u8]) -> Self {
+        Self { desc, offset: 0 }
+    }
+}
+
+impl<'a> Iterator for ReportDescriptorItems<'a> {
+    type Item = Result<ReportDescriptorItem<'a>, DescriptorError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.offset;
+        let rest = self.desc.get(offset..).filter(|r| !r.is_empty())?;
+        let eof = DescriptorError {
+            offset,
+            kind: DescriptorErrorKind::UnexpectedEof,
+        };
+        let prefix = rest[0];
+        let (item_type, tag, header, size) = if prefix == 0xfe {
+            // long item: size and tag follow the prefix
+            let (Some(&size), Some(&tag)) = (rest.get(1), rest.get(2)) else {
+                self.offset = self.desc.len();
+                return Some(Err(eof));
+            };
+            (ItemType::Long, tag, 3, size as usize)
+        } else {
+            let item_type = match (prefix >> 2) & 3 {
+                0 => ItemType::Main,
+                1 => ItemType::Global,
+                2 => ItemType::Local,
+                _ => ItemType::Long,
+            };
+            let size = [0, 1, 2, 4][(prefix & 3) as usize];
+            (item_type, prefix >> 4, 1, size)
+        };
+        let Some(data) = rest.get(header..header + size) else {
+            self.offset = self.desc.len();
+            return Some(Err(eof));
+        };
+        self.offset += header + size;
+        Some(Ok(ReportDescriptorItem {
+            offset,
+            item_type,
+            tag,
+            data,
+        }))
+    }
+}
+
+/// A usage page and usage ID.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Usage {
+    /// The usage page
+    pub page: u16,
+    /// The usage ID within the page
+    pub id: u16,
+}
+
+/// A usage in a report descriptor, with the collection depth it was found at.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DescriptorUsage {
+    /// The usage
+    pub usage: Usage,
+    /// The number of collections that contain the item using this usage
+    pub depth: usize,
+    /// `true` if this is the usage of a collection
+    pub is_collection: bool,
+}
+
+const MAIN_COLLECTION: u8 = 0xa;
+const MAIN_END_COLLECTION: u8 = 0xc;
+const GLOBAL_USAGE_PAGE: u8 = 0x0;
+const GLOBAL_PUSH: u8 = 0xa;
+const GLOBAL_POP: u8 = 0xb;
+const LOCAL_USAGE: u8 = 0x0;
+
+/// Get all usages declared in a report descriptor, in order.
+///
+/// Use the usages of collections at depth 0 to find out what a device is, e.g. usage page
+/// 0x01 (generic desktop) with usage 0x05 for a gamepad. Vendor defined pages are
+/// 0xff00 to 0xffff.
+pub fn usages(desc: &[u8]) -> Result<Vec<DescriptorUsage>, DescriptorError> {
+    let mut out = Vec::new();
+    let mut usage_page = 0_u16;
+    let mut page_stack = Vec::new();
+    let mut local = Vec::new();
+    let mut depth = 0_usize;
+
+    for item in ReportDescriptorItems::new(desc) {
+        let item = item?;
+        match item.item_type {
+            ItemType::Global => match item.tag {
+                GLOBAL_USAGE_PAGE => usage_page = item.value() as u16,
+                GLOBAL_PUSH => page_stack.push(usage_page),
+                GLOBAL_POP => {
+                    usage_page = page_stack.pop().ok_or(DescriptorError {
+                        offset: item.offset,
+                        kind: DescriptorErrorKind::UnbalancedPop,
+                    })?
+                }
+                _ => (),
+            },
+            ItemType::Local if item.tag == LOCAL_USAGE => {
+                // 4 byte usages include the page in the high 16 bits
+                let value = item.value();
+                let page = (item.data.len() == 4).then_some((value >> 16) as u16);
+                local.push((page, value as u16));
+            }
+            ItemType::Main => {
+                let is_collection = item.tag == MAIN_COLLECTION;
+                if item.tag == MAIN_END_COLLECTION {
+                    depth = depth.checked_sub(1).ok_or(DescriptorError {
+                        offset: item.offset,
+                        kind: DescriptorErrorKind::UnbalancedCollection,
+                    })?;
+                }
+                for &(page, id) in &local {
+                    out.push(DescriptorUsage {
+                        usage: Usage {
+                            page: page.unwrap_or(usage_page),
+                            id,
+                        },
+                        depth,
+                        is_collection,
+                    });
+                }
+                local.clear();
+                if is_collection {
+                    depth += 1;
+                }
+            }
+            _ => (),
+        }
+    }
+
+    if depth != 0 {
+        return Err(DescriptorError {
+            offset: desc.len(),
+            kind: DescriptorErrorKind::UnbalancedCollection,
+        });
+    }
+    Ok(out)
+}
+
+/// An error from parsing a report descriptor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DescriptorError {
+    /// Byte offset in the descriptor where the error was detected.
+    pub offset: usize,
+
+    /// What went wrong.
+    pub kind: DescriptorErrorKind,
+}
+
+impl Display for DescriptorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at offset {}", self.kind, self.offset)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DescriptorError {}
+
+impl From<DescriptorError> for Error {
+    fn from(value: DescriptorError) -> Self {
+        Error::new(alloc::format!("invalid report descriptor: {value}"))
+    }
+}
+
+/// The kind of a [`DescriptorError`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DescriptorErrorKind {
+    /// The descriptor ended in the middle of an item
+    UnexpectedEof,
+
+    /// An end collection item without a collection, or a collection that isn't ended
+    UnbalancedCollection,
+
+    /// A pop item without a matching push
+    UnbalancedPop,
+}
+
+impl Display for DescriptorErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::UnexpectedEof => "unexpected end of data",
+            Self::UnbalancedCollection => "unbalanced collection",
+            Self::UnbalancedPop => "pop without push",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wide(chars: &[u32]) -> Vec<c_wchar_t> {
+        chars.iter().map(|&c| c as c_wchar_t).collect()
+    }
+
+    fn items(desc: &[u8]) -> Vec<Result<ReportDescriptorItem<'_>, DescriptorError>> {
+        ReportDescriptorItems::new(desc).collect()
+    }
+
+    fn item(offset: usize, item_type: ItemType, tag: u8, data: &[u8]) -> ReportDescriptorItem<'_> {
+        ReportDescriptorItem {
+            offset,
+            item_type,
+            tag,
+            data,
+        }
+    }
+
+    fn eof(offset: usize) -> DescriptorError {
+        DescriptorError {
+            offset,
+            kind: DescriptorErrorKind::UnexpectedEof,
+        }
+    }
+
+    fn usage(page: u16, id: u16, depth: usize, is_collection: bool) -> DescriptorUsage {
+        DescriptorUsage {
+            usage: Usage { page, id },
+            depth,
+            is_collection,
+        }
+    }
+
+    #[test]
+    fn wide_strings() {
+        for s in ["", "abc", "h\u{e9}llo", "\u{1f600} wide \u{ffff}"] {
+            let w = str_to_wide(s);
+            assert_eq!(w.last(), Some(&0));
+            assert_eq!(wide_to_string(&w), s);
+            assert_eq!(
+                unsafe { wide_ptr_to_string(w.as_ptr()) }.as_deref(),
+                Some(s)
+            );
+        }
+        assert_eq!(unsafe { wide_ptr_to_string(ptr::null()) }, None);
+
+        // strings end at the first nul
+        assert_eq!(wide_to_string(&wide(&[0x61, 0x62, 0, 0x63])), "ab");
+        assert_eq!(wide_to_string(&str_to_wide("a\0b")), "a");
+        assert_eq!(wide_to_string(&wide(&[0])), "");
+
+        // unpaired surrogates are replaced in both encodings
+        assert_eq!(wide_to_string(&wide(&[0x61, 0xd800, 0x62])), "a\u{fffd}b");
+        assert_eq!(wide_to_string(&wide(&[0xdc00])), "\u{fffd}");
+        let pair = wide(&[0xd83d, 0xde00]);
+        if size_of::<c_wchar_t>() == 2 {
+            assert_eq!(str_to_wide("\u{1f600}"), wide(&[0xd83d, 0xde00, 0]));
+            assert_eq!(wide_to_string(&pair), "\u{1f600}");
+        } else {
+            assert_eq!(str_to_wide("\u{1f600}"), wide(&[0x1f600, 0]));
+            assert_eq!(wide_to_string(&pair), "\u{fffd}\u{fffd}");
+            assert_eq!(wide_to_string(&wide(&[0x110000, 0x61])), "\u{fffd}a");
+        }
+    }
+
+    #[test]
+    fn descriptor_items() {
+        assert_eq!(items(&[]), []);
+
+        // long item with 2 data bytes, followed by a short item
+        let desc = [0xfe, 2, 0x10, 0xaa, 0xbb, 0x05, 0x01];
+        assert_eq!(
+            items(&desc),
+            [
+                Ok(item(0, ItemType::Long, 0x10, &[0xaa, 0xbb])),
+                Ok(item(5, ItemType::Global, 0, &[0x01])),
+            ]
+        );
+
+        // size code 3 means 4 bytes
+        let desc = [0x27, 0x12, 0x34, 0x56, 0x78, 0x0b, 0x05, 0x00, 0x01, 0x00];
+        let parsed = items(&desc);
+        assert_eq!(parsed[0], Ok(item(0, ItemType::Global, 2, &desc[1..5])));
+        assert_eq!(parsed[0].unwrap().value(), 0x7856_3412);
+        assert_eq!(parsed[1], Ok(item(5, ItemType::Local, 0, &desc[6..])));
+        assert_eq!(parsed[1].unwrap().value(), 0x0001_0005);
+
+        // truncated items end iteration after the error
+        assert_eq!(items(&[0xfe]), [Err(eof(0))]);
+        assert_eq!(items(&[0xfe, 5]), [Err(eof(0))]);
+        assert_eq!(items(&[0xfe, 3, 0x10, 1, 2]), [Err(eof(0))]);
+        assert_eq!(items(&[0x05]), [Err(eof(0))]);
+        assert_eq!(
+            items(&[0x09, 0x01, 0x27, 1, 2]),
+            [Ok(item(0, ItemType::Local, 0, &[1])), Err(eof(2))]
+        );
+    }
+
+    #[test]
+    fn gamepad_usages() {
+        #[rustfmt::skip]
+        let desc = [
+            0x05, 0x01, // usage page (generic desktop)
+            0x09, 0x05, // usage (gamepad)
+            0xa1, 0x01, // collection (application)
+            0xa1, 0x00, //   collection (physical)
+            0x05, 0x09, //     usage page (button)
+            0x19, 0x01, //     usage minimum (1)
+            0x29, 0x10, //     usage maximum (16)
+            0x15, 0x00, 0x25, 0x01, 0x75, 0x01, 0x95, 0x10,
+            0x81, 0x02, //     input
+            0x05, 0x01, //     usage page (generic desktop)
+            0x09, 0x30, //     usage (x)
+            0x09, 0x31, //     usage (y)
+            0x15, 0x81, 0x25, 0x7f, 0x75, 0x08, 0x95, 0x02,
+            0x81, 0x02, //     input
+            0xc0,       //   end collection
+            0xa4,       //   push
+            0x06, 0x00, 0xff, // usage page (vendor 0xff00)
+            0x09, 0x01, //   usage (1)
+            0x0b, 0x39, 0x00, 0x01, 0x00, // usage (generic desktop, hat switch)
+            0x81, 0x02, //   input
+            0xb4,       //   pop
+            0x09, 0x32, //   usage (z)
+            0x81, 0x02, //   input
+            0xc0,       // end collection
+        ];
+        assert_eq!(
+            usages(&desc).unwrap(),
+            [
+                usage(0x01, 0x05, 0, true),
+                usage(0x01, 0x30, 2, false),
+                usage(0x01, 0x31, 2, false),
+                usage(0xff00, 0x01, 1, false),
+                usage(0x01, 0x39, 1, false),
+                usage(0x01, 0x32, 1, false),
+            ]
+        );
+
+        assert_eq!(
+            usages(&[0xa1, 0x01, 0xc0, 0xc0]).unwrap_err(),
+            DescriptorError {
+                offset: 3,
+                kind: DescriptorErrorKind::UnbalancedCollection,
+            }
+        );
+        assert_eq!(
+            usages(&[0xa1, 0x01]).unwrap_err(),
+            DescriptorError {
+                offset: 2,
+                kind: DescriptorErrorKind::UnbalancedCollection,
+            }
+        );
+        assert_eq!(
+            usages(&[0xa4, 0xb4, 0xb4]).unwrap_err(),
+            DescriptorError {
+                offset: 2,
+                kind: DescriptorErrorKind::UnbalancedPop,
+            }
+        );
+        // the last input item is missing its data
+        assert_eq!(
+            usages(&desc[..desc.len() - 2]).unwrap_err(),
+            eof(desc.len() - 3)
+        );
+    }
+}
@@ -0,0 +1,671 @@
+//! Keyboard and mouse state tracking with per-frame edge detection.
+//!
+//! [`InputState`] is fed events and keeps track of which keys and mouse buttons are held,
+//! which were pressed or released since the last frame, and how far the mouse and wheel
+//! moved. State is kept per keyboard and mouse ID, and can also be queried across all
+//! devices.
+//!
+//! Call [`InputState::handle_event`] from `app_event` (or your event loop), query the state
+//! from `app_iterate`, then call [`InputState::end_frame`] at the end of the frame.
+//!
+//! The typed event methods like [`InputState::key`] are safe, so the state can be driven
+//! by synthetic events, e.g. in tests:
+//!
+//! ```
+//! use sdl3_main::input::InputState;
+//! use sdl3_sys::{
+//!     events::{SDL_KeyboardEvent, SDL_EVENT_KEY_DOWN},
+//!     keycode::{SDLK_SPACE, SDL_KMOD_NONE},
+//!     scancode::SDL_SCANCODE_SPACE,
+//! };
+//!
+//! let mut input = InputState::new();
+//! input.key(&SDL_KeyboardEvent {
+//!     r#type: SDL_EVENT_KEY_DOWN,
+//!     reserved: 0,
+//!     timestamp: 0,
+//!     windowID: 0,
+//!     which: 0,
+//!     scancode: SDL_SCANCODE_SPACE,
+//!     key: SDLK_SPACE,
+//!     r#mod: SDL_KMOD_NONE,
+//!     raw: 0,
+//!     down: true,
+//!     repeat: false,
+//! });
+//! assert!(input.is_down(SDL_SCANCODE_SPACE));
+//! assert!(input.just_pressed(SDL_SCANCODE_SPACE));
+//! input.end_frame();
+//! assert!(input.is_down(SDL_SCANCODE_SPACE));
+//! assert!(!input.just_pressed(SDL_SCANCODE_SPACE));
+//! ```
+
+use crate::{Error, Result};
+use alloc::{collections::BTreeMap, string::String};
+use core::{
+    ffi::{c_int, c_void, CStr},
+    slice,
+};
+use sdl3_sys::{
+    events::{
+        SDL_Event, SDL_EventType, SDL_KeyboardEvent, SDL_MouseButtonEvent, SDL_MouseMotionEvent,
+        SDL_MouseWheelEvent, SDL_EVENT_KEYBOARD_ADDED, SDL_EVENT_KEYBOARD_REMOVED,
+        SDL_EVENT_KEY_DOWN, SDL_EVENT_KEY_UP, SDL_EVENT_MOUSE_ADDED, SDL_EVENT_MOUSE_BUTTON_DOWN,
+        SDL_EVENT_MOUSE_BUTTON_UP, SDL_EVENT_MOUSE_MOTION, SDL_EVENT_MOUSE_REMOVED,
+        SDL_EVENT_MOUSE_WHEEL, SDL_EVENT_TEXT_INPUT,
+    },
+    keyboard::{SDL_GetKeyboards, SDL_KeyboardID},
+    keycode::SDL_Keymod,
+    mouse::{SDL_GetMice, SDL_MouseButtonFlags, SDL_MouseID, SDL_MOUSEWHEEL_FLIPPED},
+    scancode::{SDL_Scancode, SDL_SCANCODE_COUNT},
+    stdinc::SDL_free,
+};
+
+const KEY_WORDS: usize = SDL_SCANCODE_COUNT.0 as usize / 64;
+
+// Bit set of scancodes
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+struct KeySet([u64; KEY_WORDS]);
+
+impl KeySet {
+    fn index(scancode: SDL_Scancode) -> Option<(usize, u64)> {
+        let i = usize::try_from(scancode.0).ok()?;
+        (i < KEY_WORDS * 64).then(|| (i / 64, 1 << (i & 63)))
+    }
+
+    fn contains(&self, scancode: SDL_Scancode) -> bool {
+        Self::index(scancode).is_some_and(|(w, bit)| self.0[w] & bit != 0)
+    }
+
+    fn set(&mut self, scancode: SDL_Scancode, value: bool) {
+        if let Some((w, bit)) = Self::index(scancode) {
+            if value {
+                self.0[w] |= bit;
+            } else {
+                self.0[w] &= !bit;
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        self.0 = [0; KEY_WORDS];
+    }
+}
+
+// Mask for a mouse button, like `SDL_BUTTON_MASK`
+fn button_mask(button: u8) -> SDL_MouseButtonFlags {
+    match button {
+        1..=32 => 1 << (button - 1),
+        _ => 0,
+    }
+}
+
+/// State of a single keyboard. See [`InputState::keyboard`].
+#[derive(Clone, Debug, Default)]
+pub struct KeyboardState {
+    down: KeySet,
+    pressed: KeySet,
+    released: KeySet,
+    mods: SDL_Keymod,
+}
+
+impl core::fmt::Debug for KeySet {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set()
+            .entries((0..KEY_WORDS as c_int * 64).filter(|&i| self.contains(SDL_Scancode(i))))
+            .finish()
+    }
+}
+
+impl KeyboardState {
+    /// Returns true if the key is held down.
+    #[inline]
+    pub fn is_down(&self, scancode: SDL_Scancode) -> bool {
+        self.down.contains(scancode)
+    }
+
+    /// Returns true if the key was pressed since the last frame. Key repeats don't count.
+    #[inline]
+    pub fn just_pressed(&self, scancode: SDL_Scancode) -> bool {
+        self.pressed.contains(scancode)
+    }
+
+    /// Returns true if the key was released since the last frame.
+    #[inline]
+    pub fn just_released(&self, scancode: SDL_Scancode) -> bool {
+        self.released.contains(scancode)
+    }
+
+    /// Get the modifier state from the last key event of this keyboard.
+    #[inline]
+    pub fn mods(&self) -> SDL_Keymod {
+        self.mods
+    }
+
+    fn key(&mut self, event: &SDL_KeyboardEvent) {
+        self.mods = event.r#mod;
+        if event.down {
+            if !event.repeat && !self.down.contains(event.scancode) {
+                self.pressed.set(event.scancode, true);
+            }
+        } else if self.down.contains(event.scancode) {
+            self.released.set(event.scancode, true);
+        }
+        self.down.set(event.scancode, event.down);
+    }
+
+    fn end_frame(&mut self) {
+        self.pressed.clear();
+        self.released.clear();
+    }
+}
+
+/// State of a single mouse. See [`InputState::mouse`].
+#[derive(Clone, Debug, Default)]
+pub struct MouseState {
+    down: SDL_MouseButtonFlags,
+    pressed: SDL_MouseButtonFlags,
+    released: SDL_MouseButtonFlags,
+    position: (f32, f32),
+    delta: (f32, f32),
+    wheel: (f32, f32),
+}
+
+impl MouseState {
+    /// Returns true if the button is held down. Buttons are numbered like
+    /// [`SDL_BUTTON_LEFT`](sdl3_sys::mouse::SDL_BUTTON_LEFT).
+    #[inline]
+    pub fn is_down(&self, button: u8) -> bool {
+        self.down & button_mask(button) != 0
+    }
+
+    /// Returns true if the button was pressed since the last frame.
+    #[inline]
+    pub fn just_pressed(&self, button: u8) -> bool {
+        self.pressed & button_mask(button) != 0
+    }
+
+    /// Returns true if the button was released since the last frame.
+    #[inline]
+    pub fn just_released(&self, button: u8) -> bool {
+        self.released & button_mask(button) != 0
+    }
+
+    /// Get the mask of held buttons, like [`SDL_GetMouseState`](sdl3_sys::mouse::SDL_GetMouseState).
+    #[inline]
+    pub fn buttons(&self) -> SDL_MouseButtonFlags {
+        self.down
+    }
+
+    /// Get the last known position of the mouse, relative to the focused window.
+    #[inline]
+    pub fn position(&self) -> (f32, f32) {
+        self.position
+    }
+
+    /// Get how far the mouse moved since the last frame.
+    #[inline]
+    pub fn delta(&self) -> (f32, f32) {
+        self.delta
+    }
+
+    /// Get how far the wheel scrolled since the last frame. Flipped wheel events are
+    /// negated, so positive y is always away from the user.
+    #[inline]
+    pub fn wheel(&self) -> (f32, f32) {
+        self.wheel
+    }
+
+    fn button(&mut self, event: &SDL_MouseButtonEvent) {
+        let mask = button_mask(event.button);
+        if event.down {
+            if self.down & mask == 0 {
+                self.pressed |= mask;
+            }
+            self.down |= mask;
+        } else {
+            if self.down & mask != 0 {
+                self.released |= mask;
+            }
+            self.down &= !mask;
+        }
+        self.position = (event.x, event.y);
+    }
+
+    fn motion(&mut self, event: &SDL_MouseMotionEvent) {
+        self.position = (event.x, event.y);
+        self.delta.0 += event.xrel;
+        self.delta.1 += event.yrel;
+    }
+
+    fn wheel_event(&mut self, event: &SDL_MouseWheelEvent) {
+        let sign = if event.direction == SDL_MOUSEWHEEL_FLIPPED {
+            -1.0
+        } else {
+            1.0
+        };
+        self.wheel.0 += event.x * sign;
+        self.wheel.1 += event.y * sign;
+        self.position = (event.mouse_x, event.mouse_y);
+    }
+
+    fn end_frame(&mut self) {
+        self.pressed = 0;
+        self.released = 0;
+        self.delta = (0.0, 0.0);
+        self.wheel = (0.0, 0.0);
+    }
+}
+
+/// Keyboard and mouse state for all devices, with edge detection. See the [module
+/// documentation](self).
+#[derive(Clone, Debug, Default)]
+pub struct InputState {
+    keyboards: BTreeMap<SDL_KeyboardID, KeyboardState>,
+    mice: BTreeMap<SDL_MouseID, MouseState>,
+    mods: SDL_Keymod,
+    text: String,
+}
+
+impl InputState {
+    /// Create a new `InputState` with no known devices.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Update the known keyboards and mice from [`SDL_GetKeyboards`] and [`SDL_GetMice`].
+    /// State is kept for devices that are still connected, and dropped for the rest.
+    ///
+    /// This isn't needed if you pass device added/removed events to
+    /// [`handle_event`](Self::handle_event), except for devices that were connected before
+    /// the keyboard and mouse subsystems were initialized.
+    pub fn refresh_devices(&mut self) -> Result<()> {
+        fn ids<T: Copy + Ord>(
+            get: unsafe extern "C" fn(*mut c_int) -> *mut T,
+            map: &mut BTreeMap<T, impl Default>,
+        ) -> Result<()> {
+            let mut count = 0;
+            let list = unsafe { get(&mut count) };
+            if list.is_null() {
+                return Err(Error::from_sdl());
+            }
+            let ids = unsafe { slice::from_raw_parts(list, count as usize) };
+            map.retain(|id, _| ids.contains(id));
+            for &id in ids {
+                map.entry(id).or_default();
+            }
+            unsafe { SDL_free(list as *mut c_void) };
+            Ok(())
+        }
+        ids(SDL_GetKeyboards, &mut self.keyboards)?;
+        ids(SDL_GetMice, &mut self.mice)
+    }
+
+    /// Update the state from an event. Returns true if the event was a keyboard, mouse or
+    /// text input event.
+    ///
+    /// # Safety
+    /// `event` must be a valid event, like the ones you get from SDL. In particular, text
+    /// input events must point to a valid string.
+    pub unsafe fn handle_event(&mut self, event: &SDL_Event) -> bool {
+        let event_type = SDL_EventType(unsafe { event.r#type });
+        match event_type {
+            SDL_EVENT_KEY_DOWN | SDL_EVENT_KEY_UP => self.key(unsafe { &event.key }),
+            SDL_EVENT_MOUSE_BUTTON_DOWN | SDL_EVENT_MOUSE_BUTTON_UP => {
+                self.mouse_button(unsafe { &event.button })
+            }
+            SDL_EVENT_MOUSE_MOTION => self.mouse_motion(unsafe { &event.motion }),
+            SDL_EVENT_MOUSE_WHEEL => self.mouse_wheel(unsafe { &event.wheel }),
+            SDL_EVENT_TEXT_INPUT => {
+                let text = unsafe { event.text.text };
+                if !text.is_null() {
+                    self.text_input(&unsafe { CStr::from_ptr(text) }.to_string_lossy());
+                }
+            }
+            SDL_EVENT_KEYBOARD_ADDED => self.add_keyboard(unsafe { event.kdevice.which }),
+            SDL_EVENT_KEYBOARD_REMOVED => self.remove_keyboard(unsafe { event.kdevice.which }),
+            SDL_EVENT_MOUSE_ADDED => self.add_mouse(unsafe { event.mdevice.which }),
+            SDL_EVENT_MOUSE_REMOVED => self.remove_mouse(unsafe { event.mdevice.which }),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Update the state from a key event.
+    pub fn key(&mut self, event: &SDL_KeyboardEvent) {
+        self.mods = event.r#mod;
+        self.keyboards.entry(event.which).or_default().key(event);
+    }
+
+    /// Update the state from a mouse button event.
+    pub fn mouse_button(&mut self, event: &SDL_MouseButtonEvent) {
+        self.mice.entry(event.which).or_default().button(event);
+    }
+
+    /// Update the state from a mouse motion event.
+    pub fn mouse_motion(&mut self, event: &SDL_MouseMotionEvent) {
+        self.mice.entry(event.which).or_default().motion(event);
+    }
+
+    /// Update the state from a mouse wheel event.
+    pub fn mouse_wheel(&mut self, event: &SDL_MouseWheelEvent) {
+        self.mice.entry(event.which).or_default().wheel_event(event);
+    }
+
+    /// Append text from a text input event.
+    pub fn text_input(&mut self, text: &str) {
+        self.text.push_str(text);
+    }
+
+    /// Start tracking a keyboard.
+    pub fn add_keyboard(&mut self, id: SDL_KeyboardID) {
+        self.keyboards.entry(id).or_default();
+    }
+
+    /// Stop tracking a keyboard and drop its state.
+    pub fn remove_keyboard(&mut self, id: SDL_KeyboardID) {
+        self.keyboards.remove(&id);
+    }
+
+    /// Start tracking a mouse.
+    pub fn add_mouse(&mut self, id: SDL_MouseID) {
+        self.mice.entry(id).or_default();
+    }
+
+    /// Stop tracking a mouse and drop its state.
+    pub fn remove_mouse(&mut self, id: SDL_MouseID) {
+        self.mice.remove(&id);
+    }
+
+    /// Clear the per-frame state: pressed and released keys and buttons, mouse and wheel
+    /// movement, and text input. Call this at the end of each frame.
+    pub fn end_frame(&mut self) {
+        self.keyboards
+            .values_mut()
+            .for_each(KeyboardState::end_frame);
+        self.mice.values_mut().for_each(MouseState::end_frame);
+        self.text.clear();
+    }
+
+    /// Get the state of a keyboard.
+    #[inline]
+    pub fn keyboard(&self, id: SDL_KeyboardID) -> Option<&KeyboardState> {
+        self.keyboards.get(&id)
+    }
+
+    /// Iterate over the known keyboards.
+    pub fn keyboards(&self) -> impl Iterator<Item = (SDL_KeyboardID, &KeyboardState)> {
+        self.keyboards.iter().map(|(&id, state)| (id, state))
+    }
+
+    /// Get the state of a mouse.
+    #[inline]
+    pub fn mouse(&self, id: SDL_MouseID) -> Option<&MouseState> {
+        self.mice.get(&id)
+    }
+
+    /// Iterate over the known mice.
+    pub fn mice(&self) -> impl Iterator<Item = (SDL_MouseID, &MouseState)> {
+        self.mice.iter().map(|(&id, state)| (id, state))
+    }
+
+    /// Returns true if the key is held down on any keyboard.
+    pub fn is_down(&self, scancode: SDL_Scancode) -> bool {
+        self.keyboards.values().any(|k| k.is_down(scancode))
+    }
+
+    /// Returns true if the key was pressed on any keyboard since the last frame.
+    pub fn just_pressed(&self, scancode: SDL_Scancode) -> bool {
+        self.keyboards.values().any(|k| k.just_pressed(scancode))
+    }
+
+    /// Returns true if the key was released on any keyboard since the last frame.
+    pub fn just_released(&self, scancode: SDL_Scancode) -> bool {
+        self.keyboards.values().any(|k| k.just_released(scancode))
+    }
+
+    /// Get the modifier state from the last key event of any keyboard.
+    #[inline]
+    pub fn mods(&self) -> SDL_Keymod {
+        self.mods
+    }
+
+    /// Returns true if the mouse button is held down on any mouse.
+    pub fn mouse_down(&self, button: u8) -> bool {
+        self.mice.values().any(|m| m.is_down(button))
+    }
+
+    /// Returns true if the mouse button was pressed on any mouse since the last frame.
+    pub fn mouse_just_pressed(&self, button: u8) -> bool {
+        self.mice.values().any(|m| m.just_pressed(button))
+    }
+
+    /// Returns true if the mouse button was released on any mouse since the last frame.
+    pub fn mouse_just_released(&self, button: u8) -> bool {
+        self.mice.values().any(|m| m.just_released(button))
+    }
+
+    /// Get the total mouse movement of all mice since the last frame.
+    pub fn mouse_delta(&self) -> (f32, f32) {
+        self.mice
+            .values()
+            .fold((0.0, 0.0), |(x, y), m| (x + m.delta.0, y + m.delta.1))
+    }
+
+    /// Get the total wheel movement of all mice since the last frame.
+    pub fn wheel(&self) -> (f32, f32) {
+        self.mice
+            .values()
+            .fold((0.0, 0.0), |(x, y), m| (x + m.wheel.0, y + m.wheel.1))
+    }
+
+    /// Get the text input since the last frame.
+    #[inline]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::mem;
+    use sdl3_sys::{
+        keycode::{SDL_KMOD_LSHIFT, SDL_KMOD_NONE},
+        mouse::{SDL_BUTTON_LEFT, SDL_BUTTON_RIGHT},
+        scancode::{SDL_SCANCODE_A, SDL_SCANCODE_B},
+    };
+
+    const LEFT: u8 = SDL_BUTTON_LEFT as u8;
+    const RIGHT: u8 = SDL_BUTTON_RIGHT as u8;
+
+    fn key(which: SDL_KeyboardID, scancode: SDL_Scancode, down: bool) -> SDL_KeyboardEvent {
+        let mut event: SDL_KeyboardEvent = unsafe { mem::zeroed() };
+        event.r#type = if down {
+            SDL_EVENT_KEY_DOWN
+        } else {
+            SDL_EVENT_KEY_UP
+        };
+        event.which = which;
+        event.scancode = scancode;
+        event.down = down;
+        event
+    }
+
+    fn button(which: SDL_MouseID, button: u8, down: bool) -> SDL_MouseButtonEvent {
+        let mut event: SDL_MouseButtonEvent = unsafe { mem::zeroed() };
+        event.which = which;
+        event.button = button;
+        event.down = down;
+        event
+    }
+
+    fn motion(which: SDL_MouseID, x: f32, y: f32, xrel: f32, yrel: f32) -> SDL_MouseMotionEvent {
+        let mut event: SDL_MouseMotionEvent = unsafe { mem::zeroed() };
+        event.which = which;
+        (event.x, event.y, event.xrel, event.yrel) = (x, y, xrel, yrel);
+        event
+    }
+
+    fn wheel(which: SDL_MouseID, x: f32, y: f32, flipped: bool) -> SDL_MouseWheelEvent {
+        let mut event: SDL_MouseWheelEvent = unsafe { mem::zeroed() };
+        event.which = which;
+        (event.x, event.y) = (x, y);
+        if flipped {
+            event.direction = SDL_MOUSEWHEEL_FLIPPED;
+        }
+        event
+    }
+
+    #[test]
+    fn key_edges() {
+        let mut input = InputState::new();
+        input.key(&key(1, SDL_SCANCODE_A, true));
+        assert!(input.is_down(SDL_SCANCODE_A));
+        assert!(input.just_pressed(SDL_SCANCODE_A));
+        assert!(!input.just_released(SDL_SCANCODE_A));
+
+        // repeats aren't new presses
+        input.end_frame();
+        let mut repeat = key(1, SDL_SCANCODE_A, true);
+        repeat.repeat = true;
+        input.key(&repeat);
+        assert!(input.is_down(SDL_SCANCODE_A));
+        assert!(!input.just_pressed(SDL_SCANCODE_A));
+
+        input.key(&key(1, SDL_SCANCODE_A, false));
+        assert!(!input.is_down(SDL_SCANCODE_A));
+        assert!(input.just_released(SDL_SCANCODE_A));
+        input.end_frame();
+        assert!(!input.just_released(SDL_SCANCODE_A));
+
+        // a release without a press isn't an edge
+        input.key(&key(1, SDL_SCANCODE_B, false));
+        assert!(!input.just_released(SDL_SCANCODE_B));
+    }
+
+    #[test]
+    fn press_and_release_in_one_frame() {
+        let mut input = InputState::new();
+        input.key(&key(1, SDL_SCANCODE_A, true));
+        input.key(&key(1, SDL_SCANCODE_A, false));
+        assert!(!input.is_down(SDL_SCANCODE_A));
+        assert!(input.just_pressed(SDL_SCANCODE_A));
+        assert!(input.just_released(SDL_SCANCODE_A));
+
+        input.mouse_button(&button(1, LEFT, true));
+        input.mouse_button(&button(1, LEFT, false));
+        assert!(!input.mouse_down(LEFT));
+        assert!(input.mouse_just_pressed(LEFT));
+        assert!(input.mouse_just_released(LEFT));
+
+        input.end_frame();
+        assert!(!input.just_pressed(SDL_SCANCODE_A));
+        assert!(!input.just_released(SDL_SCANCODE_A));
+        assert!(!input.mouse_just_pressed(LEFT));
+        assert!(!input.mouse_just_released(LEFT));
+    }
+
+    #[test]
+    fn devices() {
+        let mut input = InputState::new();
+        input.key(&key(1, SDL_SCANCODE_A, true));
+        input.key(&key(2, SDL_SCANCODE_B, true));
+        let (k1, k2) = (input.keyboard(1).unwrap(), input.keyboard(2).unwrap());
+        assert!(k1.is_down(SDL_SCANCODE_A) && !k1.is_down(SDL_SCANCODE_B));
+        assert!(k2.is_down(SDL_SCANCODE_B) && !k2.is_down(SDL_SCANCODE_A));
+
+        // releasing on one keyboard doesn't affect the other
+        input.key(&key(2, SDL_SCANCODE_A, true));
+        input.end_frame();
+        input.key(&key(1, SDL_SCANCODE_A, false));
+        assert!(input.is_down(SDL_SCANCODE_A));
+        assert!(input.just_released(SDL_SCANCODE_A));
+        assert!(input.keyboard(2).unwrap().is_down(SDL_SCANCODE_A));
+
+        input.mouse_button(&button(1, RIGHT, true));
+        assert!(input.mouse(1).unwrap().is_down(RIGHT));
+        assert!(input.mouse(2).is_none());
+        input.add_mouse(2);
+        assert!(!input.mouse(2).unwrap().is_down(RIGHT));
+        assert_eq!(input.mice().count(), 2);
+
+        input.remove_keyboard(2);
+        assert!(input.keyboard(2).is_none());
+        assert!(!input.is_down(SDL_SCANCODE_B));
+        input.remove_mouse(1);
+        assert!(!input.mouse_down(RIGHT));
+    }
+
+    #[test]
+    fn mods() {
+        let mut input = InputState::new();
+        let mut event = key(1, SDL_SCANCODE_A, true);
+        event.r#mod = SDL_KMOD_LSHIFT;
+        input.key(&event);
+        assert_eq!(input.mods(), SDL_KMOD_LSHIFT);
+        assert_eq!(input.keyboard(1).unwrap().mods(), SDL_KMOD_LSHIFT);
+        input.key(&key(2, SDL_SCANCODE_B, true));
+        assert_eq!(input.mods(), SDL_KMOD_NONE);
+        assert_eq!(input.keyboard(1).unwrap().mods(), SDL_KMOD_LSHIFT);
+    }
+
+    #[test]
+    fn motion_and_wheel() {
+        let mut input = InputState::new();
+        input.mouse_motion(&motion(1, 10.0, 20.0, 1.0, 2.0));
+        input.mouse_motion(&motion(1, 13.0, 18.0, 3.0, -2.0));
+        input.mouse_motion(&motion(2, 0.0, 0.0, 0.5, 0.5));
+        input.mouse_wheel(&wheel(1, 0.0, 1.0, false));
+        input.mouse_wheel(&wheel(1, 0.5, 2.0, false));
+        input.mouse_wheel(&wheel(2, 1.0, 1.0, true));
+
+        let mouse = input.mouse(1).unwrap();
+        assert_eq!(mouse.delta(), (4.0, 0.0));
+        assert_eq!(mouse.wheel(), (0.5, 3.0));
+        assert_eq!(input.mouse(2).unwrap().wheel(), (-1.0, -1.0));
+        assert_eq!(input.mouse_delta(), (4.5, 0.5));
+        assert_eq!(input.wheel(), (-0.5, 2.0));
+
+        input.end_frame();
+        assert_eq!(input.mouse_delta(), (0.0, 0.0));
+        assert_eq!(input.wheel(), (0.0, 0.0));
+        // the position is kept
+        assert_eq!(input.mouse(2).unwrap().position(), (0.0, 0.0));
+    }
+
+    #[test]
+    fn events() {
+        let mut input = InputState::new();
+        let mut event: SDL_Event = unsafe { mem::zeroed() };
+        event.key = key(1, SDL_SCANCODE_A, true);
+        assert!(unsafe { input.handle_event(&event) });
+        assert!(input.just_pressed(SDL_SCANCODE_A));
+
+        let mut event: SDL_Event = unsafe { mem::zeroed() };
+        event.text.r#type = SDL_EVENT_TEXT_INPUT;
+        event.text.text = c"hé".as_ptr();
+        assert!(unsafe { input.handle_event(&event) });
+        assert!(unsafe { input.handle_event(&event) });
+        assert_eq!(input.text(), "héhé");
+
+        let mut event: SDL_Event = unsafe { mem::zeroed() };
+        event.mdevice.r#type = SDL_EVENT_MOUSE_ADDED;
+        event.mdevice.which = 5;
+        assert!(unsafe { input.handle_event(&event) });
+        assert!(input.mouse(5).is_some());
+
+        let mut event: SDL_Event = unsafe { mem::zeroed() };
+        event.r#type = SDL_EVENT_KEYBOARD_REMOVED.0;
+        event.kdevice.which = 1;
+        assert!(unsafe { input.handle_event(&event) });
+        assert!(input.keyboard(1).is_none());
+
+        let event: SDL_Event = unsafe { mem::zeroed() };
+        assert!(!unsafe { input.handle_event(&event) });
+
+        input.end_frame();
+        assert_eq!(input.text(), "");
+    }
+}
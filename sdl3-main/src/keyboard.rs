@@ -0,0 +1,596 @@
+//! Names for keyboard scancodes, keycodes and modifiers that work without initializing SDL.
+//!
+//! [`SDL_GetKeyName`] and friends depend on the keymap of the video subsystem. The types in
+//! this module use the name tables in [`sdl3_sys::scancode`] and [`sdl3_sys::keycode`]
+//! instead, with SDL's default US layout, so key bindings can be read and written anywhere,
+//! e.g. on a headless server:
+//!
+//! ```
+//! use sdl3_main::keyboard::{KeyCombo, Keycode, Keymod};
+//! use sdl3_sys::keycode::{SDLK_S, SDL_KMOD_CTRL, SDL_KMOD_LCTRL, SDL_KMOD_SHIFT};
+//!
+//! let save_as: KeyCombo = "Ctrl+Shift+S".parse().unwrap();
+//! assert_eq!(save_as.key, Keycode(SDLK_S));
+//! assert_eq!(save_as.mods, Keymod(SDL_KMOD_CTRL | SDL_KMOD_SHIFT));
+//! assert_eq!(save_as.to_string(), "Ctrl+Shift+S");
+//! assert!(save_as.matches(Keycode(SDLK_S), Keymod(SDL_KMOD_LCTRL | SDL_KMOD_SHIFT)));
+//! ```
+//!
+//! Names are compared case insensitively, like SDL does.
+
+use core::{
+    fmt::{self, Debug, Display},
+    str::FromStr,
+};
+use sdl3_sys::{
+    keycode::{
+        SDL_Keycode, SDL_Keymod, SDLK_BACKSPACE, SDLK_DELETE, SDLK_ESCAPE, SDLK_RETURN,
+        SDLK_SCANCODE_MASK, SDLK_SPACE, SDLK_TAB, SDLK_UNKNOWN, SDL_KEYCODES, SDL_KMOD_ALT,
+        SDL_KMOD_CAPS, SDL_KMOD_CTRL, SDL_KMOD_GUI, SDL_KMOD_LALT, SDL_KMOD_LCTRL, SDL_KMOD_LGUI,
+        SDL_KMOD_LSHIFT, SDL_KMOD_MODE, SDL_KMOD_NONE, SDL_KMOD_NUM, SDL_KMOD_RALT, SDL_KMOD_RCTRL,
+        SDL_KMOD_RGUI, SDL_KMOD_RSHIFT, SDL_KMOD_SCROLL, SDL_KMOD_SHIFT, SDL_SCANCODE_TO_KEYCODE,
+        SDL_US_KEYMAP,
+    },
+    scancode::{SDL_Scancode, SDL_SCANCODE_NAMES},
+};
+
+#[cfg(doc)]
+use sdl3_sys::keyboard::SDL_GetKeyName;
+
+#[cfg(feature = "alloc")]
+use crate::Error;
+
+/// A physical key. Converts to and from its SDL name with [`Display`] and [`FromStr`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Scancode(pub SDL_Scancode);
+
+impl Scancode {
+    /// Iterate over all the scancodes that have a name, in scancode order.
+    pub fn all() -> impl ExactSizeIterator<Item = Self> + Clone {
+        SDL_SCANCODE_NAMES
+            .iter()
+            .map(|&(scancode, _)| Self(scancode))
+    }
+
+    /// Get the name of this scancode, or `None` if it doesn't have one.
+    /// This is the same name as [`SDL_GetScancodeName`](sdl3_sys::keyboard::SDL_GetScancodeName)
+    /// returns.
+    pub fn name(self) -> Option<&'static str> {
+        SDL_SCANCODE_NAMES
+            .binary_search_by_key(&self.0 .0, |(scancode, _)| scancode.0)
+            .ok()
+            .map(|i| SDL_SCANCODE_NAMES[i].1)
+    }
+
+    /// Look up a scancode by name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        SDL_SCANCODE_NAMES
+            .iter()
+            .find(|(_, n)| n.eq_ignore_ascii_case(name))
+            .map(|&(scancode, _)| Self(scancode))
+    }
+
+    /// Get the keycode this scancode produces with the US layout, with or without shift held.
+    pub fn to_keycode(self, shift: bool) -> Keycode {
+        match SDL_US_KEYMAP
+            .iter()
+            .find(|(scancode, _, _)| *scancode == self.0)
+        {
+            Some(&(_, key, shifted)) => Keycode(if shift { shifted } else { key }),
+            None => Keycode(SDL_SCANCODE_TO_KEYCODE(self.0)),
+        }
+    }
+}
+
+impl Debug for Scancode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => write!(f, "Scancode({name:?})"),
+            None => write!(f, "Scancode({})", self.0 .0),
+        }
+    }
+}
+
+/// Writes the name of the scancode, or nothing if it doesn't have one.
+impl Display for Scancode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name().unwrap_or(""))
+    }
+}
+
+impl FromStr for Scancode {
+    type Err = KeyNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(KeyNameError::new(0, KeyNameErrorKind::Empty));
+        }
+        Self::from_name(s).ok_or(KeyNameError::new(0, KeyNameErrorKind::UnknownKey))
+    }
+}
+
+impl From<SDL_Scancode> for Scancode {
+    #[inline(always)]
+    fn from(value: SDL_Scancode) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Scancode> for SDL_Scancode {
+    #[inline(always)]
+    fn from(value: Scancode) -> Self {
+        value.0
+    }
+}
+
+/// A virtual key. Converts to and from its SDL name with [`Display`] and [`FromStr`].
+///
+/// Keys that produce a character are named by the character, in upper case for letters.
+/// Other keys are named after the scancode that produces them with the US layout.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Keycode(pub SDL_Keycode);
+
+impl Keycode {
+    /// Iterate over all the keycodes defined by SDL.
+    pub fn all() -> impl ExactSizeIterator<Item = Self> + Clone {
+        SDL_KEYCODES.iter().map(|&key| Self(key))
+    }
+
+    /// Get the keycode a scancode produces with the US layout, with or without shift held.
+    #[inline]
+    pub fn from_scancode(scancode: Scancode, shift: bool) -> Self {
+        scancode.to_keycode(shift)
+    }
+
+    /// Get the scancode that produces this keycode with the US layout, and whether shift
+    /// has to be held for it. Returns `None` if no key produces this keycode.
+    pub fn to_scancode(self) -> Option<(Scancode, bool)> {
+        if self.0 & SDLK_SCANCODE_MASK != 0 {
+            return Some((
+                Scancode(SDL_Scancode((self.0 & !SDLK_SCANCODE_MASK) as _)),
+                false,
+            ));
+        }
+        SDL_US_KEYMAP.iter().find_map(|&(scancode, key, shifted)| {
+            if key == self.0 {
+                Some((Scancode(scancode), false))
+            } else if shifted == self.0 {
+                Some((Scancode(scancode), true))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Look up a keycode by name. Like [`SDL_GetKeyFromName`](sdl3_sys::keyboard::SDL_GetKeyFromName),
+    /// this accepts a single character or the name of a scancode, and returns the unshifted key
+    /// for letters.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let mut chars = name.chars();
+        match (chars.next(), chars.next()) {
+            (None, _) => None,
+            (Some(ch), None) => Some(Self(ch.to_ascii_lowercase() as SDL_Keycode)),
+            _ => Scancode::from_name(name).map(|scancode| scancode.to_keycode(false)),
+        }
+    }
+}
+
+impl Debug for Keycode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Keycode(\"{self}\")")
+    }
+}
+
+/// Writes the name of the keycode, or nothing if it doesn't have one.
+impl Display for Keycode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let key = self.0;
+        if key & SDLK_SCANCODE_MASK != 0
+            || matches!(
+                key,
+                SDLK_RETURN | SDLK_ESCAPE | SDLK_BACKSPACE | SDLK_TAB | SDLK_SPACE | SDLK_DELETE
+            )
+        {
+            match self.to_scancode() {
+                Some((scancode, _)) => Display::fmt(&scancode, f),
+                None => Ok(()),
+            }
+        } else if key == SDLK_UNKNOWN {
+            Ok(())
+        } else if let Some(ch) = char::from_u32(key) {
+            write!(f, "{}", ch.to_ascii_uppercase())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl FromStr for Keycode {
+    type Err = KeyNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(KeyNameError::new(0, KeyNameErrorKind::Empty));
+        }
+        Self::from_name(s).ok_or(KeyNameError::new(0, KeyNameErrorKind::UnknownKey))
+    }
+}
+
+impl From<SDL_Keycode> for Keycode {
+    #[inline(always)]
+    fn from(value: SDL_Keycode) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Keycode> for SDL_Keycode {
+    #[inline(always)]
+    fn from(value: Keycode) -> Self {
+        value.0
+    }
+}
+
+// (name, left name, right name, both, left, right)
+const MOD_GROUPS: [(&str, &str, &str, SDL_Keymod, SDL_Keymod, SDL_Keymod); 4] = [
+    (
+        "Ctrl",
+        "LCtrl",
+        "RCtrl",
+        SDL_KMOD_CTRL,
+        SDL_KMOD_LCTRL,
+        SDL_KMOD_RCTRL,
+    ),
+    (
+        "Shift",
+        "LShift",
+        "RShift",
+        SDL_KMOD_SHIFT,
+        SDL_KMOD_LSHIFT,
+        SDL_KMOD_RSHIFT,
+    ),
+    (
+        "Alt",
+        "LAlt",
+        "RAlt",
+        SDL_KMOD_ALT,
+        SDL_KMOD_LALT,
+        SDL_KMOD_RALT,
+    ),
+    (
+        "GUI",
+        "LGUI",
+        "RGUI",
+        SDL_KMOD_GUI,
+        SDL_KMOD_LGUI,
+        SDL_KMOD_RGUI,
+    ),
+];
+
+const MOD_LOCKS: [(&str, SDL_Keymod); 4] = [
+    ("AltGr", SDL_KMOD_MODE),
+    ("NumLock", SDL_KMOD_NUM),
+    ("CapsLock", SDL_KMOD_CAPS),
+    ("ScrollLock", SDL_KMOD_SCROLL),
+];
+
+/// A set of key modifiers, formatted like `Ctrl+Shift`.
+///
+/// A modifier without a side, like `Ctrl`, means either side. Use `LCtrl` or `RCtrl` for a
+/// specific side. The lock keys and AltGr are named `NumLock`, `CapsLock`, `ScrollLock` and
+/// `AltGr`.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Keymod(pub SDL_Keymod);
+
+impl Keymod {
+    /// No modifiers
+    pub const NONE: Self = Self(SDL_KMOD_NONE);
+
+    /// Returns true if no modifiers are set.
+    #[inline(always)]
+    pub const fn is_empty(self) -> bool {
+        self.0 == SDL_KMOD_NONE
+    }
+
+    /// Returns true if all the modifiers in `other` are also set in `self`.
+    #[inline(always)]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    fn from_name(name: &str) -> Option<SDL_Keymod> {
+        for (both_name, left_name, right_name, both, left, right) in MOD_GROUPS {
+            if name.eq_ignore_ascii_case(both_name) {
+                return Some(both);
+            } else if name.eq_ignore_ascii_case(left_name) {
+                return Some(left);
+            } else if name.eq_ignore_ascii_case(right_name) {
+                return Some(right);
+            }
+        }
+        MOD_LOCKS
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|&(_, m)| m)
+    }
+}
+
+impl Debug for Keymod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Keymod(\"{self}\")")
+    }
+}
+
+/// Writes the modifiers separated by `+`, or nothing if there are none.
+impl Display for Keymod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut sep = "";
+        let mut write = |f: &mut fmt::Formatter<'_>, name: &str| {
+            let result = write!(f, "{sep}{name}");
+            sep = "+";
+            result
+        };
+        for (both_name, left_name, right_name, both, left, right) in MOD_GROUPS {
+            let m = self.0 & both;
+            if m == both {
+                write(f, both_name)?;
+            } else if m == left {
+                write(f, left_name)?;
+            } else if m == right {
+                write(f, right_name)?;
+            }
+        }
+        for (name, m) in MOD_LOCKS {
+            if self.0 & m != 0 {
+                write(f, name)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Keymod {
+    type Err = KeyNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Ok(Self::NONE);
+        }
+        let mut mods = SDL_KMOD_NONE;
+        let mut offset = 0;
+        for name in s.split('+') {
+            mods |= Self::from_name(name.trim()).ok_or(KeyNameError::new(
+                offset,
+                if name.trim().is_empty() {
+                    KeyNameErrorKind::Empty
+                } else {
+                    KeyNameErrorKind::UnknownModifier
+                },
+            ))?;
+            offset += name.len() + 1;
+        }
+        Ok(Self(mods))
+    }
+}
+
+impl From<SDL_Keymod> for Keymod {
+    #[inline(always)]
+    fn from(value: SDL_Keymod) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Keymod> for SDL_Keymod {
+    #[inline(always)]
+    fn from(value: Keymod) -> Self {
+        value.0
+    }
+}
+
+/// A key with modifiers, formatted like `Ctrl+Shift+A`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct KeyCombo {
+    /// Modifiers that have to be held
+    pub mods: Keymod,
+    /// The key
+    pub key: Keycode,
+}
+
+impl KeyCombo {
+    /// Create a new `KeyCombo`.
+    #[inline(always)]
+    pub const fn new(mods: Keymod, key: Keycode) -> Self {
+        Self { mods, key }
+    }
+
+    /// Check if a key press with the modifier state `mods` triggers this combo.
+    ///
+    /// A modifier without a side in the combo matches either side, and the lock keys are
+    /// ignored unless the combo includes them. Modifiers that aren't in the combo must not
+    /// be held.
+    pub fn matches(&self, key: Keycode, mods: Keymod) -> bool {
+        if key != self.key {
+            return false;
+        }
+        for (_, _, _, both, _, _) in MOD_GROUPS {
+            let want = self.mods.0 & both;
+            let have = mods.0 & both;
+            let ok = if want == both {
+                have != 0
+            } else if want == 0 {
+                have == 0
+            } else {
+                have & want != 0
+            };
+            if !ok {
+                return false;
+            }
+        }
+        MOD_LOCKS
+            .iter()
+            .all(|&(_, m)| self.mods.0 & m == 0 || mods.0 & m != 0)
+    }
+}
+
+impl Display for KeyCombo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.mods.is_empty() {
+            write!(f, "{}+", self.mods)?;
+        }
+        Display::fmt(&self.key, f)
+    }
+}
+
+impl FromStr for KeyCombo {
+    type Err = KeyNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // modifiers are taken from the front for as long as they parse, so that keys with
+        // a `+` in their name, like `Keypad +` or `+` itself, still work
+        let mut mods = SDL_KMOD_NONE;
+        let mut offset = 0;
+        while let Some(i) = s[offset..].find('+') {
+            let Some(m) = Keymod::from_name(s[offset..offset + i].trim()) else {
+                break;
+            };
+            mods |= m;
+            offset += i + 1;
+        }
+        let key = s[offset..].trim();
+        if key.is_empty() {
+            return Err(KeyNameError::new(offset, KeyNameErrorKind::Empty));
+        }
+        let key = Keycode::from_name(key)
+            .ok_or(KeyNameError::new(offset, KeyNameErrorKind::UnknownKey))?;
+        Ok(Self::new(Keymod(mods), key))
+    }
+}
+
+/// An error from parsing a key or modifier name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct KeyNameError {
+    /// Byte offset in the string where the error was detected.
+    pub offset: usize,
+
+    /// What went wrong.
+    pub kind: KeyNameErrorKind,
+}
+
+impl KeyNameError {
+    const fn new(offset: usize, kind: KeyNameErrorKind) -> Self {
+        Self { offset, kind }
+    }
+}
+
+impl Display for KeyNameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at offset {}", self.kind, self.offset)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for KeyNameError {}
+
+#[cfg(feature = "alloc")]
+impl From<KeyNameError> for Error {
+    fn from(value: KeyNameError) -> Self {
+        Error::new(alloc::format!("invalid key name: {value}"))
+    }
+}
+
+/// The kind of a [`KeyNameError`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum KeyNameErrorKind {
+    /// A key or modifier name is missing
+    Empty,
+
+    /// The key name isn't a known key
+    UnknownKey,
+
+    /// A modifier name isn't a known modifier
+    UnknownModifier,
+}
+
+impl Display for KeyNameErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Empty => "missing name",
+            Self::UnknownKey => "unknown key",
+            Self::UnknownModifier => "unknown modifier",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{ffi::CString, string::ToString};
+    use core::ffi::{c_char, CStr};
+    use sdl3_sys::{
+        keyboard::{
+            SDL_GetKeyFromName, SDL_GetKeyFromScancode, SDL_GetKeyName, SDL_GetScancodeName,
+        },
+        keycode::SDLK_UNKNOWN,
+        scancode::SDL_SCANCODE_COUNT,
+    };
+
+    fn sdl_str(s: *const c_char) -> &'static str {
+        unsafe { CStr::from_ptr(s) }.to_str().unwrap()
+    }
+
+    #[test]
+    fn scancode_names_match_sdl() {
+        for i in 0..SDL_SCANCODE_COUNT.0 {
+            let scancode = Scancode(SDL_Scancode(i));
+            let expected = sdl_str(unsafe { SDL_GetScancodeName(scancode.0) });
+            assert_eq!(scancode.name().unwrap_or(""), expected, "scancode {i}");
+        }
+    }
+
+    #[test]
+    fn keycode_names_match_sdl() {
+        for key in Keycode::all() {
+            let name = sdl_str(unsafe { SDL_GetKeyName(key.0) });
+            assert_eq!(key.to_string(), name, "keycode {:#x}", key.0);
+        }
+        let names = Keycode::all()
+            .map(|key| key.to_string())
+            .chain(Scancode::all().map(|scancode| scancode.to_string()));
+        for name in names.filter(|name| !name.is_empty()) {
+            let c_name = CString::new(name.as_str()).unwrap();
+            let expected = unsafe { SDL_GetKeyFromName(c_name.as_ptr()) };
+            assert_eq!(
+                Keycode::from_name(&name).map_or(SDLK_UNKNOWN, |key| key.0),
+                expected,
+                "{name:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn us_keymap_matches_sdl() {
+        for scancode in Scancode::all() {
+            for (shift, mods) in [(false, SDL_KMOD_NONE), (true, SDL_KMOD_SHIFT)] {
+                let expected = unsafe { SDL_GetKeyFromScancode(scancode.0, mods, false) };
+                assert_eq!(
+                    scancode.to_keycode(shift).0,
+                    expected,
+                    "{scancode:?} shift={shift}"
+                );
+            }
+        }
+        for &(scancode, key, shifted) in SDL_US_KEYMAP {
+            assert_eq!(
+                Keycode(key).to_scancode(),
+                Some((Scancode(scancode), false))
+            );
+            if shifted != key {
+                assert_eq!(
+                    Keycode(shifted).to_scancode().map(|(_, shift)| shift),
+                    Some(true),
+                    "{:?}",
+                    Keycode(shifted)
+                );
+            }
+        }
+    }
+}
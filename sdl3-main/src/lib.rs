@@ -117,6 +117,7 @@ pub use sdl3_main_macros::app_iterate;
 /// &mut SDL_Event
 /// *const SDL_Event
 /// *mut SDL_Event
+/// sdl3_main::user_event::Event<T> // with the `std` feature
 /// ```
 /// You can add support for your own event types by implementing the `PassEventVal`, `PassEventRef` and/or `PassEventMut` traits.
 pub use sdl3_main_macros::app_event;
@@ -159,9 +160,61 @@ use sdl3_sys::{
 };
 
 pub mod app;
+#[cfg(feature = "alloc")]
+pub mod camera;
+#[cfg(feature = "alloc")]
+pub mod clipboard;
+pub mod colorspace;
+#[cfg(feature = "alloc")]
+pub mod cursor;
+#[cfg(feature = "alloc")]
+pub mod display;
+#[cfg(feature = "alloc")]
+mod error;
+#[cfg(feature = "alloc")]
+pub mod events;
+#[cfg(feature = "alloc")]
+pub mod gamepad_mapping;
+#[cfg(feature = "alloc")]
+pub mod gpu;
+#[cfg(feature = "alloc")]
+pub mod haptic;
+#[cfg(feature = "alloc")]
+pub mod hid;
+#[cfg(feature = "alloc")]
+pub mod input;
+pub mod keyboard;
 mod main_thread;
+#[cfg(feature = "alloc")]
+pub mod message_box;
+pub mod pixels;
+#[cfg(feature = "alloc")]
+mod properties;
+#[cfg(feature = "use-raw-window-handle-v0-6")]
+mod raw_window_handle;
+pub mod rect;
+#[cfg(feature = "alloc")]
+pub mod render;
+#[cfg(feature = "std")]
+pub mod replay;
+#[cfg(feature = "alloc")]
+pub mod shared_object;
 pub mod state;
+#[cfg(feature = "alloc")]
+pub mod surface;
+#[cfg(feature = "alloc")]
+pub mod text_input;
+#[cfg(feature = "std")]
+pub mod user_event;
+#[cfg(feature = "alloc")]
+pub mod virtual_joystick;
+#[cfg(feature = "use-ash-v0-38")]
+pub mod vulkan;
+#[cfg(feature = "alloc")]
+pub mod window;
 
+#[cfg(feature = "alloc")]
+pub use error::{Error, Result};
 pub use main_thread::{MainThreadData, MainThreadToken};
 use state::{AppState, BorrowMut, BorrowRef, BorrowVal, ConsumeMut, ConsumeRef, ConsumeVal};
 
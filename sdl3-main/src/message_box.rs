@@ -0,0 +1,255 @@
+//! Message boxes from [`sdl3_sys::messagebox`] with typed buttons.
+//!
+//! [`MessageBox`] builds the [`SDL_MessageBoxData`] for [`SDL_ShowMessageBox`], and
+//! [`MessageBox::show`] returns the ID of the button that was pressed. IDs can be any
+//! `Copy` type, like an enum of the possible choices. Message boxes don't need a parent
+//! window, so they can be shown before any window has been created or after SDL has failed
+//! to initialize.
+//!
+//! ```no_run
+//! use sdl3_main::message_box::{MessageBox, MessageBoxKind};
+//! use sdl3_sys::messagebox::{
+//!     SDL_MESSAGEBOX_BUTTON_ESCAPEKEY_DEFAULT, SDL_MESSAGEBOX_BUTTON_RETURNKEY_DEFAULT,
+//! };
+//!
+//! #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+//! enum Choice {
+//!     Report,
+//!     Quit,
+//! }
+//!
+//! let choice = MessageBox::new(MessageBoxKind::Error)
+//!     .title("Crashed")
+//!     .message("The game crashed. Send a crash report?")
+//!     .button(Choice::Report, "Send report", SDL_MESSAGEBOX_BUTTON_RETURNKEY_DEFAULT)
+//!     .button(Choice::Quit, "Quit", SDL_MESSAGEBOX_BUTTON_ESCAPEKEY_DEFAULT)
+//!     .show(None)?;
+//! if choice == Some(Choice::Report) {
+//!     // ...
+//! }
+//! # Ok::<(), sdl3_main::Error>(())
+//! ```
+
+use crate::{error::len_to_c_int, window::Window, Error, Result};
+use alloc::{ffi::CString, string::String, vec::Vec};
+use core::{ffi::c_int, fmt, ptr};
+use sdl3_sys::messagebox::{
+    SDL_MessageBoxButtonData, SDL_MessageBoxButtonFlags, SDL_MessageBoxColor,
+    SDL_MessageBoxColorScheme, SDL_MessageBoxColorType, SDL_MessageBoxData, SDL_MessageBoxFlags,
+    SDL_ShowMessageBox, SDL_MESSAGEBOX_BUTTONS_LEFT_TO_RIGHT, SDL_MESSAGEBOX_BUTTONS_RIGHT_TO_LEFT,
+    SDL_MESSAGEBOX_COLOR_COUNT, SDL_MESSAGEBOX_ERROR, SDL_MESSAGEBOX_INFORMATION,
+    SDL_MESSAGEBOX_WARNING,
+};
+
+/// The kind of message box, which decides its icon.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum MessageBoxKind {
+    /// Error dialog
+    Error,
+
+    /// Warning dialog
+    Warning,
+
+    /// Informational dialog
+    Information,
+}
+
+impl MessageBoxKind {
+    /// Get the SDL flag for this kind.
+    pub const fn to_flags(self) -> SDL_MessageBoxFlags {
+        match self {
+            Self::Error => SDL_MESSAGEBOX_ERROR,
+            Self::Warning => SDL_MESSAGEBOX_WARNING,
+            Self::Information => SDL_MESSAGEBOX_INFORMATION,
+        }
+    }
+}
+
+/// The colors SDL uses for message boxes by default, on platforms where the colors can
+/// be changed. Use this as a base when only changing some colors with [`MessageBox::color`].
+pub const DEFAULT_COLOR_SCHEME: SDL_MessageBoxColorScheme = SDL_MessageBoxColorScheme {
+    colors: [
+        SDL_MessageBoxColor {
+            r: 56,
+            g: 54,
+            b: 53,
+        },
+        SDL_MessageBoxColor {
+            r: 209,
+            g: 207,
+            b: 205,
+        },
+        SDL_MessageBoxColor {
+            r: 140,
+            g: 135,
+            b: 129,
+        },
+        SDL_MessageBoxColor {
+            r: 105,
+            g: 102,
+            b: 99,
+        },
+        SDL_MessageBoxColor {
+            r: 205,
+            g: 202,
+            b: 53,
+        },
+    ],
+};
+
+struct Button<B> {
+    id: B,
+    text: String,
+    flags: SDL_MessageBoxButtonFlags,
+}
+
+/// Builder for a message box with buttons identified by values of type `B`.
+/// See [`SDL_ShowMessageBox`].
+pub struct MessageBox<B> {
+    flags: SDL_MessageBoxFlags,
+    title: String,
+    message: String,
+    buttons: Vec<Button<B>>,
+    colors: Option<SDL_MessageBoxColorScheme>,
+}
+
+impl<B: Copy> MessageBox<B> {
+    /// Create a new builder for a message box without a title, message or buttons.
+    pub fn new(kind: MessageBoxKind) -> Self {
+        Self {
+            flags: kind.to_flags(),
+            title: String::new(),
+            message: String::new(),
+            buttons: Vec::new(),
+            colors: None,
+        }
+    }
+
+    /// Set the title.
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Set the message.
+    pub fn message(mut self, message: &str) -> Self {
+        self.message = message.into();
+        self
+    }
+
+    /// Add a button. `flags` can be `0`, or
+    /// [`SDL_MESSAGEBOX_BUTTON_RETURNKEY_DEFAULT`](sdl3_sys::messagebox::SDL_MESSAGEBOX_BUTTON_RETURNKEY_DEFAULT)
+    /// and/or
+    /// [`SDL_MESSAGEBOX_BUTTON_ESCAPEKEY_DEFAULT`](sdl3_sys::messagebox::SDL_MESSAGEBOX_BUTTON_ESCAPEKEY_DEFAULT)
+    /// to make it the button chosen by the return or escape key.
+    ///
+    /// Buttons are shown in the order they're added, unless the order is changed with
+    /// [`MessageBox::buttons_right_to_left`].
+    pub fn button(mut self, id: B, text: &str, flags: SDL_MessageBoxButtonFlags) -> Self {
+        self.buttons.push(Button {
+            id,
+            text: text.into(),
+            flags,
+        });
+        self
+    }
+
+    /// Lay out the buttons from right to left instead of from left to right.
+    pub fn buttons_right_to_left(mut self, value: bool) -> Self {
+        self.flags &=
+            !(SDL_MESSAGEBOX_BUTTONS_LEFT_TO_RIGHT | SDL_MESSAGEBOX_BUTTONS_RIGHT_TO_LEFT);
+        self.flags |= if value {
+            SDL_MESSAGEBOX_BUTTONS_RIGHT_TO_LEFT
+        } else {
+            SDL_MESSAGEBOX_BUTTONS_LEFT_TO_RIGHT
+        };
+        self
+    }
+
+    /// Set the color scheme. Not all platforms support this.
+    pub fn colors(mut self, colors: SDL_MessageBoxColorScheme) -> Self {
+        self.colors = Some(colors);
+        self
+    }
+
+    /// Set one color of the color scheme. Colors that haven't been set use
+    /// [`DEFAULT_COLOR_SCHEME`]. Not all platforms support this.
+    pub fn color(
+        mut self,
+        color_type: SDL_MessageBoxColorType,
+        color: SDL_MessageBoxColor,
+    ) -> Self {
+        let colors = self.colors.get_or_insert(DEFAULT_COLOR_SCHEME);
+        if (0..SDL_MESSAGEBOX_COLOR_COUNT.0).contains(&color_type.0) {
+            colors.colors[color_type.0 as usize] = color;
+        }
+        self
+    }
+
+    /// Show the message box and wait for the user to press a button. Returns the ID of the
+    /// button that was pressed, or `None` if the message box was closed without pressing
+    /// one.
+    ///
+    /// This should be called on the main thread, or on the thread that created `parent`.
+    pub fn show(&self, parent: Option<&Window>) -> Result<Option<B>> {
+        let cstring = |s: &str| CString::new(s).map_err(|_| Error::new("string contains nul"));
+        let title = cstring(&self.title)?;
+        let message = cstring(&self.message)?;
+        let texts = self
+            .buttons
+            .iter()
+            .map(|button| cstring(&button.text))
+            .collect::<Result<Vec<_>>>()?;
+        // the index is used as the SDL button ID
+        let buttons: Vec<SDL_MessageBoxButtonData> = self
+            .buttons
+            .iter()
+            .zip(&texts)
+            .enumerate()
+            .map(|(i, (button, text))| SDL_MessageBoxButtonData {
+                flags: button.flags,
+                buttonID: i as c_int,
+                text: text.as_ptr(),
+            })
+            .collect();
+        let data = SDL_MessageBoxData {
+            flags: self.flags,
+            window: parent.map_or(ptr::null_mut(), Window::as_ptr),
+            title: title.as_ptr(),
+            message: message.as_ptr(),
+            numbuttons: len_to_c_int(buttons.len())?,
+            buttons: buttons.as_ptr(),
+            colorScheme: self.colors.as_ref().map_or(ptr::null(), |c| c as *const _),
+        };
+        let mut button_id: c_int = -1;
+        if !unsafe { SDL_ShowMessageBox(&data, &mut button_id) } {
+            return Err(Error::from_sdl());
+        }
+        Ok(usize::try_from(button_id)
+            .ok()
+            .and_then(|i| self.buttons.get(i))
+            .map(|button| button.id))
+    }
+}
+
+impl<B: fmt::Debug> fmt::Debug for MessageBox<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MessageBox")
+            .field("flags", &self.flags)
+            .field("title", &self.title)
+            .field("message", &self.message)
+            .field(
+                "buttons",
+                &self
+                    .buttons
+                    .iter()
+                    .map(|b| (&b.id, &b.text, b.flags))
+                    .collect::<Vec<_>>(),
+            )
+            .field(
+                "colors",
+                &self.colors.map(|c| c.colors.map(|c| (c.r, c.g, c.b))),
+            )
+            .finish()
+    }
+}
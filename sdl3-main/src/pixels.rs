@@ -0,0 +1,493 @@
+//! Color types that are layout compatible with the ones in [`sdl3_sys::pixels`], and pure
+//! Rust helpers for pixel formats.
+//!
+//! The layout of every non-FOURCC [`SDL_PixelFormat`] is fully determined by its value, so
+//! [`pixel_format_details`], [`map_rgba`] and [`get_rgba`] can replace
+//! [`SDL_GetPixelFormatDetails`], [`SDL_MapRGBA`] and [`SDL_GetRGBA`] for non-indexed formats
+//! without calling into SDL. They're `const`, so they can also be used at compile time.
+
+use sdl3_sys::pixels::{
+    SDL_Color, SDL_FColor, SDL_PackedLayout, SDL_PackedOrder, SDL_PixelFormat,
+    SDL_PixelFormatDetails, SDL_PixelType, SDL_BITSPERPIXEL, SDL_BYTESPERPIXEL,
+    SDL_ISPIXELFORMAT_10BIT, SDL_ISPIXELFORMAT_FOURCC, SDL_ISPIXELFORMAT_INDEXED, SDL_PIXELLAYOUT,
+    SDL_PIXELORDER, SDL_PIXELTYPE,
+};
+
+#[cfg(doc)]
+use sdl3_sys::pixels::{SDL_GetPixelFormatDetails, SDL_GetRGBA, SDL_MapRGBA};
+
+/// An RGBA color with 8 bits per channel. Equivalent to [`SDL_Color`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Color {
+    pub const TRANSPARENT: Self = Self::rgba(0, 0, 0, 0);
+    pub const BLACK: Self = Self::rgb(0, 0, 0);
+    pub const WHITE: Self = Self::rgb(255, 255, 255);
+    pub const RED: Self = Self::rgb(255, 0, 0);
+    pub const GREEN: Self = Self::rgb(0, 255, 0);
+    pub const BLUE: Self = Self::rgb(0, 0, 255);
+
+    /// Create an opaque color.
+    #[inline(always)]
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b, a: 255 }
+    }
+
+    #[inline(always)]
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+}
+
+impl From<Color> for SDL_Color {
+    #[inline(always)]
+    fn from(value: Color) -> Self {
+        Self {
+            r: value.r,
+            g: value.g,
+            b: value.b,
+            a: value.a,
+        }
+    }
+}
+
+impl From<SDL_Color> for Color {
+    #[inline(always)]
+    fn from(value: SDL_Color) -> Self {
+        Self::rgba(value.r, value.g, value.b, value.a)
+    }
+}
+
+/// An RGBA color with floating point channels. Equivalent to [`SDL_FColor`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FColor {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl FColor {
+    #[inline(always)]
+    pub const fn rgba(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+}
+
+impl From<Color> for FColor {
+    #[inline]
+    fn from(value: Color) -> Self {
+        let f = |c: u8| c as f32 / 255.0;
+        Self::rgba(f(value.r), f(value.g), f(value.b), f(value.a))
+    }
+}
+
+impl From<FColor> for SDL_FColor {
+    #[inline(always)]
+    fn from(value: FColor) -> Self {
+        Self {
+            r: value.r,
+            g: value.g,
+            b: value.b,
+            a: value.a,
+        }
+    }
+}
+
+impl From<SDL_FColor> for FColor {
+    #[inline(always)]
+    fn from(value: SDL_FColor) -> Self {
+        Self::rgba(value.r, value.g, value.b, value.a)
+    }
+}
+
+/// Get the bits per pixel and the red, green, blue and alpha masks of a pixel format.
+///
+/// Returns `(bpp, [rmask, gmask, bmask, amask])`, or `None` for FOURCC and unknown formats.
+/// Formats that don't use masks, like indexed and array formats, have all masks set to zero.
+/// This matches [`SDL_GetMasksForPixelFormat`](sdl3_sys::pixels::SDL_GetMasksForPixelFormat).
+pub const fn pixel_format_masks(format: SDL_PixelFormat) -> Option<(u8, [u32; 4])> {
+    if format.0 == SDL_PixelFormat::UNKNOWN.0 || SDL_ISPIXELFORMAT_FOURCC(format) {
+        return None;
+    }
+    let bpp = if SDL_BYTESPERPIXEL(format) <= 2 {
+        SDL_BITSPERPIXEL(format)
+    } else {
+        SDL_BYTESPERPIXEL(format) * 8
+    };
+
+    #[cfg(target_endian = "little")]
+    const RGB24: [u32; 4] = [0x0000ff, 0x00ff00, 0xff0000, 0];
+    #[cfg(target_endian = "big")]
+    const RGB24: [u32; 4] = [0xff0000, 0x00ff00, 0x0000ff, 0];
+
+    if format.0 == SDL_PixelFormat::RGB24.0 {
+        return Some((bpp, RGB24));
+    } else if format.0 == SDL_PixelFormat::BGR24.0 {
+        return Some((bpp, [RGB24[2], RGB24[1], RGB24[0], 0]));
+    }
+
+    let ty = SDL_PIXELTYPE(format);
+    if ty.0 != SDL_PixelType::PACKED8.0
+        && ty.0 != SDL_PixelType::PACKED16.0
+        && ty.0 != SDL_PixelType::PACKED32.0
+    {
+        // not a format that uses masks
+        return Some((bpp, [0; 4]));
+    }
+
+    let m = match SDL_PIXELLAYOUT(format) {
+        SDL_PackedLayout::_332 => [0x00000000, 0x000000e0, 0x0000001c, 0x00000003],
+        SDL_PackedLayout::_4444 => [0x0000f000, 0x00000f00, 0x000000f0, 0x0000000f],
+        SDL_PackedLayout::_1555 => [0x00008000, 0x00007c00, 0x000003e0, 0x0000001f],
+        SDL_PackedLayout::_5551 => [0x0000f800, 0x000007c0, 0x0000003e, 0x00000001],
+        SDL_PackedLayout::_565 => [0x00000000, 0x0000f800, 0x000007e0, 0x0000001f],
+        SDL_PackedLayout::_8888 => [0xff000000, 0x00ff0000, 0x0000ff00, 0x000000ff],
+        SDL_PackedLayout::_2101010 => [0xc0000000, 0x3ff00000, 0x000ffc00, 0x000003ff],
+        SDL_PackedLayout::_1010102 => [0xffc00000, 0x003ff000, 0x00000ffc, 0x00000003],
+        _ => return None,
+    };
+
+    // [r, g, b, a]
+    let masks = match SDL_PackedOrder(SDL_PIXELORDER(format)) {
+        SDL_PackedOrder::XRGB => [m[1], m[2], m[3], 0],
+        SDL_PackedOrder::RGBX => [m[0], m[1], m[2], 0],
+        SDL_PackedOrder::ARGB => [m[1], m[2], m[3], m[0]],
+        SDL_PackedOrder::RGBA => [m[0], m[1], m[2], m[3]],
+        SDL_PackedOrder::XBGR => [m[3], m[2], m[1], 0],
+        SDL_PackedOrder::BGRX => [m[2], m[1], m[0], 0],
+        SDL_PackedOrder::ABGR => [m[3], m[2], m[1], m[0]],
+        SDL_PackedOrder::BGRA => [m[2], m[1], m[0], m[3]],
+        _ => return None,
+    };
+    Some((bpp, masks))
+}
+
+/// Get the details of a pixel format. This is the pure Rust equivalent of
+/// [`SDL_GetPixelFormatDetails`].
+///
+/// Returns `None` for FOURCC and unknown formats.
+pub const fn pixel_format_details(format: SDL_PixelFormat) -> Option<SDL_PixelFormatDetails> {
+    const fn shift_and_bits(mask: u32) -> (u8, u8) {
+        if mask == 0 {
+            (0, 0)
+        } else {
+            let shift = mask.trailing_zeros();
+            (shift as u8, (mask >> shift).trailing_ones() as u8)
+        }
+    }
+
+    let Some((bpp, [rmask, gmask, bmask, amask])) = pixel_format_masks(format) else {
+        return None;
+    };
+    let (rshift, rbits) = shift_and_bits(rmask);
+    let (gshift, gbits) = shift_and_bits(gmask);
+    let (bshift, bbits) = shift_and_bits(bmask);
+    let (ashift, abits) = shift_and_bits(amask);
+    Some(SDL_PixelFormatDetails {
+        format,
+        bits_per_pixel: bpp,
+        bytes_per_pixel: bpp.div_ceil(8),
+        padding: [0; 2],
+        Rmask: rmask,
+        Gmask: gmask,
+        Bmask: bmask,
+        Amask: amask,
+        Rbits: rbits,
+        Gbits: gbits,
+        Bbits: bbits,
+        Abits: abits,
+        Rshift: rshift,
+        Gshift: gshift,
+        Bshift: bshift,
+        Ashift: ashift,
+    })
+}
+
+const fn uses_masks(details: &SDL_PixelFormatDetails) -> bool {
+    !SDL_ISPIXELFORMAT_INDEXED(details.format)
+        && (details.Rmask | details.Gmask | details.Bmask) != 0
+}
+
+// scale an 8 bit channel value down to `bits` bits
+const fn pack_channel(value: u8, bits: u8, is_10bit: bool) -> u32 {
+    if bits == 0 {
+        0
+    } else if is_10bit {
+        // round to nearest, like SDL does for 10 bit formats
+        let max = (1_u32 << bits) - 1;
+        (value as u32 * max + 127) / 255
+    } else {
+        value as u32 >> (8 - bits)
+    }
+}
+
+// expand a channel value with `bits` bits to 8 bits by replicating the bits
+const fn unpack_channel(value: u32, bits: u8) -> u8 {
+    if bits == 0 {
+        0
+    } else if bits >= 8 {
+        (value >> (bits - 8)) as u8
+    } else {
+        let mut out = 0;
+        let mut shift = 8 - bits as i32;
+        while shift > -(bits as i32) {
+            out |= if shift >= 0 {
+                value << shift
+            } else {
+                value >> -shift
+            };
+            shift -= bits as i32;
+        }
+        out as u8
+    }
+}
+
+/// Pack a color into a pixel value for a packed or 24-bit RGB format. This is the pure
+/// Rust equivalent of [`SDL_MapRGBA`] for formats without a palette.
+///
+/// Returns `None` for formats that don't use channel masks, like indexed formats.
+pub const fn map_rgba(details: &SDL_PixelFormatDetails, color: Color) -> Option<u32> {
+    if !uses_masks(details) {
+        return None;
+    }
+    let is_10bit = SDL_ISPIXELFORMAT_10BIT(details.format);
+    Some(
+        (pack_channel(color.r, details.Rbits, is_10bit) << details.Rshift)
+            | (pack_channel(color.g, details.Gbits, is_10bit) << details.Gshift)
+            | (pack_channel(color.b, details.Bbits, is_10bit) << details.Bshift)
+            | ((pack_channel(color.a, details.Abits, is_10bit) << details.Ashift) & details.Amask),
+    )
+}
+
+/// Unpack a pixel value for a packed or 24-bit RGB format into a color. This is the pure
+/// Rust equivalent of [`SDL_GetRGBA`] for formats without a palette.
+///
+/// Formats without an alpha channel always return an alpha of 255. Returns `None` for
+/// formats that don't use channel masks, like indexed formats.
+pub const fn get_rgba(details: &SDL_PixelFormatDetails, pixel: u32) -> Option<Color> {
+    if !uses_masks(details) {
+        return None;
+    }
+    Some(Color {
+        r: unpack_channel((pixel & details.Rmask) >> details.Rshift, details.Rbits),
+        g: unpack_channel((pixel & details.Gmask) >> details.Gshift, details.Gbits),
+        b: unpack_channel((pixel & details.Bmask) >> details.Bshift, details.Bbits),
+        a: if details.Amask != 0 {
+            unpack_channel((pixel & details.Amask) >> details.Ashift, details.Abits)
+        } else {
+            255
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::{fmt, ptr};
+    use sdl3_sys::pixels::{
+        SDL_GetMasksForPixelFormat, SDL_GetPixelFormatDetails, SDL_GetRGBA, SDL_MapRGBA,
+    };
+
+    const FORMATS: &[SDL_PixelFormat] = &[
+        SDL_PixelFormat::UNKNOWN,
+        SDL_PixelFormat::INDEX1LSB,
+        SDL_PixelFormat::INDEX1MSB,
+        SDL_PixelFormat::INDEX2LSB,
+        SDL_PixelFormat::INDEX2MSB,
+        SDL_PixelFormat::INDEX4LSB,
+        SDL_PixelFormat::INDEX4MSB,
+        SDL_PixelFormat::INDEX8,
+        SDL_PixelFormat::RGB332,
+        SDL_PixelFormat::XRGB4444,
+        SDL_PixelFormat::XBGR4444,
+        SDL_PixelFormat::XRGB1555,
+        SDL_PixelFormat::XBGR1555,
+        SDL_PixelFormat::ARGB4444,
+        SDL_PixelFormat::RGBA4444,
+        SDL_PixelFormat::ABGR4444,
+        SDL_PixelFormat::BGRA4444,
+        SDL_PixelFormat::ARGB1555,
+        SDL_PixelFormat::RGBA5551,
+        SDL_PixelFormat::ABGR1555,
+        SDL_PixelFormat::BGRA5551,
+        SDL_PixelFormat::RGB565,
+        SDL_PixelFormat::BGR565,
+        SDL_PixelFormat::RGB24,
+        SDL_PixelFormat::BGR24,
+        SDL_PixelFormat::XRGB8888,
+        SDL_PixelFormat::RGBX8888,
+        SDL_PixelFormat::XBGR8888,
+        SDL_PixelFormat::BGRX8888,
+        SDL_PixelFormat::ARGB8888,
+        SDL_PixelFormat::RGBA8888,
+        SDL_PixelFormat::ABGR8888,
+        SDL_PixelFormat::BGRA8888,
+        SDL_PixelFormat::XRGB2101010,
+        SDL_PixelFormat::XBGR2101010,
+        SDL_PixelFormat::ARGB2101010,
+        SDL_PixelFormat::ABGR2101010,
+        SDL_PixelFormat::RGB48,
+        SDL_PixelFormat::BGR48,
+        SDL_PixelFormat::RGBA64,
+        SDL_PixelFormat::ARGB64,
+        SDL_PixelFormat::BGRA64,
+        SDL_PixelFormat::ABGR64,
+        SDL_PixelFormat::RGB48_FLOAT,
+        SDL_PixelFormat::BGR48_FLOAT,
+        SDL_PixelFormat::RGBA64_FLOAT,
+        SDL_PixelFormat::ARGB64_FLOAT,
+        SDL_PixelFormat::BGRA64_FLOAT,
+        SDL_PixelFormat::ABGR64_FLOAT,
+        SDL_PixelFormat::RGB96_FLOAT,
+        SDL_PixelFormat::BGR96_FLOAT,
+        SDL_PixelFormat::RGBA128_FLOAT,
+        SDL_PixelFormat::ARGB128_FLOAT,
+        SDL_PixelFormat::BGRA128_FLOAT,
+        SDL_PixelFormat::ABGR128_FLOAT,
+        SDL_PixelFormat::YV12,
+        SDL_PixelFormat::IYUV,
+        SDL_PixelFormat::YUY2,
+        SDL_PixelFormat::UYVY,
+        SDL_PixelFormat::YVYU,
+        SDL_PixelFormat::NV12,
+        SDL_PixelFormat::NV21,
+        SDL_PixelFormat::P010,
+        SDL_PixelFormat::EXTERNAL_OES,
+    ];
+
+    const CHANNEL_VALUES: [u8; 9] = [0, 1, 7, 63, 127, 128, 200, 254, 255];
+
+    fn fields(d: &SDL_PixelFormatDetails) -> impl PartialEq + fmt::Debug {
+        (
+            d.format.0,
+            (d.bits_per_pixel, d.bytes_per_pixel),
+            [d.Rmask, d.Gmask, d.Bmask, d.Amask],
+            [d.Rbits, d.Gbits, d.Bbits, d.Abits],
+            [d.Rshift, d.Gshift, d.Bshift, d.Ashift],
+        )
+    }
+
+    fn sdl_get_rgba(details: &SDL_PixelFormatDetails, pixel: u32) -> Color {
+        let mut c = Color::default();
+        unsafe {
+            SDL_GetRGBA(
+                pixel,
+                details,
+                ptr::null(),
+                &mut c.r,
+                &mut c.g,
+                &mut c.b,
+                &mut c.a,
+            )
+        };
+        c
+    }
+
+    #[test]
+    fn known_values() {
+        assert_eq!(
+            pixel_format_masks(SDL_PixelFormat::RGB565),
+            Some((16, [0xf800, 0x07e0, 0x001f, 0]))
+        );
+        assert_eq!(
+            pixel_format_masks(SDL_PixelFormat::ARGB8888),
+            Some((32, [0xff0000, 0xff00, 0xff, 0xff000000]))
+        );
+        assert_eq!(
+            pixel_format_masks(SDL_PixelFormat::INDEX8),
+            Some((8, [0; 4]))
+        );
+        assert_eq!(
+            pixel_format_masks(SDL_PixelFormat::RGBA64),
+            Some((64, [0; 4]))
+        );
+        assert_eq!(pixel_format_masks(SDL_PixelFormat::NV12), None);
+        assert_eq!(pixel_format_masks(SDL_PixelFormat::UNKNOWN), None);
+
+        let d = pixel_format_details(SDL_PixelFormat::ARGB1555).unwrap();
+        assert_eq!((d.Abits, d.Ashift, d.Rbits, d.Rshift), (1, 15, 5, 10));
+        assert_eq!(map_rgba(&d, Color::rgba(255, 0, 255, 255)), Some(0xfc1f));
+        assert_eq!(get_rgba(&d, 0xfc1f), Some(Color::rgba(255, 0, 255, 255)));
+        assert_eq!(get_rgba(&d, 0x0421), Some(Color::rgba(8, 8, 8, 0)));
+
+        let d = pixel_format_details(SDL_PixelFormat::XRGB8888).unwrap();
+        assert_eq!(map_rgba(&d, Color::rgba(1, 2, 3, 4)), Some(0x010203));
+        assert_eq!(get_rgba(&d, 0xff010203), Some(Color::rgba(1, 2, 3, 255)));
+
+        let d = pixel_format_details(SDL_PixelFormat::ARGB2101010).unwrap();
+        assert_eq!(map_rgba(&d, Color::WHITE), Some(0xffffffff));
+        assert_eq!(map_rgba(&d, Color::rgba(128, 0, 0, 0)), Some(514 << 20));
+
+        let d = pixel_format_details(SDL_PixelFormat::INDEX8).unwrap();
+        assert_eq!(map_rgba(&d, Color::WHITE), None);
+        assert_eq!(get_rgba(&d, 0), None);
+    }
+
+    #[test]
+    fn matches_sdl() {
+        for &format in FORMATS {
+            let Some((bpp, masks)) = pixel_format_masks(format) else {
+                assert!(format.0 == 0 || SDL_ISPIXELFORMAT_FOURCC(format));
+                assert!(pixel_format_details(format).is_none());
+                continue;
+            };
+
+            let mut sdl_bpp = 0;
+            let mut sdl_masks = [0; 4];
+            let [r, g, b, a] = &mut sdl_masks;
+            assert!(unsafe { SDL_GetMasksForPixelFormat(format, &mut sdl_bpp, r, g, b, a) });
+            assert_eq!((bpp as i32, masks), (sdl_bpp, sdl_masks), "{:#x}", format.0);
+
+            let details = pixel_format_details(format).unwrap();
+            let sdl_details = unsafe { SDL_GetPixelFormatDetails(format) };
+            assert!(!sdl_details.is_null());
+            let sdl_details = unsafe { &*sdl_details };
+            assert_eq!(fields(&details), fields(sdl_details), "{:#x}", format.0);
+
+            if map_rgba(&details, Color::BLACK).is_none() {
+                continue;
+            }
+            for r in CHANNEL_VALUES {
+                for g in CHANNEL_VALUES {
+                    for b in CHANNEL_VALUES {
+                        for a in CHANNEL_VALUES {
+                            let color = Color::rgba(r, g, b, a);
+                            let pixel = map_rgba(&details, color).unwrap();
+                            let sdl_pixel =
+                                unsafe { SDL_MapRGBA(sdl_details, ptr::null(), r, g, b, a) };
+                            assert_eq!(pixel, sdl_pixel, "{:#x} {color:?}", format.0);
+                            assert_eq!(
+                                get_rgba(&details, pixel).unwrap(),
+                                sdl_get_rgba(sdl_details, pixel),
+                                "{:#x} {pixel:#x}",
+                                format.0
+                            );
+                        }
+                    }
+                }
+            }
+            let pixels: &mut dyn Iterator<Item = u32> = if bpp <= 16 {
+                &mut (0..1 << bpp)
+            } else {
+                &mut (0..=u32::MAX).step_by(0x10101)
+            };
+            for pixel in pixels {
+                assert_eq!(
+                    get_rgba(&details, pixel).unwrap(),
+                    sdl_get_rgba(sdl_details, pixel),
+                    "{:#x} {pixel:#x}",
+                    format.0
+                );
+            }
+        }
+    }
+}
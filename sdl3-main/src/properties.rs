@@ -0,0 +1,51 @@
+use crate::{error::check, Error, Result};
+use core::ffi::{c_char, c_void, CStr};
+use sdl3_sys::properties::{
+    SDL_CreateProperties, SDL_DestroyProperties, SDL_PropertiesID, SDL_SetBooleanProperty,
+    SDL_SetNumberProperty, SDL_SetPointerProperty, SDL_SetStringProperty,
+};
+
+/// Owned set of properties, destroyed on drop. Used to pass options to the `*WithProperties`
+/// functions.
+pub(crate) struct Properties(SDL_PropertiesID);
+
+impl Properties {
+    pub fn new() -> Result<Self> {
+        let id = unsafe { SDL_CreateProperties() };
+        if id == 0 {
+            Err(Error::from_sdl())
+        } else {
+            Ok(Self(id))
+        }
+    }
+
+    #[inline(always)]
+    pub fn id(&self) -> SDL_PropertiesID {
+        self.0
+    }
+
+    // Safety for the setters: `name` must be a valid C string, like the `SDL_PROP_*` constants
+
+    pub unsafe fn set_number(&self, name: *const c_char, value: i64) -> Result<()> {
+        check(unsafe { SDL_SetNumberProperty(self.0, name, value) })
+    }
+
+    pub unsafe fn set_boolean(&self, name: *const c_char, value: bool) -> Result<()> {
+        check(unsafe { SDL_SetBooleanProperty(self.0, name, value) })
+    }
+
+    /// SDL copies the string
+    pub unsafe fn set_string(&self, name: *const c_char, value: &CStr) -> Result<()> {
+        check(unsafe { SDL_SetStringProperty(self.0, name, value.as_ptr()) })
+    }
+
+    pub unsafe fn set_pointer(&self, name: *const c_char, value: *mut c_void) -> Result<()> {
+        check(unsafe { SDL_SetPointerProperty(self.0, name, value) })
+    }
+}
+
+impl Drop for Properties {
+    fn drop(&mut self) {
+        unsafe { SDL_DestroyProperties(self.0) };
+    }
+}
@@ -0,0 +1,220 @@
+//! [`raw-window-handle`](raw_window_handle_v0_6) (v0.6) integration for [`Window`].
+//!
+//! The handles are read from the window's properties, based on the current video driver.
+//! Drivers that don't have a matching handle type return [`HandleError::NotSupported`].
+
+use crate::window::Window;
+use core::{
+    ffi::{c_char, c_int, c_ulong, c_void, CStr},
+    num::NonZeroIsize,
+    ptr::{self, NonNull},
+};
+use raw_window_handle_v0_6::{
+    AndroidDisplayHandle, AndroidNdkWindowHandle, AppKitDisplayHandle, DisplayHandle,
+    DrmDisplayHandle, HandleError, HasDisplayHandle, HasWindowHandle, RawDisplayHandle,
+    RawWindowHandle, UiKitDisplayHandle, WaylandDisplayHandle, WaylandWindowHandle,
+    Win32WindowHandle, WindowHandle, WindowsDisplayHandle, XlibDisplayHandle, XlibWindowHandle,
+};
+use sdl3_sys::{
+    properties::{SDL_GetNumberProperty, SDL_GetPointerProperty, SDL_PropertiesID},
+    video::{
+        SDL_GetCurrentVideoDriver, SDL_GetWindowProperties, SDL_PROP_WINDOW_ANDROID_WINDOW_POINTER,
+        SDL_PROP_WINDOW_KMSDRM_DRM_FD_NUMBER, SDL_PROP_WINDOW_WAYLAND_DISPLAY_POINTER,
+        SDL_PROP_WINDOW_WAYLAND_SURFACE_POINTER, SDL_PROP_WINDOW_WIN32_HWND_POINTER,
+        SDL_PROP_WINDOW_WIN32_INSTANCE_POINTER, SDL_PROP_WINDOW_X11_DISPLAY_POINTER,
+        SDL_PROP_WINDOW_X11_SCREEN_NUMBER, SDL_PROP_WINDOW_X11_WINDOW_NUMBER,
+    },
+};
+
+enum Driver {
+    Android,
+    Cocoa,
+    KmsDrm,
+    UiKit,
+    Wayland,
+    Windows,
+    X11,
+}
+
+fn current_driver() -> Result<Driver, HandleError> {
+    let name = unsafe { SDL_GetCurrentVideoDriver() };
+    if name.is_null() {
+        // video isn't initialized
+        return Err(HandleError::Unavailable);
+    }
+    match unsafe { CStr::from_ptr(name) }.to_bytes() {
+        b"android" => Ok(Driver::Android),
+        b"cocoa" => Ok(Driver::Cocoa),
+        b"kmsdrm" => Ok(Driver::KmsDrm),
+        b"uikit" => Ok(Driver::UiKit),
+        b"wayland" => Ok(Driver::Wayland),
+        b"windows" => Ok(Driver::Windows),
+        b"x11" => Ok(Driver::X11),
+        _ => Err(HandleError::NotSupported),
+    }
+}
+
+struct WindowProperties(SDL_PropertiesID);
+
+impl WindowProperties {
+    fn of(window: &Window) -> Result<Self, HandleError> {
+        let id = unsafe { SDL_GetWindowProperties(window.as_ptr()) };
+        if id == 0 {
+            Err(HandleError::Unavailable)
+        } else {
+            Ok(Self(id))
+        }
+    }
+
+    // Safety for the getters: `name` must be a valid C string, like the `SDL_PROP_*` constants
+
+    unsafe fn pointer(&self, name: *const c_char) -> Result<NonNull<c_void>, HandleError> {
+        NonNull::new(unsafe { SDL_GetPointerProperty(self.0, name, ptr::null_mut()) })
+            .ok_or(HandleError::Unavailable)
+    }
+
+    unsafe fn number(&self, name: *const c_char) -> Result<i64, HandleError> {
+        match unsafe { SDL_GetNumberProperty(self.0, name, 0) } {
+            0 => Err(HandleError::Unavailable),
+            n => Ok(n),
+        }
+    }
+}
+
+#[cfg(target_vendor = "apple")]
+mod apple {
+    use super::WindowProperties;
+    use core::{
+        ffi::{c_char, c_void, CStr},
+        mem,
+        ptr::NonNull,
+    };
+    use raw_window_handle_v0_6::{
+        AppKitWindowHandle, HandleError, RawWindowHandle, UiKitWindowHandle,
+    };
+    use sdl3_sys::video::{
+        SDL_PROP_WINDOW_COCOA_WINDOW_POINTER, SDL_PROP_WINDOW_UIKIT_WINDOW_POINTER,
+    };
+
+    #[link(name = "objc")]
+    extern "C" {
+        fn sel_registerName(name: *const c_char) -> *mut c_void;
+        fn objc_msgSend();
+    }
+
+    // Safety: `obj` must be a valid object that responds to `sel` by returning an object
+    unsafe fn get(obj: NonNull<c_void>, sel: &CStr) -> Result<NonNull<c_void>, HandleError> {
+        let send: unsafe extern "C" fn(*mut c_void, *mut c_void) -> *mut c_void =
+            unsafe { mem::transmute(objc_msgSend as unsafe extern "C" fn()) };
+        NonNull::new(unsafe { send(obj.as_ptr(), sel_registerName(sel.as_ptr())) })
+            .ok_or(HandleError::Unavailable)
+    }
+
+    pub fn appkit(props: &WindowProperties) -> Result<RawWindowHandle, HandleError> {
+        unsafe {
+            let ns_window = props.pointer(SDL_PROP_WINDOW_COCOA_WINDOW_POINTER)?;
+            let ns_view = get(ns_window, c"contentView")?;
+            Ok(AppKitWindowHandle::new(ns_view).into())
+        }
+    }
+
+    pub fn uikit(props: &WindowProperties) -> Result<RawWindowHandle, HandleError> {
+        unsafe {
+            let ui_window = props.pointer(SDL_PROP_WINDOW_UIKIT_WINDOW_POINTER)?;
+            let ui_view_controller = get(ui_window, c"rootViewController")?;
+            let mut handle = UiKitWindowHandle::new(get(ui_view_controller, c"view")?);
+            handle.ui_view_controller = Some(ui_view_controller);
+            Ok(handle.into())
+        }
+    }
+}
+
+fn raw_window_handle(window: &Window) -> Result<RawWindowHandle, HandleError> {
+    let driver = current_driver()?;
+    let props = WindowProperties::of(window)?;
+    unsafe {
+        match driver {
+            Driver::Android => Ok(AndroidNdkWindowHandle::new(
+                props.pointer(SDL_PROP_WINDOW_ANDROID_WINDOW_POINTER)?,
+            )
+            .into()),
+
+            #[cfg(target_vendor = "apple")]
+            Driver::Cocoa => apple::appkit(&props),
+
+            #[cfg(target_vendor = "apple")]
+            Driver::UiKit => apple::uikit(&props),
+
+            #[cfg(not(target_vendor = "apple"))]
+            Driver::Cocoa | Driver::UiKit => Err(HandleError::NotSupported),
+
+            // SDL doesn't expose the DRM plane or the GBM surface
+            Driver::KmsDrm => Err(HandleError::NotSupported),
+
+            Driver::Wayland => Ok(WaylandWindowHandle::new(
+                props.pointer(SDL_PROP_WINDOW_WAYLAND_SURFACE_POINTER)?,
+            )
+            .into()),
+
+            Driver::Windows => {
+                let hwnd = props.pointer(SDL_PROP_WINDOW_WIN32_HWND_POINTER)?;
+                let hinstance = props.pointer(SDL_PROP_WINDOW_WIN32_INSTANCE_POINTER).ok();
+                let mut handle = Win32WindowHandle::new(
+                    NonZeroIsize::new(hwnd.as_ptr() as isize).ok_or(HandleError::Unavailable)?,
+                );
+                handle.hinstance = hinstance.and_then(|h| NonZeroIsize::new(h.as_ptr() as isize));
+                Ok(handle.into())
+            }
+
+            Driver::X11 => Ok(XlibWindowHandle::new(
+                props.number(SDL_PROP_WINDOW_X11_WINDOW_NUMBER)? as c_ulong,
+            )
+            .into()),
+        }
+    }
+}
+
+fn raw_display_handle(window: &Window) -> Result<RawDisplayHandle, HandleError> {
+    let driver = current_driver()?;
+    let props = WindowProperties::of(window)?;
+    unsafe {
+        match driver {
+            Driver::Android => Ok(AndroidDisplayHandle::new().into()),
+            Driver::Cocoa => Ok(AppKitDisplayHandle::new().into()),
+            Driver::UiKit => Ok(UiKitDisplayHandle::new().into()),
+
+            Driver::KmsDrm => Ok(DrmDisplayHandle::new(
+                props.number(SDL_PROP_WINDOW_KMSDRM_DRM_FD_NUMBER)? as i32,
+            )
+            .into()),
+
+            Driver::Wayland => Ok(WaylandDisplayHandle::new(
+                props.pointer(SDL_PROP_WINDOW_WAYLAND_DISPLAY_POINTER)?,
+            )
+            .into()),
+
+            Driver::Windows => Ok(WindowsDisplayHandle::new().into()),
+
+            Driver::X11 => {
+                let display = props.pointer(SDL_PROP_WINDOW_X11_DISPLAY_POINTER)?;
+                // screen 0 is valid, so this doesn't use `number`
+                let screen = SDL_GetNumberProperty(props.0, SDL_PROP_WINDOW_X11_SCREEN_NUMBER, 0);
+                Ok(XlibDisplayHandle::new(Some(display), screen as c_int).into())
+            }
+        }
+    }
+}
+
+impl HasWindowHandle for Window {
+    fn window_handle(&self) -> Result<WindowHandle<'_>, HandleError> {
+        // the handle stays valid until the window is destroyed, which can't happen while
+        // it's borrowed
+        Ok(unsafe { WindowHandle::borrow_raw(raw_window_handle(self)?) })
+    }
+}
+
+impl HasDisplayHandle for Window {
+    fn display_handle(&self) -> Result<DisplayHandle<'_>, HandleError> {
+        Ok(unsafe { DisplayHandle::borrow_raw(raw_display_handle(self)?) })
+    }
+}
@@ -0,0 +1,166 @@
+//! Rectangle and point types that are layout compatible with the ones in
+//! [`sdl3_sys::rect`].
+
+use sdl3_sys::rect::{SDL_FPoint, SDL_FRect, SDL_Point, SDL_Rect};
+
+macro_rules! conv {
+    ($rust:ident <=> $sdl:ident { $($field:ident),* }) => {
+        impl From<$rust> for $sdl {
+            #[inline(always)]
+            fn from(value: $rust) -> Self {
+                Self { $($field: value.$field),* }
+            }
+        }
+
+        impl From<$sdl> for $rust {
+            #[inline(always)]
+            fn from(value: $sdl) -> Self {
+                Self { $($field: value.$field),* }
+            }
+        }
+
+        impl $rust {
+            /// Get a pointer to this as the equivalent SDL type.
+            #[inline(always)]
+            pub fn as_sdl(&self) -> *const $sdl {
+                self as *const Self as *const $sdl
+            }
+
+            /// Cast a slice of this type to a pointer to the equivalent SDL type.
+            #[inline(always)]
+            pub fn slice_as_sdl(slice: &[Self]) -> *const $sdl {
+                slice.as_ptr() as *const $sdl
+            }
+        }
+
+        const _: () = assert!(
+            ::core::mem::size_of::<$rust>() == ::core::mem::size_of::<$sdl>()
+                && ::core::mem::align_of::<$rust>() == ::core::mem::align_of::<$sdl>()
+        );
+    };
+}
+
+/// A point with integer coordinates. Equivalent to [`SDL_Point`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    #[inline(always)]
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+}
+
+conv!(Point <=> SDL_Point { x, y });
+
+/// A point with floating point coordinates. Equivalent to [`SDL_FPoint`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FPoint {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl FPoint {
+    #[inline(always)]
+    pub const fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+}
+
+conv!(FPoint <=> SDL_FPoint { x, y });
+
+/// A rectangle with integer coordinates. Equivalent to [`SDL_Rect`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub x: i32,
+    pub y: i32,
+    pub w: i32,
+    pub h: i32,
+}
+
+impl Rect {
+    #[inline(always)]
+    pub const fn new(x: i32, y: i32, w: i32, h: i32) -> Self {
+        Self { x, y, w, h }
+    }
+
+    /// Returns true if this rectangle has no area.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.w <= 0 || self.h <= 0
+    }
+
+    /// Returns true if `other` is completely inside this rectangle.
+    pub const fn contains_rect(&self, other: &Rect) -> bool {
+        other.x >= self.x
+            && other.y >= self.y
+            && other.x as i64 + other.w as i64 <= self.x as i64 + self.w as i64
+            && other.y as i64 + other.h as i64 <= self.y as i64 + self.h as i64
+    }
+
+    /// Returns true if the point is inside this rectangle.
+    pub const fn contains_point(&self, point: Point) -> bool {
+        point.x >= self.x
+            && point.y >= self.y
+            && (point.x as i64) < self.x as i64 + self.w as i64
+            && (point.y as i64) < self.y as i64 + self.h as i64
+    }
+}
+
+conv!(Rect <=> SDL_Rect { x, y, w, h });
+
+impl From<Rect> for FRect {
+    #[inline]
+    fn from(value: Rect) -> Self {
+        Self::new(
+            value.x as f32,
+            value.y as f32,
+            value.w as f32,
+            value.h as f32,
+        )
+    }
+}
+
+/// A rectangle with floating point coordinates. Equivalent to [`SDL_FRect`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FRect {
+    pub x: f32,
+    pub y: f32,
+    pub w: f32,
+    pub h: f32,
+}
+
+impl FRect {
+    #[inline(always)]
+    pub const fn new(x: f32, y: f32, w: f32, h: f32) -> Self {
+        Self { x, y, w, h }
+    }
+
+    /// Returns true if this rectangle has no area.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        !(self.w > 0.0 && self.h > 0.0)
+    }
+}
+
+conv!(FRect <=> SDL_FRect { x, y, w, h });
+
+/// Corner sizes and scale for 9-grid scaling, where the corners keep their size, the edges
+/// are stretched along one axis and the center is stretched along both.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct NineGrid {
+    pub left_width: f32,
+    pub right_width: f32,
+    pub top_height: f32,
+    pub bottom_height: f32,
+
+    /// Scale applied to the corners when drawing, or `0.0` for no scaling.
+    pub scale: f32,
+}
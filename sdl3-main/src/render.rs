@@ -0,0 +1,880 @@
+//! Safe wrappers for the 2D rendering API in [`sdl3_sys::render`].
+//!
+//! [`Renderer`] owns an [`SDL_Renderer`], and [`Texture`] owns an [`SDL_Texture`]. Textures
+//! borrow the renderer that created them, so they can't outlive it.
+
+use crate::{
+    error::{check, len_to_c_int},
+    pixels::Color,
+    rect::{FPoint, FRect, NineGrid, Rect},
+    surface::Surface,
+    Error, Result,
+};
+use core::{
+    ffi::{c_void, CStr},
+    marker::PhantomData,
+    ops::Deref,
+    ptr::{self, NonNull},
+    slice,
+};
+use sdl3_sys::{
+    blendmode::SDL_BlendMode,
+    pixels::{SDL_PixelFormat, SDL_BYTESPERPIXEL, SDL_ISPIXELFORMAT_FOURCC},
+    render::{
+        SDL_CreateRenderer, SDL_CreateSoftwareRenderer, SDL_CreateTexture,
+        SDL_CreateTextureFromSurface, SDL_DestroyRenderer, SDL_DestroyTexture,
+        SDL_GetRenderDrawColor, SDL_GetRenderOutputSize, SDL_GetRenderTarget,
+        SDL_GetRendererFromTexture, SDL_GetRendererName, SDL_LockTexture, SDL_LockTextureToSurface,
+        SDL_RenderClear, SDL_RenderFillRect, SDL_RenderFillRects, SDL_RenderGeometry,
+        SDL_RenderLine, SDL_RenderLines, SDL_RenderPoint, SDL_RenderPoints, SDL_RenderPresent,
+        SDL_RenderReadPixels, SDL_RenderRect, SDL_RenderRects, SDL_RenderTexture,
+        SDL_RenderTexture9Grid, SDL_RenderTextureRotated, SDL_Renderer, SDL_SetRenderClipRect,
+        SDL_SetRenderDrawBlendMode, SDL_SetRenderDrawColor, SDL_SetRenderScale,
+        SDL_SetRenderTarget, SDL_SetRenderViewport, SDL_SetTextureAlphaMod,
+        SDL_SetTextureBlendMode, SDL_SetTextureColorMod, SDL_Texture, SDL_TextureAccess,
+        SDL_UnlockTexture, SDL_UpdateNVTexture, SDL_UpdateTexture, SDL_UpdateYUVTexture,
+        SDL_Vertex,
+    },
+    surface::{SDL_FlipMode, SDL_Surface},
+    video::SDL_Window,
+};
+
+#[inline(always)]
+fn opt_ptr<T: ?Sized, U>(value: Option<&T>, f: impl FnOnce(&T) -> *const U) -> *const U {
+    value.map(f).unwrap_or(ptr::null())
+}
+
+fn check_indices(indices: &[i32], num_vertices: i32) -> Result<()> {
+    if indices.iter().any(|&i| i < 0 || i >= num_vertices) {
+        Err(Error::new("vertex index out of range"))
+    } else {
+        Ok(())
+    }
+}
+
+/// An owned 2D renderer. The renderer is destroyed when this is dropped.
+///
+/// Rendering must happen on the thread that created the renderer, so this is neither
+/// `Send` nor `Sync`.
+pub struct Renderer {
+    ptr: NonNull<SDL_Renderer>,
+    _not_send_sync: PhantomData<*const ()>,
+}
+
+impl Renderer {
+    /// Create a renderer for a window. See [`SDL_CreateRenderer`].
+    ///
+    /// If `name` is `None`, SDL picks the driver.
+    ///
+    /// # Safety
+    /// `window` must be a valid window that outlives the renderer
+    pub unsafe fn new(window: *mut SDL_Window, name: Option<&CStr>) -> Result<Self> {
+        let name = opt_ptr(name, |n| n.as_ptr());
+        unsafe { Self::from_created(SDL_CreateRenderer(window, name)) }
+    }
+
+    /// Create a software renderer that draws to a surface. See [`SDL_CreateSoftwareRenderer`].
+    ///
+    /// This doesn't need a window or a video driver.
+    ///
+    /// # Safety
+    /// `surface` must be a valid surface that outlives the renderer
+    pub unsafe fn new_software(surface: *mut SDL_Surface) -> Result<Self> {
+        unsafe { Self::from_created(SDL_CreateSoftwareRenderer(surface)) }
+    }
+
+    unsafe fn from_created(ptr: *mut SDL_Renderer) -> Result<Self> {
+        match NonNull::new(ptr) {
+            Some(ptr) => Ok(unsafe { Self::from_raw(ptr) }),
+            None => Err(Error::from_sdl()),
+        }
+    }
+
+    /// Take ownership of a raw renderer.
+    ///
+    /// # Safety
+    /// `ptr` must be a valid renderer that isn't owned by anything else
+    #[inline(always)]
+    pub unsafe fn from_raw(ptr: NonNull<SDL_Renderer>) -> Self {
+        Self {
+            ptr,
+            _not_send_sync: PhantomData,
+        }
+    }
+
+    /// Give up ownership of the raw renderer. It won't be destroyed automatically.
+    #[inline]
+    pub fn into_raw(self) -> *mut SDL_Renderer {
+        let ptr = self.ptr.as_ptr();
+        core::mem::forget(self);
+        ptr
+    }
+
+    /// Get the raw renderer pointer.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *mut SDL_Renderer {
+        self.ptr.as_ptr()
+    }
+
+    /// Get the name of the rendering driver.
+    pub fn name(&self) -> Option<&CStr> {
+        let name = unsafe { SDL_GetRendererName(self.as_ptr()) };
+        (!name.is_null()).then(|| unsafe { CStr::from_ptr(name) })
+    }
+
+    /// Get the output size in pixels.
+    pub fn output_size(&self) -> Result<(i32, i32)> {
+        let (mut w, mut h) = (0, 0);
+        check(unsafe { SDL_GetRenderOutputSize(self.as_ptr(), &mut w, &mut h) })?;
+        Ok((w, h))
+    }
+
+    /// Set the color used for drawing and clearing.
+    pub fn set_draw_color(&self, color: Color) -> Result<()> {
+        check(unsafe { SDL_SetRenderDrawColor(self.as_ptr(), color.r, color.g, color.b, color.a) })
+    }
+
+    /// Get the color used for drawing and clearing.
+    pub fn draw_color(&self) -> Result<Color> {
+        let mut c = Color::default();
+        check(unsafe {
+            SDL_GetRenderDrawColor(self.as_ptr(), &mut c.r, &mut c.g, &mut c.b, &mut c.a)
+        })?;
+        Ok(c)
+    }
+
+    /// Set the blend mode used for drawing.
+    pub fn set_draw_blend_mode(&self, mode: SDL_BlendMode) -> Result<()> {
+        check(unsafe { SDL_SetRenderDrawBlendMode(self.as_ptr(), mode) })
+    }
+
+    /// Set the drawing area, or reset it to the whole target with `None`.
+    pub fn set_viewport(&self, rect: Option<Rect>) -> Result<()> {
+        check(unsafe { SDL_SetRenderViewport(self.as_ptr(), opt_ptr(rect.as_ref(), Rect::as_sdl)) })
+    }
+
+    /// Set the clip rectangle, or disable clipping with `None`.
+    pub fn set_clip_rect(&self, rect: Option<Rect>) -> Result<()> {
+        check(unsafe { SDL_SetRenderClipRect(self.as_ptr(), opt_ptr(rect.as_ref(), Rect::as_sdl)) })
+    }
+
+    /// Set the drawing scale.
+    pub fn set_scale(&self, x: f32, y: f32) -> Result<()> {
+        check(unsafe { SDL_SetRenderScale(self.as_ptr(), x, y) })
+    }
+
+    /// Clear the current target with the draw color.
+    pub fn clear(&self) -> Result<()> {
+        check(unsafe { SDL_RenderClear(self.as_ptr()) })
+    }
+
+    /// Clear the current target with `color`. This also sets the draw color.
+    pub fn clear_with(&self, color: Color) -> Result<()> {
+        self.set_draw_color(color)?;
+        self.clear()
+    }
+
+    /// Present the backbuffer.
+    pub fn present(&self) -> Result<()> {
+        check(unsafe { SDL_RenderPresent(self.as_ptr()) })
+    }
+
+    /// Draw a point.
+    pub fn draw_point(&self, point: FPoint) -> Result<()> {
+        check(unsafe { SDL_RenderPoint(self.as_ptr(), point.x, point.y) })
+    }
+
+    /// Draw multiple points.
+    pub fn draw_points(&self, points: &[FPoint]) -> Result<()> {
+        check(unsafe {
+            SDL_RenderPoints(
+                self.as_ptr(),
+                FPoint::slice_as_sdl(points),
+                len_to_c_int(points.len())?,
+            )
+        })
+    }
+
+    /// Draw a line.
+    pub fn draw_line(&self, from: FPoint, to: FPoint) -> Result<()> {
+        check(unsafe { SDL_RenderLine(self.as_ptr(), from.x, from.y, to.x, to.y) })
+    }
+
+    /// Draw a sequence of connected lines.
+    pub fn draw_lines(&self, points: &[FPoint]) -> Result<()> {
+        check(unsafe {
+            SDL_RenderLines(
+                self.as_ptr(),
+                FPoint::slice_as_sdl(points),
+                len_to_c_int(points.len())?,
+            )
+        })
+    }
+
+    /// Draw the outline of a rectangle.
+    pub fn draw_rect(&self, rect: FRect) -> Result<()> {
+        check(unsafe { SDL_RenderRect(self.as_ptr(), rect.as_sdl()) })
+    }
+
+    /// Draw the outlines of multiple rectangles.
+    pub fn draw_rects(&self, rects: &[FRect]) -> Result<()> {
+        check(unsafe {
+            SDL_RenderRects(
+                self.as_ptr(),
+                FRect::slice_as_sdl(rects),
+                len_to_c_int(rects.len())?,
+            )
+        })
+    }
+
+    /// Fill a rectangle.
+    pub fn fill_rect(&self, rect: FRect) -> Result<()> {
+        check(unsafe { SDL_RenderFillRect(self.as_ptr(), rect.as_sdl()) })
+    }
+
+    /// Fill multiple rectangles.
+    pub fn fill_rects(&self, rects: &[FRect]) -> Result<()> {
+        check(unsafe {
+            SDL_RenderFillRects(
+                self.as_ptr(),
+                FRect::slice_as_sdl(rects),
+                len_to_c_int(rects.len())?,
+            )
+        })
+    }
+
+    /// Draw debug text using SDL's built in 8x8 font.
+    ///
+    /// This needs SDL 3.1.6, so it isn't available with the `sdl-3-1-3` feature.
+    #[cfg(not(feature = "sdl-3-1-3"))]
+    pub fn debug_text(&self, pos: FPoint, text: &CStr) -> Result<()> {
+        use sdl3_sys::render::SDL_RenderDebugText;
+        check(unsafe { SDL_RenderDebugText(self.as_ptr(), pos.x, pos.y, text.as_ptr()) })
+    }
+
+    /// Copy (part of) a texture to (part of) the current target.
+    ///
+    /// `None` means the whole texture or target.
+    pub fn copy(&self, texture: &Texture, src: Option<FRect>, dst: Option<FRect>) -> Result<()> {
+        self.check_texture(texture)?;
+        check(unsafe {
+            SDL_RenderTexture(
+                self.as_ptr(),
+                texture.as_ptr(),
+                opt_ptr(src.as_ref(), FRect::as_sdl),
+                opt_ptr(dst.as_ref(), FRect::as_sdl),
+            )
+        })
+    }
+
+    /// Copy a texture with rotation and flipping.
+    ///
+    /// `angle` is in degrees clockwise around `center`, which is relative to `dst`.
+    /// If `center` is `None`, the texture is rotated around the center of `dst`.
+    pub fn copy_ex(
+        &self,
+        texture: &Texture,
+        src: Option<FRect>,
+        dst: Option<FRect>,
+        angle: f64,
+        center: Option<FPoint>,
+        flip: SDL_FlipMode,
+    ) -> Result<()> {
+        self.check_texture(texture)?;
+        check(unsafe {
+            SDL_RenderTextureRotated(
+                self.as_ptr(),
+                texture.as_ptr(),
+                opt_ptr(src.as_ref(), FRect::as_sdl),
+                opt_ptr(dst.as_ref(), FRect::as_sdl),
+                angle,
+                opt_ptr(center.as_ref(), FPoint::as_sdl),
+                flip,
+            )
+        })
+    }
+
+    /// Copy a texture using 9-grid scaling. See [`SDL_RenderTexture9Grid`].
+    pub fn copy_9grid(
+        &self,
+        texture: &Texture,
+        src: Option<FRect>,
+        grid: NineGrid,
+        dst: Option<FRect>,
+    ) -> Result<()> {
+        self.check_texture(texture)?;
+        check(unsafe {
+            SDL_RenderTexture9Grid(
+                self.as_ptr(),
+                texture.as_ptr(),
+                opt_ptr(src.as_ref(), FRect::as_sdl),
+                grid.left_width,
+                grid.right_width,
+                grid.top_height,
+                grid.bottom_height,
+                grid.scale,
+                opt_ptr(dst.as_ref(), FRect::as_sdl),
+            )
+        })
+    }
+
+    /// Render triangles, optionally textured. See [`SDL_RenderGeometry`].
+    ///
+    /// If `indices` is `None`, each consecutive three vertices make up a triangle.
+    /// Otherwise each index must be in range for `vertices`.
+    pub fn geometry(
+        &self,
+        texture: Option<&Texture>,
+        vertices: &[SDL_Vertex],
+        indices: Option<&[i32]>,
+    ) -> Result<()> {
+        if let Some(texture) = texture {
+            self.check_texture(texture)?;
+        }
+        let num_vertices = len_to_c_int(vertices.len())?;
+        let (indices_ptr, num_indices) = match indices {
+            Some(indices) => {
+                check_indices(indices, num_vertices)?;
+                (indices.as_ptr(), len_to_c_int(indices.len())?)
+            }
+            None => (ptr::null(), 0),
+        };
+        check(unsafe {
+            SDL_RenderGeometry(
+                self.as_ptr(),
+                texture.map(|t| t.as_ptr()).unwrap_or(ptr::null_mut()),
+                vertices.as_ptr(),
+                num_vertices,
+                indices_ptr,
+                num_indices,
+            )
+        })
+    }
+
+    /// Create a texture. See [`SDL_CreateTexture`].
+    pub fn create_texture(
+        &self,
+        format: SDL_PixelFormat,
+        access: SDL_TextureAccess,
+        w: i32,
+        h: i32,
+    ) -> Result<Texture<'_>> {
+        unsafe { Texture::from_created(SDL_CreateTexture(self.as_ptr(), format, access, w, h)) }
+    }
+
+    /// Create a static texture from the contents of a surface.
+    ///
+    /// # Safety
+    /// `surface` must be a valid surface
+    pub unsafe fn create_texture_from_surface(
+        &self,
+        surface: *mut SDL_Surface,
+    ) -> Result<Texture<'_>> {
+        unsafe { Texture::from_created(SDL_CreateTextureFromSurface(self.as_ptr(), surface)) }
+    }
+
+    /// Draw to `texture` instead of the default target until the returned guard is dropped.
+    /// The previous target is restored when the guard is dropped.
+    ///
+    /// `texture` must have been created with [`SDL_TextureAccess::TARGET`].
+    pub fn with_target<'a>(&'a self, texture: &'a Texture) -> Result<RenderTarget<'a>> {
+        self.check_texture(texture)?;
+        let previous = unsafe { SDL_GetRenderTarget(self.as_ptr()) };
+        check(unsafe { SDL_SetRenderTarget(self.as_ptr(), texture.as_ptr()) })?;
+        Ok(RenderTarget {
+            renderer: self,
+            previous,
+            _texture: PhantomData,
+        })
+    }
+
+    /// Read pixels from the current target. `None` reads the whole viewport.
+    ///
+    /// This is slow and shouldn't be used in a frame loop.
+    pub fn read_pixels(&self, rect: Option<Rect>) -> Result<Surface> {
+        unsafe {
+            Surface::from_created(SDL_RenderReadPixels(
+                self.as_ptr(),
+                opt_ptr(rect.as_ref(), Rect::as_sdl),
+            ))
+        }
+    }
+
+    fn check_texture(&self, texture: &Texture) -> Result<()> {
+        if texture.renderer == self.ptr {
+            Ok(())
+        } else {
+            Err(Error::new("texture belongs to a different renderer"))
+        }
+    }
+}
+
+impl Drop for Renderer {
+    fn drop(&mut self) {
+        unsafe { SDL_DestroyRenderer(self.as_ptr()) };
+    }
+}
+
+/// Guard returned from [`Renderer::with_target`]. Restores the previous render target when dropped.
+///
+/// This derefs to the [`Renderer`], so you can draw through it.
+pub struct RenderTarget<'a> {
+    renderer: &'a Renderer,
+    previous: *mut SDL_Texture,
+    _texture: PhantomData<&'a Texture<'a>>,
+}
+
+impl Deref for RenderTarget<'_> {
+    type Target = Renderer;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.renderer
+    }
+}
+
+impl Drop for RenderTarget<'_> {
+    fn drop(&mut self) {
+        unsafe { SDL_SetRenderTarget(self.renderer.as_ptr(), self.previous) };
+    }
+}
+
+/// An owned texture. The texture is destroyed when this is dropped.
+pub struct Texture<'r> {
+    ptr: NonNull<SDL_Texture>,
+    renderer: NonNull<SDL_Renderer>,
+    _renderer: PhantomData<&'r Renderer>,
+}
+
+impl Texture<'_> {
+    unsafe fn from_created(ptr: *mut SDL_Texture) -> Result<Self> {
+        let ptr = NonNull::new(ptr).ok_or_else(Error::from_sdl)?;
+        let renderer = unsafe { SDL_GetRendererFromTexture(ptr.as_ptr()) };
+        Ok(Self {
+            ptr,
+            renderer: NonNull::new(renderer).ok_or_else(Error::from_sdl)?,
+            _renderer: PhantomData,
+        })
+    }
+
+    /// Get the raw texture pointer.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *mut SDL_Texture {
+        self.ptr.as_ptr()
+    }
+
+    /// The pixel format of the texture.
+    #[inline]
+    pub fn format(&self) -> SDL_PixelFormat {
+        unsafe { (*self.as_ptr()).format }
+    }
+
+    /// The size of the texture in pixels.
+    #[inline]
+    pub fn size(&self) -> (i32, i32) {
+        unsafe { ((*self.as_ptr()).w, (*self.as_ptr()).h) }
+    }
+
+    /// Set the color multiplier used when this texture is rendered. The alpha channel is
+    /// set with [`SDL_SetTextureAlphaMod`].
+    pub fn set_color_mod(&self, color: Color) -> Result<()> {
+        check(unsafe { SDL_SetTextureColorMod(self.as_ptr(), color.r, color.g, color.b) })?;
+        check(unsafe { SDL_SetTextureAlphaMod(self.as_ptr(), color.a) })
+    }
+
+    /// Set the blend mode used when this texture is rendered.
+    pub fn set_blend_mode(&self, mode: SDL_BlendMode) -> Result<()> {
+        check(unsafe { SDL_SetTextureBlendMode(self.as_ptr(), mode) })
+    }
+
+    /// Update (part of) the texture with new pixel data. `None` updates the whole texture.
+    ///
+    /// `pixels` must be in the texture's format, with `pitch` bytes between rows. Textures
+    /// with a FOURCC format can't be updated with this; use [`update_yuv`](Self::update_yuv)
+    /// or [`update_nv`](Self::update_nv) for planar YUV textures.
+    pub fn update(&mut self, rect: Option<Rect>, pixels: &[u8], pitch: usize) -> Result<()> {
+        let format = self.format();
+        if SDL_ISPIXELFORMAT_FOURCC(format) {
+            return Err(Error::new(
+                "textures with a FOURCC format must be updated per plane",
+            ));
+        }
+        let area = self.checked_area(rect)?;
+        check_plane(
+            pixels,
+            area.w as usize * bytes_per_pixel(format),
+            area.h,
+            pitch,
+        )?;
+        check(unsafe {
+            SDL_UpdateTexture(
+                self.as_ptr(),
+                opt_ptr(rect.as_ref(), Rect::as_sdl),
+                pixels.as_ptr() as *const c_void,
+                len_to_c_int(pitch)?,
+            )
+        })
+    }
+
+    /// Update (part of) an `IYUV` or `YV12` texture with separate Y, U and V planes.
+    /// `None` updates the whole texture. See [`SDL_UpdateYUVTexture`].
+    ///
+    /// Each plane is given as its pixels and pitch. The U and V planes have half the width
+    /// and height of the area, rounded up.
+    pub fn update_yuv(
+        &mut self,
+        rect: Option<Rect>,
+        y: (&[u8], usize),
+        u: (&[u8], usize),
+        v: (&[u8], usize),
+    ) -> Result<()> {
+        let format = self.format();
+        if format != SDL_PixelFormat::IYUV && format != SDL_PixelFormat::YV12 {
+            return Err(Error::new("texture isn't an IYUV or YV12 texture"));
+        }
+        let area = self.checked_area(rect)?;
+        let (cw, ch) = chroma_size(area);
+        check_plane(y.0, area.w as usize, area.h, y.1)?;
+        check_plane(u.0, cw, ch, u.1)?;
+        check_plane(v.0, cw, ch, v.1)?;
+        check(unsafe {
+            SDL_UpdateYUVTexture(
+                self.as_ptr(),
+                opt_ptr(rect.as_ref(), Rect::as_sdl),
+                y.0.as_ptr(),
+                len_to_c_int(y.1)?,
+                u.0.as_ptr(),
+                len_to_c_int(u.1)?,
+                v.0.as_ptr(),
+                len_to_c_int(v.1)?,
+            )
+        })
+    }
+
+    /// Update (part of) an `NV12` or `NV21` texture with a Y plane and an interleaved UV
+    /// plane. `None` updates the whole texture. See [`SDL_UpdateNVTexture`].
+    ///
+    /// Each plane is given as its pixels and pitch. The UV plane has half the width and
+    /// height of the area, rounded up, with two bytes per pixel.
+    pub fn update_nv(
+        &mut self,
+        rect: Option<Rect>,
+        y: (&[u8], usize),
+        uv: (&[u8], usize),
+    ) -> Result<()> {
+        let format = self.format();
+        if format != SDL_PixelFormat::NV12 && format != SDL_PixelFormat::NV21 {
+            return Err(Error::new("texture isn't an NV12 or NV21 texture"));
+        }
+        let area = self.checked_area(rect)?;
+        let (cw, ch) = chroma_size(area);
+        check_plane(y.0, area.w as usize, area.h, y.1)?;
+        check_plane(uv.0, cw * 2, ch, uv.1)?;
+        check(unsafe {
+            SDL_UpdateNVTexture(
+                self.as_ptr(),
+                opt_ptr(rect.as_ref(), Rect::as_sdl),
+                y.0.as_ptr(),
+                len_to_c_int(y.1)?,
+                uv.0.as_ptr(),
+                len_to_c_int(uv.1)?,
+            )
+        })
+    }
+
+    /// Lock (part of) a streaming texture for write-only pixel access. `None` locks the
+    /// whole texture. The texture is unlocked when the returned guard is dropped.
+    ///
+    /// The texture must have been created with [`SDL_TextureAccess::STREAMING`]. The
+    /// initial contents of the locked pixels are undefined.
+    pub fn lock(&mut self, rect: Option<Rect>) -> Result<TextureLock<'_>> {
+        let area = self.checked_area(rect)?;
+        let mut pixels = ptr::null_mut();
+        let mut pitch = 0;
+        check(unsafe {
+            SDL_LockTexture(
+                self.as_ptr(),
+                opt_ptr(rect.as_ref(), Rect::as_sdl),
+                &mut pixels,
+                &mut pitch,
+            )
+        })?;
+        let pitch = pitch as usize;
+        let row_len = area.w as usize * bytes_per_pixel(self.format());
+        let Some(len) = plane_len(row_len, area.h, pitch) else {
+            unsafe { SDL_UnlockTexture(self.as_ptr()) };
+            return Err(Error::new("locked texture area is too large"));
+        };
+        Ok(TextureLock {
+            texture: self.as_ptr(),
+            pixels: unsafe { slice::from_raw_parts_mut(pixels as *mut u8, len) },
+            pitch,
+            row_len,
+        })
+    }
+
+    /// Lock (part of) a streaming texture as a surface. `None` locks the whole texture.
+    /// The texture is unlocked when the returned guard is dropped.
+    pub fn lock_to_surface(&mut self, rect: Option<Rect>) -> Result<TextureSurfaceLock<'_>> {
+        self.checked_area(rect)?;
+        let mut surface = ptr::null_mut();
+        check(unsafe {
+            SDL_LockTextureToSurface(
+                self.as_ptr(),
+                opt_ptr(rect.as_ref(), Rect::as_sdl),
+                &mut surface,
+            )
+        })?;
+        Ok(TextureSurfaceLock {
+            texture: self.as_ptr(),
+            surface,
+            _texture: PhantomData,
+        })
+    }
+
+    fn checked_area(&self, rect: Option<Rect>) -> Result<Rect> {
+        let (w, h) = self.size();
+        let full = Rect::new(0, 0, w, h);
+        match rect {
+            None => Ok(full),
+            Some(rect) if !rect.is_empty() && full.contains_rect(&rect) => Ok(rect),
+            Some(_) => Err(Error::new("rect is outside of the texture")),
+        }
+    }
+}
+
+impl Drop for Texture<'_> {
+    fn drop(&mut self) {
+        unsafe { SDL_DestroyTexture(self.as_ptr()) };
+    }
+}
+
+/// Guard returned from [`Texture::lock`]. Unlocks the texture when dropped.
+pub struct TextureLock<'a> {
+    texture: *mut SDL_Texture,
+    pixels: &'a mut [u8],
+    pitch: usize,
+    row_len: usize,
+}
+
+impl TextureLock<'_> {
+    /// The number of bytes between the start of each row.
+    #[inline(always)]
+    pub fn pitch(&self) -> usize {
+        self.pitch
+    }
+
+    /// All locked pixels, including any padding between rows.
+    #[inline(always)]
+    pub fn pixels(&mut self) -> &mut [u8] {
+        self.pixels
+    }
+
+    /// The pixels in row `y` of the locked area, without padding.
+    pub fn row_mut(&mut self, y: usize) -> Option<&mut [u8]> {
+        let start = y.checked_mul(self.pitch)?;
+        self.pixels.get_mut(start..start.checked_add(self.row_len)?)
+    }
+
+    /// Iterate over the rows of the locked area, without padding.
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [u8]> {
+        let row_len = self.row_len;
+        self.pixels
+            .chunks_mut(self.pitch.max(1))
+            .map(move |row| &mut row[..row_len])
+    }
+}
+
+impl Drop for TextureLock<'_> {
+    fn drop(&mut self) {
+        unsafe { SDL_UnlockTexture(self.texture) };
+    }
+}
+
+/// Guard returned from [`Texture::lock_to_surface`]. Unlocks the texture when dropped,
+/// which also frees the surface.
+pub struct TextureSurfaceLock<'a> {
+    texture: *mut SDL_Texture,
+    surface: *mut SDL_Surface,
+    _texture: PhantomData<&'a mut SDL_Texture>,
+}
+
+impl TextureSurfaceLock<'_> {
+    /// Get the raw surface pointer. It's only valid while this guard exists.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *mut SDL_Surface {
+        self.surface
+    }
+}
+
+impl Drop for TextureSurfaceLock<'_> {
+    fn drop(&mut self) {
+        unsafe { SDL_UnlockTexture(self.texture) };
+    }
+}
+
+fn bytes_per_pixel(format: SDL_PixelFormat) -> usize {
+    // planar formats only expose the first plane, which has one byte per pixel
+    SDL_BYTESPERPIXEL(format).max(1) as usize
+}
+
+// number of bytes in a plane of `rows` rows, not including padding after the last row
+fn plane_len(row_len: usize, rows: i32, pitch: usize) -> Option<usize> {
+    if row_len == 0 || rows <= 0 {
+        return Some(0);
+    }
+    pitch.checked_mul(rows as usize - 1)?.checked_add(row_len)
+}
+
+fn check_plane(pixels: &[u8], row_len: usize, rows: i32, pitch: usize) -> Result<()> {
+    match plane_len(row_len, rows, pitch) {
+        Some(needed) if pixels.len() >= needed => Ok(()),
+        _ => Err(Error::new("pixel buffer is too small")),
+    }
+}
+
+// size of the chroma planes of a 4:2:0 area
+fn chroma_size(area: Rect) -> (usize, i32) {
+    ((area.w as usize).div_ceil(2), area.h / 2 + area.h % 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn indices() {
+        assert!(check_indices(&[], 0).is_ok());
+        assert!(check_indices(&[0, 1, 2], 3).is_ok());
+        assert!(check_indices(&[2, 2, 2, 0, 1, 0], 3).is_ok());
+        assert!(check_indices(&[0, 1, 3], 3).is_err());
+        assert!(check_indices(&[0], 0).is_err());
+        assert!(check_indices(&[0, -1, 1], 3).is_err());
+        assert!(check_indices(&[i32::MIN], i32::MAX).is_err());
+        assert!(check_indices(&[i32::MAX - 1], i32::MAX).is_ok());
+    }
+
+    #[test]
+    fn planes() {
+        assert_eq!(plane_len(16, 4, 16), Some(64));
+        assert_eq!(plane_len(12, 4, 16), Some(60));
+        assert_eq!(plane_len(0, 4, 16), Some(0));
+        assert_eq!(plane_len(16, 0, 16), Some(0));
+        assert_eq!(plane_len(16, 3, usize::MAX), None);
+        assert_eq!(plane_len(usize::MAX, 2, 1), None);
+        assert!(check_plane(&[0; 60], 12, 4, 16).is_ok());
+        assert!(check_plane(&[0; 59], 12, 4, 16).is_err());
+        assert!(check_plane(&[], 16, 3, usize::MAX).is_err());
+        assert_eq!(chroma_size(Rect::new(0, 0, 4, 4)), (2, 2));
+        assert_eq!(chroma_size(Rect::new(0, 0, 5, 3)), (3, 2));
+        assert_eq!(chroma_size(Rect::new(0, 0, 1, 1)), (1, 1));
+    }
+
+    fn software(surface: &Surface) -> Renderer {
+        unsafe { Renderer::new_software(surface.as_ptr()) }.unwrap()
+    }
+
+    fn pixel(renderer: &Renderer, x: i32, y: i32) -> Color {
+        renderer
+            .read_pixels(None)
+            .unwrap()
+            .read_pixel(x, y)
+            .unwrap()
+    }
+
+    fn vertex(x: f32, y: f32, color: Color) -> SDL_Vertex {
+        SDL_Vertex {
+            position: FPoint::new(x, y).into(),
+            color: crate::pixels::FColor::from(color).into(),
+            tex_coord: FPoint::new(0.0, 0.0).into(),
+        }
+    }
+
+    #[test]
+    fn software_draw() {
+        let surface = Surface::new(8, 8, SDL_PixelFormat::RGBA32).unwrap();
+        let renderer = software(&surface);
+
+        renderer.clear_with(Color::RED).unwrap();
+        assert_eq!(pixel(&renderer, 0, 0), Color::RED);
+        assert_eq!(pixel(&renderer, 7, 7), Color::RED);
+
+        renderer.set_draw_color(Color::BLUE).unwrap();
+        renderer.fill_rect(FRect::new(2.0, 2.0, 3.0, 3.0)).unwrap();
+        assert_eq!(pixel(&renderer, 2, 2), Color::BLUE);
+        assert_eq!(pixel(&renderer, 4, 4), Color::BLUE);
+        assert_eq!(pixel(&renderer, 5, 5), Color::RED);
+        assert_eq!(pixel(&renderer, 1, 2), Color::RED);
+
+        renderer.clear_with(Color::BLACK).unwrap();
+        let quad = [
+            vertex(0.0, 0.0, Color::GREEN),
+            vertex(4.0, 0.0, Color::GREEN),
+            vertex(0.0, 4.0, Color::GREEN),
+            vertex(4.0, 4.0, Color::GREEN),
+        ];
+        renderer
+            .geometry(None, &quad, Some(&[0, 1, 2, 2, 1, 3]))
+            .unwrap();
+        assert_eq!(pixel(&renderer, 1, 1), Color::GREEN);
+        assert_eq!(pixel(&renderer, 3, 3), Color::GREEN);
+        assert_eq!(pixel(&renderer, 6, 6), Color::BLACK);
+        assert!(renderer.geometry(None, &quad, Some(&[0, 1, 4])).is_err());
+    }
+
+    #[test]
+    fn software_texture() {
+        let surface = Surface::new(8, 8, SDL_PixelFormat::RGBA32).unwrap();
+        let renderer = software(&surface);
+        renderer.clear_with(Color::BLACK).unwrap();
+
+        let mut texture = renderer
+            .create_texture(SDL_PixelFormat::RGBA32, SDL_TextureAccess::STATIC, 2, 2)
+            .unwrap();
+        let colors = [Color::RED, Color::GREEN, Color::BLUE, Color::WHITE];
+        let pixels: alloc::vec::Vec<u8> =
+            colors.iter().flat_map(|c| [c.r, c.g, c.b, c.a]).collect();
+        texture.update(None, &pixels, 8).unwrap();
+        renderer
+            .copy(&texture, None, Some(FRect::new(4.0, 4.0, 2.0, 2.0)))
+            .unwrap();
+        assert_eq!(pixel(&renderer, 4, 4), Color::RED);
+        assert_eq!(pixel(&renderer, 5, 4), Color::GREEN);
+        assert_eq!(pixel(&renderer, 4, 5), Color::BLUE);
+        assert_eq!(pixel(&renderer, 5, 5), Color::WHITE);
+        assert_eq!(pixel(&renderer, 3, 3), Color::BLACK);
+
+        // One byte short of the last row
+        assert!(texture.update(None, &pixels[..15], 8).is_err());
+        // Pitch makes the second row start past the end
+        assert!(texture.update(None, &pixels, 12).is_err());
+        assert!(texture
+            .update(Some(Rect::new(1, 1, 2, 2)), &pixels, 8)
+            .is_err());
+        assert!(texture
+            .update(Some(Rect::new(1, 1, 1, 1)), &pixels[..4], 4)
+            .is_ok());
+    }
+
+    #[test]
+    fn software_yuv_texture() {
+        let surface = Surface::new(8, 8, SDL_PixelFormat::RGBA32).unwrap();
+        let renderer = software(&surface);
+        let mut texture = renderer
+            .create_texture(SDL_PixelFormat::IYUV, SDL_TextureAccess::STATIC, 5, 3)
+            .unwrap();
+        let y = [0; 15];
+        let uv = [128; 6];
+        assert!(texture.update(None, &y, 5).is_err());
+        assert!(texture.update_nv(None, (&y, 5), (&uv, 6)).is_err());
+        assert!(texture
+            .update_yuv(None, (&y, 5), (&uv, 3), (&uv[..5], 3))
+            .is_err());
+        assert!(texture
+            .update_yuv(None, (&y[..14], 5), (&uv, 3), (&uv, 3))
+            .is_err());
+        texture
+            .update_yuv(None, (&y, 5), (&uv, 3), (&uv, 3))
+            .unwrap();
+    }
+}
@@ -0,0 +1,789 @@
+//! Recording events to a file and replaying them later, for reproducing bugs.
+//!
+//! [`EventRecorder`] writes every event it's given, with its time relative to the start of
+//! the recording, and [`EventPlayer`] pushes them back onto the event queue with
+//! `SDL_PushEvent` at the same relative times. [`EventLog`] wraps both so an app can pick one
+//! from environment variables and call it from its `app_event` and `app_iterate`
+//! functions. Replaying works without a display using the dummy video driver
+//! (`SDL_VIDEO_DRIVER=dummy`).
+//!
+//! ```no_run
+//! use sdl3_main::replay::EventLog;
+//! use sdl3_sys::events::SDL_Event;
+//!
+//! // record with SDL3_MAIN_RECORD_EVENTS=events.bin,
+//! // or replay with SDL3_MAIN_REPLAY_EVENTS=events.bin
+//! let mut log = EventLog::from_env().expect("couldn't open event log");
+//!
+//! // in `app_event`:
+//! fn app_event(log: &mut EventLog, event: &SDL_Event) {
+//!     unsafe { log.event(event) }.expect("couldn't record event");
+//! }
+//!
+//! // in `app_iterate`:
+//! log.iterate()?;
+//! # Ok::<(), sdl3_main::Error>(())
+//! ```
+//!
+//! # File format
+//!
+//! The file starts with an 8 byte magic `SDL3EVTS`, a little endian `u32` version, the
+//! pointer size and endianness of the platform that recorded it, and two reserved bytes.
+//! Each event is stored as its time in nanoseconds (`u64`), its type (`u32`), the bytes of
+//! the `SDL_Event` variant for that type (with a `u16` length), and the strings the variant
+//! points to (with a `u8` count, and a `u32` length for each, or `u32::MAX` for null).
+//!
+//! The variant bytes use the layout of the recording platform, so recordings can only be
+//! replayed on the same kind of platform. Pointers in user events can't be recorded and are
+//! replayed as null.
+
+use crate::{Error, Result};
+use alloc::{boxed::Box, ffi::CString, vec::Vec};
+use core::{
+    ffi::{c_char, CStr},
+    mem::{self, size_of},
+    ptr,
+};
+use sdl3_sys::{
+    events::{
+        SDL_AudioDeviceEvent, SDL_CameraDeviceEvent, SDL_ClipboardEvent, SDL_DisplayEvent,
+        SDL_DropEvent, SDL_Event, SDL_EventType, SDL_GamepadAxisEvent, SDL_GamepadButtonEvent,
+        SDL_GamepadDeviceEvent, SDL_GamepadSensorEvent, SDL_GamepadTouchpadEvent, SDL_JoyAxisEvent,
+        SDL_JoyBallEvent, SDL_JoyBatteryEvent, SDL_JoyButtonEvent, SDL_JoyDeviceEvent,
+        SDL_JoyHatEvent, SDL_KeyboardDeviceEvent, SDL_KeyboardEvent, SDL_MouseButtonEvent,
+        SDL_MouseDeviceEvent, SDL_MouseMotionEvent, SDL_MouseWheelEvent, SDL_PenAxisEvent,
+        SDL_PenButtonEvent, SDL_PenMotionEvent, SDL_PenProximityEvent, SDL_PenTouchEvent,
+        SDL_PushEvent, SDL_QuitEvent, SDL_SensorEvent, SDL_TextEditingCandidatesEvent,
+        SDL_TextEditingEvent, SDL_TextInputEvent, SDL_TouchFingerEvent, SDL_UserEvent,
+        SDL_WindowEvent, SDL_EVENT_AUDIO_DEVICE_ADDED, SDL_EVENT_AUDIO_DEVICE_FORMAT_CHANGED,
+        SDL_EVENT_AUDIO_DEVICE_REMOVED, SDL_EVENT_CAMERA_DEVICE_ADDED,
+        SDL_EVENT_CAMERA_DEVICE_APPROVED, SDL_EVENT_CAMERA_DEVICE_DENIED,
+        SDL_EVENT_CAMERA_DEVICE_REMOVED, SDL_EVENT_CLIPBOARD_UPDATE, SDL_EVENT_DISPLAY_FIRST,
+        SDL_EVENT_DISPLAY_LAST, SDL_EVENT_DROP_BEGIN, SDL_EVENT_DROP_COMPLETE, SDL_EVENT_DROP_FILE,
+        SDL_EVENT_DROP_POSITION, SDL_EVENT_DROP_TEXT, SDL_EVENT_FINGER_DOWN,
+        SDL_EVENT_FINGER_MOTION, SDL_EVENT_FINGER_UP, SDL_EVENT_GAMEPAD_ADDED,
+        SDL_EVENT_GAMEPAD_AXIS_MOTION, SDL_EVENT_GAMEPAD_BUTTON_DOWN, SDL_EVENT_GAMEPAD_BUTTON_UP,
+        SDL_EVENT_GAMEPAD_REMAPPED, SDL_EVENT_GAMEPAD_REMOVED, SDL_EVENT_GAMEPAD_SENSOR_UPDATE,
+        SDL_EVENT_GAMEPAD_STEAM_HANDLE_UPDATED, SDL_EVENT_GAMEPAD_TOUCHPAD_DOWN,
+        SDL_EVENT_GAMEPAD_TOUCHPAD_MOTION, SDL_EVENT_GAMEPAD_TOUCHPAD_UP,
+        SDL_EVENT_GAMEPAD_UPDATE_COMPLETE, SDL_EVENT_JOYSTICK_ADDED,
+        SDL_EVENT_JOYSTICK_AXIS_MOTION, SDL_EVENT_JOYSTICK_BALL_MOTION,
+        SDL_EVENT_JOYSTICK_BATTERY_UPDATED, SDL_EVENT_JOYSTICK_BUTTON_DOWN,
+        SDL_EVENT_JOYSTICK_BUTTON_UP, SDL_EVENT_JOYSTICK_HAT_MOTION, SDL_EVENT_JOYSTICK_REMOVED,
+        SDL_EVENT_JOYSTICK_UPDATE_COMPLETE, SDL_EVENT_KEYBOARD_ADDED, SDL_EVENT_KEYBOARD_REMOVED,
+        SDL_EVENT_KEY_DOWN, SDL_EVENT_KEY_UP, SDL_EVENT_LAST, SDL_EVENT_MOUSE_ADDED,
+        SDL_EVENT_MOUSE_BUTTON_DOWN, SDL_EVENT_MOUSE_BUTTON_UP, SDL_EVENT_MOUSE_MOTION,
+        SDL_EVENT_MOUSE_REMOVED, SDL_EVENT_MOUSE_WHEEL, SDL_EVENT_PEN_AXIS,
+        SDL_EVENT_PEN_BUTTON_DOWN, SDL_EVENT_PEN_BUTTON_UP, SDL_EVENT_PEN_DOWN,
+        SDL_EVENT_PEN_MOTION, SDL_EVENT_PEN_PROXIMITY_IN, SDL_EVENT_PEN_PROXIMITY_OUT,
+        SDL_EVENT_PEN_UP, SDL_EVENT_QUIT, SDL_EVENT_SENSOR_UPDATE, SDL_EVENT_TEXT_EDITING,
+        SDL_EVENT_TEXT_EDITING_CANDIDATES, SDL_EVENT_TEXT_INPUT, SDL_EVENT_USER,
+        SDL_EVENT_WINDOW_FIRST, SDL_EVENT_WINDOW_LAST,
+    },
+    timer::SDL_GetTicksNS,
+};
+use std::{
+    env,
+    fs::File,
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::Path,
+};
+
+const MAGIC: &[u8; 8] = b"SDL3EVTS";
+const VERSION: u32 = 1;
+const NULL_STRING: u32 = u32::MAX;
+
+/// Environment variable with the path to record events to for [`EventLog::from_env`]
+pub const RECORD_ENV: &str = "SDL3_MAIN_RECORD_EVENTS";
+
+/// Environment variable with the path to replay events from for [`EventLog::from_env`]
+pub const REPLAY_ENV: &str = "SDL3_MAIN_REPLAY_EVENTS";
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Strings {
+    None,
+    // one string at this byte offset in the variant
+    One(usize),
+    // two strings at these byte offsets
+    Two(usize, usize),
+    // an array of strings at this offset, with the length as an i32 at the other offset
+    Array(usize, usize),
+}
+
+// size of the variant for an event type, and where its string pointers are
+fn variant(event_type: SDL_EventType) -> (usize, Strings) {
+    let in_range = |first: SDL_EventType, last: SDL_EventType| (first..=last).contains(&event_type);
+    match event_type {
+        SDL_EVENT_QUIT => (size_of::<SDL_QuitEvent>(), Strings::None),
+        SDL_EVENT_KEY_DOWN | SDL_EVENT_KEY_UP => (size_of::<SDL_KeyboardEvent>(), Strings::None),
+        SDL_EVENT_TEXT_EDITING => (
+            size_of::<SDL_TextEditingEvent>(),
+            Strings::One(mem::offset_of!(SDL_TextEditingEvent, text)),
+        ),
+        SDL_EVENT_TEXT_EDITING_CANDIDATES => (
+            size_of::<SDL_TextEditingCandidatesEvent>(),
+            Strings::Array(
+                mem::offset_of!(SDL_TextEditingCandidatesEvent, candidates),
+                mem::offset_of!(SDL_TextEditingCandidatesEvent, num_candidates),
+            ),
+        ),
+        SDL_EVENT_TEXT_INPUT => (
+            size_of::<SDL_TextInputEvent>(),
+            Strings::One(mem::offset_of!(SDL_TextInputEvent, text)),
+        ),
+        SDL_EVENT_KEYBOARD_ADDED | SDL_EVENT_KEYBOARD_REMOVED => {
+            (size_of::<SDL_KeyboardDeviceEvent>(), Strings::None)
+        }
+        SDL_EVENT_MOUSE_MOTION => (size_of::<SDL_MouseMotionEvent>(), Strings::None),
+        SDL_EVENT_MOUSE_BUTTON_DOWN | SDL_EVENT_MOUSE_BUTTON_UP => {
+            (size_of::<SDL_MouseButtonEvent>(), Strings::None)
+        }
+        SDL_EVENT_MOUSE_WHEEL => (size_of::<SDL_MouseWheelEvent>(), Strings::None),
+        SDL_EVENT_MOUSE_ADDED | SDL_EVENT_MOUSE_REMOVED => {
+            (size_of::<SDL_MouseDeviceEvent>(), Strings::None)
+        }
+        SDL_EVENT_JOYSTICK_AXIS_MOTION => (size_of::<SDL_JoyAxisEvent>(), Strings::None),
+        SDL_EVENT_JOYSTICK_BALL_MOTION => (size_of::<SDL_JoyBallEvent>(), Strings::None),
+        SDL_EVENT_JOYSTICK_HAT_MOTION => (size_of::<SDL_JoyHatEvent>(), Strings::None),
+        SDL_EVENT_JOYSTICK_BUTTON_DOWN | SDL_EVENT_JOYSTICK_BUTTON_UP => {
+            (size_of::<SDL_JoyButtonEvent>(), Strings::None)
+        }
+        SDL_EVENT_JOYSTICK_ADDED
+        | SDL_EVENT_JOYSTICK_REMOVED
+        | SDL_EVENT_JOYSTICK_UPDATE_COMPLETE => (size_of::<SDL_JoyDeviceEvent>(), Strings::None),
+        SDL_EVENT_JOYSTICK_BATTERY_UPDATED => (size_of::<SDL_JoyBatteryEvent>(), Strings::None),
+        SDL_EVENT_GAMEPAD_AXIS_MOTION => (size_of::<SDL_GamepadAxisEvent>(), Strings::None),
+        SDL_EVENT_GAMEPAD_BUTTON_DOWN | SDL_EVENT_GAMEPAD_BUTTON_UP => {
+            (size_of::<SDL_GamepadButtonEvent>(), Strings::None)
+        }
+        SDL_EVENT_GAMEPAD_ADDED
+        | SDL_EVENT_GAMEPAD_REMOVED
+        | SDL_EVENT_GAMEPAD_REMAPPED
+        | SDL_EVENT_GAMEPAD_UPDATE_COMPLETE
+        | SDL_EVENT_GAMEPAD_STEAM_HANDLE_UPDATED => {
+            (size_of::<SDL_GamepadDeviceEvent>(), Strings::None)
+        }
+        SDL_EVENT_GAMEPAD_TOUCHPAD_DOWN
+        | SDL_EVENT_GAMEPAD_TOUCHPAD_MOTION
+        | SDL_EVENT_GAMEPAD_TOUCHPAD_UP => (size_of::<SDL_GamepadTouchpadEvent>(), Strings::None),
+        SDL_EVENT_GAMEPAD_SENSOR_UPDATE => (size_of::<SDL_GamepadSensorEvent>(), Strings::None),
+        SDL_EVENT_FINGER_DOWN | SDL_EVENT_FINGER_UP | SDL_EVENT_FINGER_MOTION => {
+            (size_of::<SDL_TouchFingerEvent>(), Strings::None)
+        }
+        SDL_EVENT_CLIPBOARD_UPDATE => (
+            size_of::<SDL_ClipboardEvent>(),
+            Strings::Array(
+                mem::offset_of!(SDL_ClipboardEvent, mime_types),
+                mem::offset_of!(SDL_ClipboardEvent, n_mime_types),
+            ),
+        ),
+        SDL_EVENT_DROP_FILE
+        | SDL_EVENT_DROP_TEXT
+        | SDL_EVENT_DROP_BEGIN
+        | SDL_EVENT_DROP_COMPLETE
+        | SDL_EVENT_DROP_POSITION => (
+            size_of::<SDL_DropEvent>(),
+            Strings::Two(
+                mem::offset_of!(SDL_DropEvent, source),
+                mem::offset_of!(SDL_DropEvent, data),
+            ),
+        ),
+        SDL_EVENT_AUDIO_DEVICE_ADDED
+        | SDL_EVENT_AUDIO_DEVICE_REMOVED
+        | SDL_EVENT_AUDIO_DEVICE_FORMAT_CHANGED => {
+            (size_of::<SDL_AudioDeviceEvent>(), Strings::None)
+        }
+        SDL_EVENT_SENSOR_UPDATE => (size_of::<SDL_SensorEvent>(), Strings::None),
+        SDL_EVENT_PEN_PROXIMITY_IN | SDL_EVENT_PEN_PROXIMITY_OUT => {
+            (size_of::<SDL_PenProximityEvent>(), Strings::None)
+        }
+        SDL_EVENT_PEN_DOWN | SDL_EVENT_PEN_UP => (size_of::<SDL_PenTouchEvent>(), Strings::None),
+        SDL_EVENT_PEN_BUTTON_DOWN | SDL_EVENT_PEN_BUTTON_UP => {
+            (size_of::<SDL_PenButtonEvent>(), Strings::None)
+        }
+        SDL_EVENT_PEN_MOTION => (size_of::<SDL_PenMotionEvent>(), Strings::None),
+        SDL_EVENT_PEN_AXIS => (size_of::<SDL_PenAxisEvent>(), Strings::None),
+        SDL_EVENT_CAMERA_DEVICE_ADDED
+        | SDL_EVENT_CAMERA_DEVICE_REMOVED
+        | SDL_EVENT_CAMERA_DEVICE_APPROVED
+        | SDL_EVENT_CAMERA_DEVICE_DENIED => (size_of::<SDL_CameraDeviceEvent>(), Strings::None),
+        _ if in_range(SDL_EVENT_DISPLAY_FIRST, SDL_EVENT_DISPLAY_LAST) => {
+            (size_of::<SDL_DisplayEvent>(), Strings::None)
+        }
+        _ if in_range(SDL_EVENT_WINDOW_FIRST, SDL_EVENT_WINDOW_LAST) => {
+            (size_of::<SDL_WindowEvent>(), Strings::None)
+        }
+        _ if in_range(SDL_EVENT_USER, SDL_EVENT_LAST) => {
+            (size_of::<SDL_UserEvent>(), Strings::None)
+        }
+        // common events and event types this version doesn't know about
+        _ => (size_of::<SDL_Event>(), Strings::None),
+    }
+}
+
+unsafe fn read_ptr<T>(bytes: &[u8], offset: usize) -> *const T {
+    unsafe { ptr::read_unaligned(bytes.as_ptr().add(offset) as *const *const T) }
+}
+
+fn write_ptr<T>(bytes: &mut [u8], offset: usize, value: *const T) {
+    unsafe { ptr::write_unaligned(bytes.as_mut_ptr().add(offset) as *mut *const T, value) }
+}
+
+/// An event read from a recording. The strings the event points to are owned by this.
+pub struct RecordedEvent {
+    time_ns: u64,
+    event_type: SDL_EventType,
+    body: Vec<u8>,
+    strings: Vec<Option<CString>>,
+    // string pointers for array variants
+    array: Box<[*const c_char]>,
+}
+
+// the pointers only point into the event's own strings
+unsafe impl Send for RecordedEvent {}
+unsafe impl Sync for RecordedEvent {}
+
+impl RecordedEvent {
+    /// Copy an event, including the strings it points to.
+    ///
+    /// # Safety
+    /// `event` must be a valid event, like the ones you get from SDL. In particular, the
+    /// pointers in text, drop and clipboard events must be valid.
+    pub unsafe fn new(time_ns: u64, event: &SDL_Event) -> Self {
+        let event_type = SDL_EventType(unsafe { event.r#type });
+        let (size, strings) = variant(event_type);
+        let mut body = unsafe { event.padding }[..size].to_vec();
+        let read =
+            |p: *const c_char| (!p.is_null()).then(|| CString::from(unsafe { CStr::from_ptr(p) }));
+        let strings = match strings {
+            Strings::None => {
+                if event_type >= SDL_EVENT_USER && event_type < SDL_EVENT_LAST {
+                    write_ptr::<u8>(
+                        &mut body,
+                        mem::offset_of!(SDL_UserEvent, data1),
+                        ptr::null(),
+                    );
+                    write_ptr::<u8>(
+                        &mut body,
+                        mem::offset_of!(SDL_UserEvent, data2),
+                        ptr::null(),
+                    );
+                }
+                Vec::new()
+            }
+            Strings::One(offset) => {
+                let s = read(unsafe { read_ptr(&body, offset) });
+                write_ptr::<c_char>(&mut body, offset, ptr::null());
+                alloc::vec![s]
+            }
+            Strings::Two(a, b) => {
+                let s = alloc::vec![
+                    read(unsafe { read_ptr(&body, a) }),
+                    read(unsafe { read_ptr(&body, b) })
+                ];
+                write_ptr::<c_char>(&mut body, a, ptr::null());
+                write_ptr::<c_char>(&mut body, b, ptr::null());
+                s
+            }
+            Strings::Array(offset, len_offset) => {
+                let list: *const *const c_char = unsafe { read_ptr(&body, offset) };
+                let len = i32::from_ne_bytes(body[len_offset..len_offset + 4].try_into().unwrap());
+                let s = if list.is_null() {
+                    Vec::new()
+                } else {
+                    (0..len.max(0) as usize)
+                        .map(|i| read(unsafe { *list.add(i) }))
+                        .collect()
+                };
+                write_ptr::<*const c_char>(&mut body, offset, ptr::null());
+                s
+            }
+        };
+        Self::from_parts(time_ns, event_type, body, strings)
+    }
+
+    fn from_parts(
+        time_ns: u64,
+        event_type: SDL_EventType,
+        body: Vec<u8>,
+        strings: Vec<Option<CString>>,
+    ) -> Self {
+        let array: Box<[*const c_char]> = match variant(event_type).1 {
+            Strings::Array(..) => strings
+                .iter()
+                .map(|s| s.as_ref().map_or(ptr::null(), |s| s.as_ptr()))
+                .collect(),
+            _ => Box::new([]),
+        };
+        Self {
+            time_ns,
+            event_type,
+            body,
+            strings,
+            array,
+        }
+    }
+
+    /// Get the time of the event in nanoseconds, relative to the start of the recording.
+    #[inline]
+    pub fn time_ns(&self) -> u64 {
+        self.time_ns
+    }
+
+    /// Get the event type.
+    #[inline]
+    pub fn event_type(&self) -> SDL_EventType {
+        self.event_type
+    }
+
+    /// Get the strings the event points to, in the order they're stored.
+    pub fn strings(&self) -> impl Iterator<Item = Option<&CStr>> {
+        self.strings.iter().map(|s| s.as_deref())
+    }
+
+    /// Rebuild the event, with a timestamp of 0. Pointers in the event point into `self`.
+    pub fn to_event(&self) -> SDL_Event {
+        let mut event: SDL_Event = unsafe { mem::zeroed() };
+        let body = unsafe { &mut event.padding };
+        body[..self.body.len()].copy_from_slice(&self.body);
+        let string = |i: usize| {
+            self.strings
+                .get(i)
+                .and_then(|s| s.as_ref())
+                .map_or(ptr::null(), |s| s.as_ptr())
+        };
+        match variant(self.event_type).1 {
+            Strings::None => (),
+            Strings::One(offset) => write_ptr(body, offset, string(0)),
+            Strings::Two(a, b) => {
+                write_ptr(body, a, string(0));
+                write_ptr(body, b, string(1));
+            }
+            Strings::Array(offset, len_offset) => {
+                write_ptr(body, offset, self.array.as_ptr());
+                body[len_offset..len_offset + 4]
+                    .copy_from_slice(&(self.array.len() as i32).to_ne_bytes());
+            }
+        }
+        event.common.timestamp = 0;
+        event
+    }
+
+    // copy the event and leak it, so pointers into it stay valid for the rest of the process
+    fn leak(&self) -> &'static Self {
+        Box::leak(Box::new(Self::from_parts(
+            self.time_ns,
+            self.event_type,
+            self.body.clone(),
+            self.strings.clone(),
+        )))
+    }
+
+    fn write(&self, w: &mut impl Write) -> io::Result<()> {
+        w.write_all(&self.time_ns.to_le_bytes())?;
+        w.write_all(&self.event_type.0.to_le_bytes())?;
+        w.write_all(&(self.body.len() as u16).to_le_bytes())?;
+        w.write_all(&self.body)?;
+        let count = u8::try_from(self.strings.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "too many strings"))?;
+        w.write_all(&[count])?;
+        for s in &self.strings {
+            match s {
+                Some(s) => {
+                    let bytes = s.to_bytes();
+                    let len = u32::try_from(bytes.len())
+                        .ok()
+                        .filter(|&len| len != NULL_STRING)
+                        .ok_or_else(|| {
+                            io::Error::new(io::ErrorKind::InvalidInput, "string too long")
+                        })?;
+                    w.write_all(&len.to_le_bytes())?;
+                    w.write_all(bytes)?;
+                }
+                None => w.write_all(&NULL_STRING.to_le_bytes())?,
+            }
+        }
+        Ok(())
+    }
+
+    // returns None at the end of the file
+    fn read(r: &mut impl Read) -> io::Result<Option<Self>> {
+        let mut time = [0; 8];
+        match r.read_exact(&mut time) {
+            Ok(()) => (),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e),
+        }
+        let event_type = SDL_EventType(u32::from_le_bytes(read_array(r)?));
+        let len = u16::from_le_bytes(read_array(r)?) as usize;
+        if len != variant(event_type).0 {
+            return Err(invalid_data("event size doesn't match its type"));
+        }
+        let mut body = alloc::vec![0; len];
+        r.read_exact(&mut body)?;
+        let [count] = read_array(r)?;
+        let mut strings = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let len = u32::from_le_bytes(read_array(r)?);
+            strings.push(if len == NULL_STRING {
+                None
+            } else {
+                let mut bytes = alloc::vec![0; len as usize];
+                r.read_exact(&mut bytes)?;
+                Some(CString::new(bytes).map_err(|_| invalid_data("string contains nul"))?)
+            });
+        }
+        Ok(Some(Self::from_parts(
+            u64::from_le_bytes(time),
+            event_type,
+            body,
+            strings,
+        )))
+    }
+}
+
+fn read_array<const N: usize>(r: &mut impl Read) -> io::Result<[u8; N]> {
+    let mut buf = [0; N];
+    r.read_exact(&mut buf)?;
+    Ok(buf)
+}
+
+fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+fn header() -> [u8; 16] {
+    let mut header = [0; 16];
+    header[..8].copy_from_slice(MAGIC);
+    header[8..12].copy_from_slice(&VERSION.to_le_bytes());
+    header[12] = size_of::<*const ()>() as u8;
+    header[13] = cfg!(target_endian = "little") as u8;
+    header
+}
+
+/// Writes events to a recording. See the [module documentation](self).
+pub struct EventRecorder<W: Write> {
+    writer: W,
+    start_ns: u64,
+}
+
+impl EventRecorder<BufWriter<File>> {
+    /// Create a recording file.
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::new(BufWriter::new(File::create(path)?))
+    }
+}
+
+impl<W: Write> EventRecorder<W> {
+    /// Start a recording. Event times are recorded relative to now.
+    pub fn new(mut writer: W) -> io::Result<Self> {
+        writer.write_all(&header())?;
+        Ok(Self {
+            writer,
+            start_ns: unsafe { SDL_GetTicksNS() },
+        })
+    }
+
+    /// Record an event, at the time of its timestamp.
+    ///
+    /// # Safety
+    /// `event` must be a valid event, like the ones you get from SDL
+    pub unsafe fn record(&mut self, event: &SDL_Event) -> io::Result<()> {
+        let timestamp = unsafe { event.common.timestamp };
+        let time_ns = timestamp.saturating_sub(self.start_ns);
+        unsafe { RecordedEvent::new(time_ns, event) }.write(&mut self.writer)
+    }
+
+    /// Record an event that has already been copied.
+    pub fn record_copy(&mut self, event: &RecordedEvent) -> io::Result<()> {
+        event.write(&mut self.writer)
+    }
+
+    /// Flush the recording and get the writer back.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+/// Replays a recording. See the [module documentation](self).
+///
+/// The whole recording is read up front. Events may still be queued after the player is
+/// dropped, so the strings that replayed events point to are copied and leaked when the
+/// events are pushed.
+pub struct EventPlayer {
+    events: Vec<RecordedEvent>,
+    next: usize,
+    start_ns: Option<u64>,
+}
+
+impl EventPlayer {
+    /// Open a recording file.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::new(BufReader::new(File::open(path)?))
+    }
+
+    /// Read a recording.
+    pub fn new(mut reader: impl Read) -> io::Result<Self> {
+        let found: [u8; 16] = read_array(&mut reader)?;
+        let expected = header();
+        if found[..8] != expected[..8] {
+            return Err(invalid_data("not an event recording"));
+        }
+        if found[8..12] != expected[8..12] {
+            return Err(invalid_data("unsupported event recording version"));
+        }
+        if found[12..14] != expected[12..14] {
+            return Err(invalid_data(
+                "event recording is from another kind of platform",
+            ));
+        }
+        let mut events = Vec::new();
+        while let Some(event) = RecordedEvent::read(&mut reader)? {
+            events.push(event);
+        }
+        Ok(Self {
+            events,
+            next: 0,
+            start_ns: None,
+        })
+    }
+
+    /// Get the recorded events.
+    #[inline]
+    pub fn events(&self) -> &[RecordedEvent] {
+        &self.events
+    }
+
+    /// Check if all events have been replayed.
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.next >= self.events.len()
+    }
+
+    /// Push the events that are due onto the event queue. Returns the number of events pushed,
+    /// not counting events that were filtered out.
+    ///
+    /// The replay starts at the first call to this, and events are pushed once as much time
+    /// has passed as when they were recorded. Call this once per iteration of the main loop.
+    pub fn pump(&mut self) -> usize {
+        let now = unsafe { SDL_GetTicksNS() };
+        let start = *self.start_ns.get_or_insert(now);
+        self.pump_until(now.saturating_sub(start))
+    }
+
+    /// Push the events recorded up to `time_ns` after the start of the recording onto the
+    /// event queue, regardless of the actual time. Returns the number of events pushed, not
+    /// counting events that were filtered out.
+    pub fn pump_until(&mut self, time_ns: u64) -> usize {
+        let mut pushed = 0;
+        while let Some(event) = self.events.get(self.next) {
+            if event.time_ns > time_ns {
+                break;
+            }
+            self.next += 1;
+            let mut event = match variant(event.event_type).1 {
+                Strings::None => event.to_event(),
+                _ => event.leak().to_event(),
+            };
+            // this fails if the event is filtered out, which isn't an error here
+            if unsafe { SDL_PushEvent(&mut event) } {
+                pushed += 1;
+            }
+        }
+        pushed
+    }
+}
+
+/// Records or replays events depending on how it was created, or does nothing.
+///
+/// Call [`EventLog::event`] for every event the app receives and [`EventLog::iterate`] once
+/// per iteration of the main loop.
+pub enum EventLog {
+    /// Do nothing
+    Off,
+
+    /// Record events
+    Recording(EventRecorder<BufWriter<File>>),
+
+    /// Replay events
+    Replaying(EventPlayer),
+}
+
+impl EventLog {
+    /// Record to the path in the [`RECORD_ENV`] environment variable if it's set, or else
+    /// replay from the path in [`REPLAY_ENV`] if it's set, or else do nothing.
+    pub fn from_env() -> io::Result<Self> {
+        if let Some(path) = env::var_os(RECORD_ENV) {
+            Ok(Self::Recording(EventRecorder::create(path)?))
+        } else if let Some(path) = env::var_os(REPLAY_ENV) {
+            Ok(Self::Replaying(EventPlayer::open(path)?))
+        } else {
+            Ok(Self::Off)
+        }
+    }
+
+    /// Record the event if recording.
+    ///
+    /// # Safety
+    /// `event` must be a valid event, like the ones passed to `app_event`
+    pub unsafe fn event(&mut self, event: &SDL_Event) -> io::Result<()> {
+        match self {
+            Self::Recording(recorder) => unsafe { recorder.record(event) },
+            _ => Ok(()),
+        }
+    }
+
+    /// Push the events that are due if replaying, or flush the recording if recording.
+    pub fn iterate(&mut self) -> Result<()> {
+        match self {
+            Self::Off => Ok(()),
+            Self::Recording(recorder) => recorder
+                .writer
+                .flush()
+                .map_err(|e| Error::new(alloc::format!("couldn't write events: {e}"))),
+            Self::Replaying(player) => {
+                player.pump();
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn events() -> Vec<RecordedEvent> {
+        let mut events = Vec::new();
+
+        let mut event: SDL_Event = unsafe { mem::zeroed() };
+        event.quit.r#type = SDL_EVENT_QUIT;
+        events.push(unsafe { RecordedEvent::new(10, &event) });
+
+        let mut event: SDL_Event = unsafe { mem::zeroed() };
+        event.text.r#type = SDL_EVENT_TEXT_INPUT;
+        event.text.windowID = 3;
+        event.text.text = c"héllo".as_ptr();
+        events.push(unsafe { RecordedEvent::new(20, &event) });
+
+        let mut event: SDL_Event = unsafe { mem::zeroed() };
+        event.drop.r#type = SDL_EVENT_DROP_FILE;
+        event.drop.x = 1.5;
+        event.drop.data = c"/tmp/file".as_ptr();
+        events.push(unsafe { RecordedEvent::new(20, &event) });
+
+        let mut mime_types = [c"text/plain".as_ptr(), ptr::null(), c"".as_ptr()];
+        let mut event: SDL_Event = unsafe { mem::zeroed() };
+        event.clipboard.r#type = SDL_EVENT_CLIPBOARD_UPDATE;
+        event.clipboard.n_mime_types = mime_types.len() as i32;
+        event.clipboard.mime_types = mime_types.as_mut_ptr();
+        events.push(unsafe { RecordedEvent::new(30, &event) });
+
+        events
+    }
+
+    fn recording(events: &[RecordedEvent]) -> Vec<u8> {
+        let mut bytes = header().to_vec();
+        for event in events {
+            event.write(&mut bytes).unwrap();
+        }
+        bytes
+    }
+
+    #[test]
+    fn round_trip() {
+        let events = events();
+        let player = EventPlayer::new(&recording(&events)[..]).unwrap();
+        assert_eq!(player.events().len(), events.len());
+        for (a, b) in events.iter().zip(player.events()) {
+            assert_eq!(a.time_ns(), b.time_ns());
+            assert!(a.event_type() == b.event_type());
+            assert_eq!(a.body, b.body);
+            assert!(a.strings().eq(b.strings()));
+        }
+
+        let strings: Vec<_> = player
+            .events()
+            .iter()
+            .map(|e| e.strings().collect::<Vec<_>>())
+            .collect();
+        assert_eq!(strings[0], []);
+        assert_eq!(strings[1], [Some(c"héllo")]);
+        assert_eq!(strings[2], [None, Some(c"/tmp/file")]);
+        assert_eq!(strings[3], [Some(c"text/plain"), None, Some(c"")]);
+
+        let event = player.events()[1].to_event();
+        unsafe {
+            assert_eq!(event.text.windowID, 3);
+            assert_eq!(CStr::from_ptr(event.text.text), c"héllo");
+        }
+        let event = player.events()[2].to_event();
+        unsafe {
+            assert_eq!(event.drop.x, 1.5);
+            assert!(event.drop.source.is_null());
+            assert_eq!(CStr::from_ptr(event.drop.data), c"/tmp/file");
+        }
+        let event = player.events()[3].to_event();
+        unsafe {
+            assert_eq!(event.clipboard.n_mime_types, 3);
+            assert!((*event.clipboard.mime_types.add(1)).is_null());
+            assert_eq!(CStr::from_ptr(*event.clipboard.mime_types.add(2)), c"");
+        }
+    }
+
+    #[test]
+    fn leaked_copy() {
+        let events = events();
+        let leaked = events[1].leak();
+        let event = leaked.to_event();
+        drop(events);
+        assert_eq!(unsafe { CStr::from_ptr(event.text.text) }, c"héllo");
+    }
+
+    #[test]
+    fn empty() {
+        let player = EventPlayer::new(&header()[..]).unwrap();
+        assert!(player.events().is_empty());
+        assert!(player.is_finished());
+    }
+
+    #[test]
+    fn bad_header() {
+        let mut bytes = recording(&events());
+        bytes[0] = b'X';
+        let e = EventPlayer::new(&bytes[..]).err().unwrap();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+
+        let mut bytes = recording(&events());
+        bytes[8] = 99;
+        let e = EventPlayer::new(&bytes[..]).err().unwrap();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+
+        let e = EventPlayer::new(&header()[..10]).err().unwrap();
+        assert_eq!(e.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn truncated() {
+        let bytes = recording(&events());
+        for len in 16..bytes.len() {
+            // cutting between events only drops the later events
+            if let Err(e) = EventPlayer::new(&bytes[..len]) {
+                assert_eq!(e.kind(), io::ErrorKind::UnexpectedEof);
+            }
+        }
+        let e = EventPlayer::new(&bytes[..bytes.len() - 1]).err().unwrap();
+        assert_eq!(e.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn size_mismatch() {
+        let mut bytes = header().to_vec();
+        bytes.extend_from_slice(&0u64.to_le_bytes());
+        bytes.extend_from_slice(&SDL_EVENT_QUIT.0.to_le_bytes());
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        let e = EventPlayer::new(&bytes[..]).err().unwrap();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,358 @@
+//! Loading shared libraries with [`sdl3_sys::loadso`] and typed symbols.
+//!
+//! [`SharedObject`] owns a library loaded with [`SDL_LoadObject`] and unloads it on drop.
+//! [`SharedObject::get`] looks up a function as a typed function pointer, wrapped in a
+//! [`Symbol`] that borrows the library. [`shared_object_functions!`](crate::shared_object_functions)
+//! declares a struct of functions that are loaded all at once, reporting every missing
+//! symbol if any can't be found.
+//!
+//! ```no_run
+//! use sdl3_main::{shared_object::SharedObject, shared_object_functions};
+//!
+//! shared_object_functions! {
+//!     /// Functions from libm
+//!     pub struct LibM {
+//!         pub cos: unsafe extern "C" fn(f64) -> f64,
+//!         pub pow: unsafe extern "C" fn(f64, f64) -> f64,
+//!     }
+//! }
+//!
+//! let libm = SharedObject::load("libm.so.6")?;
+//!
+//! let cos = unsafe { libm.get::<unsafe extern "C" fn(f64) -> f64>("cos") }?;
+//! assert_eq!(unsafe { cos(0.0) }, 1.0);
+//!
+//! let m = unsafe { LibM::load(&libm) }?;
+//! assert_eq!(unsafe { (m.pow)(2.0, 10.0) }, 1024.0);
+//! # Ok::<(), sdl3_main::Error>(())
+//! ```
+
+use crate::{Error, Result};
+use alloc::{ffi::CString, vec::Vec};
+use core::{
+    ffi::c_void,
+    fmt::{self, Display},
+    marker::PhantomData,
+    ops::Deref,
+    ptr::NonNull,
+};
+use sdl3_sys::loadso::{SDL_LoadFunction, SDL_LoadObject, SDL_SharedObject, SDL_UnloadObject};
+
+/// Function pointer types that symbols can be loaded as.
+///
+/// This is implemented for `unsafe extern "C" fn` pointers with up to 12 arguments. Safe
+/// function pointers aren't supported, because a copy of the pointer could be called after
+/// the library has been unloaded.
+///
+/// # Safety
+/// Implementors must be function pointers.
+pub unsafe trait FunctionPointer: Copy {
+    /// Convert a generic function pointer to this type.
+    ///
+    /// # Safety
+    /// `f` must be a function with a signature that matches this type
+    unsafe fn from_raw(f: unsafe extern "C" fn()) -> Self;
+
+    /// Get the address of the function.
+    fn as_ptr(self) -> *const c_void;
+}
+
+macro_rules! impl_function_pointer {
+    ($($arg:ident),*) => {
+        unsafe impl<R $(, $arg)*> FunctionPointer for unsafe extern "C" fn($($arg),*) -> R {
+            #[inline(always)]
+            unsafe fn from_raw(f: unsafe extern "C" fn()) -> Self {
+                unsafe { core::mem::transmute::<unsafe extern "C" fn(), Self>(f) }
+            }
+
+            #[inline(always)]
+            fn as_ptr(self) -> *const c_void {
+                self as *const c_void
+            }
+        }
+
+    };
+}
+
+impl_function_pointer!();
+impl_function_pointer!(A1);
+impl_function_pointer!(A1, A2);
+impl_function_pointer!(A1, A2, A3);
+impl_function_pointer!(A1, A2, A3, A4);
+impl_function_pointer!(A1, A2, A3, A4, A5);
+impl_function_pointer!(A1, A2, A3, A4, A5, A6);
+impl_function_pointer!(A1, A2, A3, A4, A5, A6, A7);
+impl_function_pointer!(A1, A2, A3, A4, A5, A6, A7, A8);
+impl_function_pointer!(A1, A2, A3, A4, A5, A6, A7, A8, A9);
+impl_function_pointer!(A1, A2, A3, A4, A5, A6, A7, A8, A9, A10);
+impl_function_pointer!(A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11);
+impl_function_pointer!(A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12);
+
+/// A loaded shared library. The library is unloaded when this is dropped.
+pub struct SharedObject {
+    ptr: NonNull<SDL_SharedObject>,
+}
+
+// looking up symbols is thread safe, and the library can be unloaded from any thread
+unsafe impl Send for SharedObject {}
+unsafe impl Sync for SharedObject {}
+
+impl SharedObject {
+    /// Load a shared library. See [`SDL_LoadObject`].
+    pub fn load(path: &str) -> Result<Self> {
+        let path = CString::new(path).map_err(|_| Error::new("path contains nul"))?;
+        match NonNull::new(unsafe { SDL_LoadObject(path.as_ptr()) }) {
+            Some(ptr) => Ok(Self { ptr }),
+            None => Err(Error::from_sdl()),
+        }
+    }
+
+    /// Take ownership of a raw shared object.
+    ///
+    /// # Safety
+    /// `ptr` must be a valid shared object that isn't owned by anything else
+    #[inline(always)]
+    pub unsafe fn from_raw(ptr: NonNull<SDL_SharedObject>) -> Self {
+        Self { ptr }
+    }
+
+    /// Give up ownership of the raw shared object. It won't be unloaded automatically.
+    #[inline]
+    pub fn into_raw(self) -> *mut SDL_SharedObject {
+        let ptr = self.ptr.as_ptr();
+        core::mem::forget(self);
+        ptr
+    }
+
+    /// Get the raw shared object pointer.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *mut SDL_SharedObject {
+        self.ptr.as_ptr()
+    }
+
+    /// Look up a function. See [`SDL_LoadFunction`].
+    ///
+    /// # Safety
+    /// The function named `name` must have the signature of `F`. The function pointer can be
+    /// copied out of the [`Symbol`], but it must not be called after this `SharedObject` has
+    /// been dropped
+    pub unsafe fn get<F: FunctionPointer>(&self, name: &str) -> Result<Symbol<'_, F>> {
+        let name = CString::new(name).map_err(|_| Error::new("name contains nul"))?;
+        match unsafe { SDL_LoadFunction(self.as_ptr(), name.as_ptr()) } {
+            Some(f) => Ok(Symbol {
+                f: unsafe { F::from_raw(f) },
+                _lib: PhantomData,
+            }),
+            None => Err(Error::from_sdl()),
+        }
+    }
+}
+
+impl fmt::Debug for SharedObject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SharedObject").field(&self.ptr).finish()
+    }
+}
+
+impl Drop for SharedObject {
+    fn drop(&mut self) {
+        unsafe { SDL_UnloadObject(self.ptr.as_ptr()) };
+    }
+}
+
+/// A function loaded from a [`SharedObject`]. This can't outlive the library it was
+/// loaded from.
+///
+/// This derefs to the function pointer, so it can be called directly. Copies of the
+/// function pointer itself aren't tied to the library, and must not be called after the
+/// library has been dropped. See the safety requirements of [`SharedObject::get`].
+#[derive(Clone, Copy)]
+pub struct Symbol<'lib, F> {
+    f: F,
+    _lib: PhantomData<&'lib SharedObject>,
+}
+
+impl<F: FunctionPointer> Symbol<'_, F> {
+    /// Get the address of the function.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *const c_void {
+        self.f.as_ptr()
+    }
+}
+
+impl<F> Deref for Symbol<'_, F> {
+    type Target = F;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.f
+    }
+}
+
+impl<F: FunctionPointer> fmt::Debug for Symbol<'_, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Symbol").field(&self.as_ptr()).finish()
+    }
+}
+
+/// The symbols that couldn't be found when loading a struct declared with
+/// [`shared_object_functions!`](crate::shared_object_functions).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MissingSymbols(Vec<&'static str>);
+
+impl MissingSymbols {
+    #[doc(hidden)]
+    pub fn __push(&mut self, name: &'static str) {
+        self.0.push(name);
+    }
+
+    #[doc(hidden)]
+    pub fn __into_result(self) -> core::result::Result<(), Self> {
+        if self.0.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Get the names of the missing symbols.
+    #[inline(always)]
+    pub fn names(&self) -> &[&'static str] {
+        &self.0
+    }
+}
+
+impl Display for MissingSymbols {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("missing symbols: ")?;
+        for (i, name) in self.0.iter().enumerate() {
+            if i != 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(name)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MissingSymbols {}
+
+impl From<MissingSymbols> for Error {
+    fn from(value: MissingSymbols) -> Self {
+        Error::new(alloc::format!("{value}"))
+    }
+}
+
+/// Declare a struct of functions that are loaded from a [`SharedObject`] all at once.
+///
+/// Each field is named after the symbol it's loaded from, and its type must implement
+/// [`FunctionPointer`]. The struct gets a lifetime parameter that ties it to the library,
+/// and an `unsafe fn load(&SharedObject) -> Result<Self, MissingSymbols>` that lists every
+/// symbol that couldn't be found if any are missing. The safety requirements of `load` are
+/// that the functions in the library have the declared signatures, and that the function
+/// pointers aren't called after the library has been dropped, even if they're copied out of
+/// the struct.
+///
+/// See the [module documentation](crate::shared_object) for an example.
+#[macro_export]
+macro_rules! shared_object_functions {
+    ($(
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $( $(#[$field_meta:meta])* $field_vis:vis $field:ident : $ty:ty ),* $(,)?
+        }
+    )*) => {$(
+        $(#[$meta])*
+        $vis struct $name<'lib> {
+            $( $(#[$field_meta])* $field_vis $field: $ty, )*
+            _lib: ::core::marker::PhantomData<&'lib $crate::shared_object::SharedObject>,
+        }
+
+        impl<'lib> $name<'lib> {
+            /// Load all the functions from `lib`.
+            ///
+            /// # Safety
+            /// The functions in `lib` must have the declared signatures, and the function
+            /// pointers must not be called after `lib` has been dropped
+            #[allow(dead_code)]
+            $vis unsafe fn load(
+                lib: &'lib $crate::shared_object::SharedObject,
+            ) -> ::core::result::Result<Self, $crate::shared_object::MissingSymbols> {
+                let mut missing = $crate::shared_object::MissingSymbols::default();
+                $(
+                    let $field = match unsafe { lib.get::<$ty>(::core::stringify!($field)) } {
+                        ::core::result::Result::Ok(f) => ::core::option::Option::Some(*f),
+                        ::core::result::Result::Err(_) => {
+                            missing.__push(::core::stringify!($field));
+                            ::core::option::Option::None
+                        }
+                    };
+                )*
+                missing.__into_result()?;
+                ::core::result::Result::Ok(Self {
+                    $( $field: $field.unwrap(), )*
+                    _lib: ::core::marker::PhantomData,
+                })
+            }
+        }
+    )*};
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::SharedObject;
+
+    crate::shared_object_functions! {
+        struct LibM {
+            cos: unsafe extern "C" fn(f64) -> f64,
+            pow: unsafe extern "C" fn(f64, f64) -> f64,
+        }
+
+        #[allow(dead_code)]
+        struct Missing {
+            cos: unsafe extern "C" fn(f64) -> f64,
+            sdl3_main_missing_1: unsafe extern "C" fn(),
+            sdl3_main_missing_2: unsafe extern "C" fn(),
+        }
+    }
+
+    #[test]
+    fn get() {
+        let libm = SharedObject::load("libm.so.6").unwrap();
+        let cos = unsafe { libm.get::<unsafe extern "C" fn(f64) -> f64>("cos") }.unwrap();
+        assert_eq!(unsafe { cos(0.0) }, 1.0);
+        assert!(!cos.as_ptr().is_null());
+        assert!(unsafe { libm.get::<unsafe extern "C" fn()>("sdl3_main_missing") }.is_err());
+        assert!(unsafe { libm.get::<unsafe extern "C" fn()>("c\0s") }.is_err());
+    }
+
+    #[test]
+    fn load_functions() {
+        let libm = SharedObject::load("libm.so.6").unwrap();
+        let m = unsafe { LibM::load(&libm) }.unwrap();
+        assert_eq!(unsafe { (m.cos)(0.0) }, 1.0);
+        assert_eq!(unsafe { (m.pow)(2.0, 10.0) }, 1024.0);
+    }
+
+    #[test]
+    fn missing_symbols() {
+        let libm = SharedObject::load("libm.so.6").unwrap();
+        let Err(missing) = (unsafe { Missing::load(&libm) }) else {
+            panic!("loaded missing symbols");
+        };
+        assert_eq!(
+            missing.names(),
+            ["sdl3_main_missing_1", "sdl3_main_missing_2"]
+        );
+        assert_eq!(
+            alloc::format!("{missing}"),
+            "missing symbols: sdl3_main_missing_1, sdl3_main_missing_2"
+        );
+    }
+
+    #[test]
+    fn load_missing_library() {
+        assert!(SharedObject::load("sdl3-main-missing-library.so").is_err());
+    }
+}
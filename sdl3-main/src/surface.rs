@@ -0,0 +1,709 @@
+//! Safe wrappers for [`SDL_Surface`].
+//!
+//! Surfaces live in system memory and don't need a video driver, so everything here
+//! works without a display.
+
+use crate::{
+    error::{check, len_to_c_int},
+    pixels::{Color, FColor},
+    rect::{NineGrid, Point, Rect},
+    Error, Result,
+};
+use alloc::vec::Vec;
+use core::{
+    ffi::{c_void, CStr},
+    marker::PhantomData,
+    mem::{align_of, size_of},
+    ptr::{self, NonNull},
+    slice,
+};
+use sdl3_sys::{
+    iostream::{
+        SDL_CloseIO, SDL_GetIOProperties, SDL_GetIOSize, SDL_IOFromConstMem, SDL_IOFromDynamicMem,
+        SDL_PROP_IOSTREAM_DYNAMIC_MEMORY_POINTER,
+    },
+    pixels::{SDL_PixelFormat, SDL_BITSPERPIXEL, SDL_BYTESPERPIXEL, SDL_ISPIXELFORMAT_FOURCC},
+    properties::SDL_GetPointerProperty,
+    surface::{
+        SDL_BlitSurface, SDL_BlitSurface9Grid, SDL_BlitSurfaceScaled, SDL_ClearSurface,
+        SDL_ConvertSurface, SDL_CreateSurface, SDL_DestroySurface, SDL_DuplicateSurface,
+        SDL_FillSurfaceRect, SDL_FillSurfaceRects, SDL_FlipMode, SDL_FlipSurface, SDL_LoadBMP,
+        SDL_LoadBMP_IO, SDL_LockSurface, SDL_MapSurfaceRGBA, SDL_ReadSurfacePixel, SDL_SaveBMP,
+        SDL_SaveBMP_IO, SDL_ScaleMode, SDL_ScaleSurface, SDL_SetSurfaceClipRect,
+        SDL_SetSurfaceColorKey, SDL_Surface, SDL_UnlockSurface, SDL_WriteSurfacePixel,
+    },
+};
+
+#[inline(always)]
+fn opt_rect(rect: Option<&Rect>) -> *const sdl3_sys::rect::SDL_Rect {
+    rect.map(Rect::as_sdl).unwrap_or(ptr::null())
+}
+
+fn check_rect(bounds: &Rect, rect: Option<Rect>) -> Result<Option<Rect>> {
+    match rect {
+        Some(rect) if rect.w < 0 || rect.h < 0 || !bounds.contains_rect(&rect) => {
+            Err(Error::new("rect is outside of the surface"))
+        }
+        _ => Ok(rect),
+    }
+}
+
+// get the corner sizes of a 9-grid as whole pixels, if they fit in `area`
+fn check_9grid(grid: &NineGrid, area: &Rect) -> Result<(i32, i32, i32, i32)> {
+    let (l, r) = (grid.left_width as i32, grid.right_width as i32);
+    let (t, b) = (grid.top_height as i32, grid.bottom_height as i32);
+    let fits = |a: i32, b: i32, size: i32| {
+        a >= 0 && b >= 0 && a.checked_add(b).is_some_and(|sum| sum <= size)
+    };
+    if fits(l, r, area.w) && fits(t, b, area.h) {
+        Ok((l, r, t, b))
+    } else {
+        Err(Error::new("9-grid corners don't fit in the source rect"))
+    }
+}
+
+/// An owned surface. The surface is destroyed when this is dropped.
+pub struct Surface {
+    ptr: NonNull<SDL_Surface>,
+}
+
+// SDL surfaces aren't tied to the thread that created them
+unsafe impl Send for Surface {}
+
+impl Surface {
+    /// Create a new surface. The pixels are initialized to zero. See [`SDL_CreateSurface`].
+    pub fn new(width: i32, height: i32, format: SDL_PixelFormat) -> Result<Self> {
+        unsafe { Self::from_created(SDL_CreateSurface(width, height, format)) }
+    }
+
+    /// Load a BMP image from a file.
+    pub fn load_bmp(path: &CStr) -> Result<Self> {
+        unsafe { Self::from_created(SDL_LoadBMP(path.as_ptr())) }
+    }
+
+    /// Load a BMP image from memory.
+    pub fn load_bmp_from_bytes(bytes: &[u8]) -> Result<Self> {
+        let io = unsafe { SDL_IOFromConstMem(bytes.as_ptr() as *const c_void, bytes.len()) };
+        if io.is_null() {
+            return Err(Error::from_sdl());
+        }
+        // SDL_LoadBMP_IO closes the stream
+        unsafe { Self::from_created(SDL_LoadBMP_IO(io, true)) }
+    }
+
+    pub(crate) unsafe fn from_created(ptr: *mut SDL_Surface) -> Result<Self> {
+        match NonNull::new(ptr) {
+            Some(ptr) => Ok(unsafe { Self::from_raw(ptr) }),
+            None => Err(Error::from_sdl()),
+        }
+    }
+
+    /// Take ownership of a raw surface.
+    ///
+    /// # Safety
+    /// `ptr` must be a valid surface that isn't owned by anything else
+    #[inline(always)]
+    pub unsafe fn from_raw(ptr: NonNull<SDL_Surface>) -> Self {
+        Self { ptr }
+    }
+
+    /// Give up ownership of the raw surface. It won't be destroyed automatically.
+    #[inline]
+    pub fn into_raw(self) -> *mut SDL_Surface {
+        let ptr = self.ptr.as_ptr();
+        core::mem::forget(self);
+        ptr
+    }
+
+    /// Get the raw surface pointer.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *mut SDL_Surface {
+        self.ptr.as_ptr()
+    }
+
+    #[inline(always)]
+    fn raw(&self) -> &SDL_Surface {
+        unsafe { self.ptr.as_ref() }
+    }
+
+    /// The width of the surface in pixels.
+    #[inline]
+    pub fn width(&self) -> i32 {
+        self.raw().w
+    }
+
+    /// The height of the surface in pixels.
+    #[inline]
+    pub fn height(&self) -> i32 {
+        self.raw().h
+    }
+
+    /// The pixel format of the surface.
+    #[inline]
+    pub fn format(&self) -> SDL_PixelFormat {
+        self.raw().format
+    }
+
+    /// The number of bytes between the start of each row.
+    #[inline]
+    pub fn pitch(&self) -> usize {
+        self.raw().pitch as usize
+    }
+
+    /// A rect covering the whole surface.
+    #[inline]
+    pub fn rect(&self) -> Rect {
+        Rect::new(0, 0, self.width(), self.height())
+    }
+
+    fn checked_rect(&self, rect: Option<Rect>) -> Result<Option<Rect>> {
+        check_rect(&self.rect(), rect)
+    }
+
+    /// Save the surface to a file in BMP format.
+    pub fn save_bmp(&self, path: &CStr) -> Result<()> {
+        check(unsafe { SDL_SaveBMP(self.as_ptr(), path.as_ptr()) })
+    }
+
+    /// Save the surface in BMP format to a `Vec`.
+    pub fn save_bmp_to_vec(&self) -> Result<Vec<u8>> {
+        let io = unsafe { SDL_IOFromDynamicMem() };
+        if io.is_null() {
+            return Err(Error::from_sdl());
+        }
+        let result = if unsafe { SDL_SaveBMP_IO(self.as_ptr(), io, false) } {
+            let size = unsafe { SDL_GetIOSize(io) };
+            let data = unsafe {
+                SDL_GetPointerProperty(
+                    SDL_GetIOProperties(io),
+                    SDL_PROP_IOSTREAM_DYNAMIC_MEMORY_POINTER,
+                    ptr::null_mut(),
+                )
+            };
+            if size < 0 || (data.is_null() && size != 0) {
+                Err(Error::from_sdl())
+            } else if size == 0 {
+                Ok(Vec::new())
+            } else {
+                Ok(unsafe { slice::from_raw_parts(data as *const u8, size as usize) }.to_vec())
+            }
+        } else {
+            Err(Error::from_sdl())
+        };
+        unsafe { SDL_CloseIO(io) };
+        result
+    }
+
+    /// Lock the surface for direct pixel access. The surface is unlocked when the returned
+    /// guard is dropped.
+    pub fn lock(&mut self) -> Result<SurfaceLock<'_>> {
+        if SDL_ISPIXELFORMAT_FOURCC(self.format()) {
+            return Err(Error::new("can't lock a surface with a FOURCC format"));
+        }
+        check(unsafe { SDL_LockSurface(self.as_ptr()) })?;
+        let raw = self.raw();
+        let bpp = SDL_BYTESPERPIXEL(raw.format) as usize;
+        let pitch = raw.pitch as usize;
+        let (w, h) = (raw.w as usize, raw.h as usize);
+        // packed formats like INDEX1 have less than one byte per pixel
+        let row_len = (w * SDL_BITSPERPIXEL(raw.format) as usize).div_ceil(8);
+        let len = if w == 0 || h == 0 {
+            0
+        } else {
+            pitch * (h - 1) + row_len
+        };
+        let pixels = if len == 0 {
+            &mut []
+        } else {
+            unsafe { slice::from_raw_parts_mut(raw.pixels as *mut u8, len) }
+        };
+        Ok(SurfaceLock {
+            surface: self.as_ptr(),
+            pixels,
+            pitch,
+            row_len,
+            bytes_per_pixel: bpp,
+            _surface: PhantomData,
+        })
+    }
+
+    /// Map a color to a pixel value for this surface's format. See [`SDL_MapSurfaceRGBA`].
+    pub fn map_color(&self, color: Color) -> u32 {
+        unsafe { SDL_MapSurfaceRGBA(self.as_ptr(), color.r, color.g, color.b, color.a) }
+    }
+
+    /// Read the color of a single pixel. This is slow; use [`Surface::lock`] for bulk access.
+    pub fn read_pixel(&self, x: i32, y: i32) -> Result<Color> {
+        let mut c = Color::default();
+        check(unsafe {
+            SDL_ReadSurfacePixel(self.as_ptr(), x, y, &mut c.r, &mut c.g, &mut c.b, &mut c.a)
+        })?;
+        Ok(c)
+    }
+
+    /// Write the color of a single pixel. This is slow; use [`Surface::lock`] for bulk access.
+    pub fn write_pixel(&mut self, x: i32, y: i32, color: Color) -> Result<()> {
+        check(unsafe {
+            SDL_WriteSurfacePixel(self.as_ptr(), x, y, color.r, color.g, color.b, color.a)
+        })
+    }
+
+    /// Set the color that's treated as transparent when blitting, or disable it with `None`.
+    pub fn set_color_key(&mut self, key: Option<Color>) -> Result<()> {
+        let pixel = key.map(|c| self.map_color(c)).unwrap_or(0);
+        check(unsafe { SDL_SetSurfaceColorKey(self.as_ptr(), key.is_some(), pixel) })
+    }
+
+    /// Set the clip rect used when blitting to this surface, or disable clipping with `None`.
+    pub fn set_clip_rect(&mut self, rect: Option<Rect>) -> Result<()> {
+        check(unsafe { SDL_SetSurfaceClipRect(self.as_ptr(), opt_rect(rect.as_ref())) })
+    }
+
+    /// Clear the whole surface with a color, ignoring the clip rect.
+    pub fn clear(&mut self, color: FColor) -> Result<()> {
+        check(unsafe { SDL_ClearSurface(self.as_ptr(), color.r, color.g, color.b, color.a) })
+    }
+
+    /// Fill a rect with a color, or the whole surface if `rect` is `None`.
+    pub fn fill_rect(&mut self, rect: Option<Rect>, color: Color) -> Result<()> {
+        let rect = self.checked_rect(rect)?;
+        let pixel = self.map_color(color);
+        check(unsafe { SDL_FillSurfaceRect(self.as_ptr(), opt_rect(rect.as_ref()), pixel) })
+    }
+
+    /// Fill multiple rects with a color.
+    pub fn fill_rects(&mut self, rects: &[Rect], color: Color) -> Result<()> {
+        for rect in rects.iter() {
+            self.checked_rect(Some(*rect))?;
+        }
+        let pixel = self.map_color(color);
+        check(unsafe {
+            SDL_FillSurfaceRects(
+                self.as_ptr(),
+                Rect::slice_as_sdl(rects),
+                len_to_c_int(rects.len())?,
+                pixel,
+            )
+        })
+    }
+
+    /// Flip the surface in place.
+    pub fn flip(&mut self, flip: SDL_FlipMode) -> Result<()> {
+        check(unsafe { SDL_FlipSurface(self.as_ptr(), flip) })
+    }
+
+    /// Create a copy of this surface.
+    pub fn duplicate(&self) -> Result<Surface> {
+        unsafe { Self::from_created(SDL_DuplicateSurface(self.as_ptr())) }
+    }
+
+    /// Create a scaled copy of this surface.
+    pub fn scale(&self, width: i32, height: i32, mode: SDL_ScaleMode) -> Result<Surface> {
+        unsafe { Self::from_created(SDL_ScaleSurface(self.as_ptr(), width, height, mode)) }
+    }
+
+    /// Create a copy of this surface converted to another pixel format.
+    pub fn convert(&self, format: SDL_PixelFormat) -> Result<Surface> {
+        unsafe { Self::from_created(SDL_ConvertSurface(self.as_ptr(), format)) }
+    }
+
+    /// Copy (part of) this surface to `dst` at `dst_pos`, without scaling.
+    ///
+    /// `None` for `src_rect` copies the whole surface, and `None` for `dst_pos` copies to
+    /// the top left corner. The copy is clipped to the clip rect of `dst`.
+    pub fn blit(
+        &self,
+        src_rect: Option<Rect>,
+        dst: &mut Surface,
+        dst_pos: Option<Point>,
+    ) -> Result<()> {
+        let src_rect = self.checked_rect(src_rect)?;
+        let pos = dst_pos.unwrap_or_default();
+        // only the position is used
+        let dst_rect = Rect::new(pos.x, pos.y, 0, 0);
+        check(unsafe {
+            SDL_BlitSurface(
+                self.as_ptr(),
+                opt_rect(src_rect.as_ref()),
+                dst.as_ptr(),
+                dst_rect.as_sdl(),
+            )
+        })
+    }
+
+    /// Copy (part of) this surface to (part of) `dst`, scaling it to fit.
+    ///
+    /// `None` means the whole surface for both `src_rect` and `dst_rect`.
+    pub fn blit_scaled(
+        &self,
+        src_rect: Option<Rect>,
+        dst: &mut Surface,
+        dst_rect: Option<Rect>,
+        mode: SDL_ScaleMode,
+    ) -> Result<()> {
+        let src_rect = self.checked_rect(src_rect)?;
+        let dst_rect = dst.checked_rect(dst_rect)?;
+        check(unsafe {
+            SDL_BlitSurfaceScaled(
+                self.as_ptr(),
+                opt_rect(src_rect.as_ref()),
+                dst.as_ptr(),
+                opt_rect(dst_rect.as_ref()),
+                mode,
+            )
+        })
+    }
+
+    /// Copy (part of) this surface to (part of) `dst` using 9-grid scaling.
+    ///
+    /// The corner sizes in `grid` are truncated to whole pixels.
+    pub fn blit_9grid(
+        &self,
+        src_rect: Option<Rect>,
+        grid: NineGrid,
+        mode: SDL_ScaleMode,
+        dst: &mut Surface,
+        dst_rect: Option<Rect>,
+    ) -> Result<()> {
+        let src_rect = self.checked_rect(src_rect)?;
+        let dst_rect = dst.checked_rect(dst_rect)?;
+        let area = src_rect.unwrap_or(self.rect());
+        let (l, r, t, b) = check_9grid(&grid, &area)?;
+        check(unsafe {
+            SDL_BlitSurface9Grid(
+                self.as_ptr(),
+                opt_rect(src_rect.as_ref()),
+                l,
+                r,
+                t,
+                b,
+                grid.scale,
+                mode,
+                dst.as_ptr(),
+                opt_rect(dst_rect.as_ref()),
+            )
+        })
+    }
+}
+
+impl Drop for Surface {
+    fn drop(&mut self) {
+        unsafe { SDL_DestroySurface(self.as_ptr()) };
+    }
+}
+
+mod sealed {
+    pub trait Pixel {}
+}
+
+/// Types that can be used to view the pixels of a locked surface.
+///
+/// | Type | Bytes per pixel | Example formats |
+/// | ---- | --------------- | --------------- |
+/// | `u8` | 1 | `INDEX8`, `RGB332` |
+/// | `u16` | 2 | `RGB565`, `ARGB4444` (native endian packed formats) |
+/// | `[u8; 3]` | 3 | `RGB24`, `BGR24` |
+/// | `u32` | 4 | `ARGB8888`, `XRGB8888` (native endian packed formats) |
+/// | `[u8; 4]` | 4 | `RGBA32`, `BGRA32` (byte order formats) |
+pub trait Pixel: sealed::Pixel + Copy + 'static {}
+
+macro_rules! impl_pixel {
+    ($($ty:ty),*) => { $(
+        impl sealed::Pixel for $ty {}
+        impl Pixel for $ty {}
+    )* };
+}
+
+impl_pixel!(u8, u16, [u8; 3], u32, [u8; 4]);
+
+/// Guard returned from [`Surface::lock`]. Unlocks the surface when dropped.
+pub struct SurfaceLock<'a> {
+    surface: *mut SDL_Surface,
+    pixels: &'a mut [u8],
+    pitch: usize,
+    row_len: usize,
+    bytes_per_pixel: usize,
+    _surface: PhantomData<&'a mut Surface>,
+}
+
+impl SurfaceLock<'_> {
+    /// The number of bytes between the start of each row.
+    #[inline(always)]
+    pub fn pitch(&self) -> usize {
+        self.pitch
+    }
+
+    /// All pixels, including any padding between rows.
+    #[inline(always)]
+    pub fn pixels(&self) -> &[u8] {
+        self.pixels
+    }
+
+    /// All pixels, including any padding between rows.
+    #[inline(always)]
+    pub fn pixels_mut(&mut self) -> &mut [u8] {
+        self.pixels
+    }
+
+    /// The pixels in row `y`, without padding.
+    pub fn row(&self, y: usize) -> Option<&[u8]> {
+        let start = y.checked_mul(self.pitch)?;
+        self.pixels.get(start..start.checked_add(self.row_len)?)
+    }
+
+    /// The pixels in row `y`, without padding.
+    pub fn row_mut(&mut self, y: usize) -> Option<&mut [u8]> {
+        let start = y.checked_mul(self.pitch)?;
+        self.pixels.get_mut(start..start.checked_add(self.row_len)?)
+    }
+
+    /// Iterate over the rows of the surface, without padding.
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [u8]> {
+        let row_len = self.row_len;
+        self.pixels
+            .chunks_mut(self.pitch.max(1))
+            .map(move |row| &mut row[..row_len])
+    }
+
+    fn check_view<P: Pixel>(&self) -> Result<()> {
+        if size_of::<P>() != self.bytes_per_pixel {
+            Err(Error::new("pixel type doesn't match the surface format"))
+        } else if self.pixels.as_ptr().align_offset(align_of::<P>()) != 0
+            || self.pitch & (align_of::<P>() - 1) != 0
+        {
+            Err(Error::new(
+                "surface pixels aren't aligned for the pixel type",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// View row `y` as pixels of type `P`. See [`Pixel`].
+    ///
+    /// Returns `Ok(None)` if `y` is out of range, and an error if `P` doesn't match the
+    /// surface format.
+    pub fn row_as<P: Pixel>(&self, y: usize) -> Result<Option<&[P]>> {
+        self.check_view::<P>()?;
+        Ok(self.row(y).map(|row| unsafe {
+            slice::from_raw_parts(row.as_ptr() as *const P, row.len() / size_of::<P>())
+        }))
+    }
+
+    /// View row `y` as mutable pixels of type `P`. See [`Pixel`].
+    ///
+    /// Returns `Ok(None)` if `y` is out of range, and an error if `P` doesn't match the
+    /// surface format.
+    pub fn row_as_mut<P: Pixel>(&mut self, y: usize) -> Result<Option<&mut [P]>> {
+        self.check_view::<P>()?;
+        Ok(self.row_mut(y).map(|row| unsafe {
+            slice::from_raw_parts_mut(row.as_mut_ptr() as *mut P, row.len() / size_of::<P>())
+        }))
+    }
+
+    /// Get the pixel at (`x`, `y`) as type `P`.
+    pub fn get<P: Pixel>(&self, x: usize, y: usize) -> Result<Option<P>> {
+        Ok(self.row_as::<P>(y)?.and_then(|row| row.get(x).copied()))
+    }
+
+    /// Set the pixel at (`x`, `y`) as type `P`. Returns `Ok(false)` if out of range.
+    pub fn set<P: Pixel>(&mut self, x: usize, y: usize, value: P) -> Result<bool> {
+        Ok(
+            match self.row_as_mut::<P>(y)?.and_then(|row| row.get_mut(x)) {
+                Some(pixel) => {
+                    *pixel = value;
+                    true
+                }
+                None => false,
+            },
+        )
+    }
+}
+
+impl Drop for SurfaceLock<'_> {
+    fn drop(&mut self) {
+        unsafe { SDL_UnlockSurface(self.surface) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(left: f32, right: f32, top: f32, bottom: f32) -> NineGrid {
+        NineGrid {
+            left_width: left,
+            right_width: right,
+            top_height: top,
+            bottom_height: bottom,
+            scale: 0.0,
+        }
+    }
+
+    #[test]
+    fn rects() {
+        let bounds = Rect::new(0, 0, 10, 20);
+        assert!(check_rect(&bounds, None).unwrap().is_none());
+        for rect in [
+            bounds,
+            Rect::new(0, 0, 0, 0),
+            Rect::new(10, 20, 0, 0),
+            Rect::new(2, 3, 8, 17),
+        ] {
+            assert_eq!(check_rect(&bounds, Some(rect)).unwrap(), Some(rect));
+        }
+        for rect in [
+            Rect::new(-1, 0, 5, 5),
+            Rect::new(0, -1, 5, 5),
+            Rect::new(1, 0, 10, 20),
+            Rect::new(0, 1, 10, 20),
+            Rect::new(5, 5, -1, 1),
+            Rect::new(5, 5, 1, -1),
+            Rect::new(11, 0, 0, 0),
+            Rect::new(i32::MAX, 0, i32::MAX, 1),
+            Rect::new(0, 0, i32::MAX, i32::MAX),
+        ] {
+            assert!(check_rect(&bounds, Some(rect)).is_err(), "{rect:?}");
+        }
+    }
+
+    #[test]
+    fn nine_grid() {
+        let area = Rect::new(5, 5, 10, 20);
+        assert_eq!(
+            check_9grid(&grid(1.0, 2.0, 3.0, 4.0), &area).unwrap(),
+            (1, 2, 3, 4)
+        );
+        // sizes are truncated
+        assert_eq!(
+            check_9grid(&grid(1.9, 2.5, 0.1, 4.99), &area).unwrap(),
+            (1, 2, 0, 4)
+        );
+        assert_eq!(
+            check_9grid(&grid(5.0, 5.0, 10.0, 10.0), &area).unwrap(),
+            (5, 5, 10, 10)
+        );
+        assert_eq!(
+            check_9grid(&grid(0.0, 0.0, 0.0, 0.0), &Rect::new(0, 0, 0, 0)).unwrap(),
+            (0, 0, 0, 0)
+        );
+        for grid in [
+            grid(6.0, 5.0, 0.0, 0.0),
+            grid(0.0, 0.0, 10.0, 11.0),
+            grid(-1.0, 0.0, 0.0, 0.0),
+            grid(0.0, 0.0, 0.0, -1.0),
+            grid(f32::MAX, f32::MAX, 0.0, 0.0),
+            grid(0.0, 0.0, i32::MAX as f32, 1.0),
+        ] {
+            assert!(check_9grid(&grid, &area).is_err(), "{grid:?}");
+        }
+        let huge = Rect::new(0, 0, i32::MAX, i32::MAX);
+        assert!(check_9grid(&grid(f32::MAX, 1.0, 0.0, 0.0), &huge).is_err());
+        assert!(check_9grid(&grid(f32::MAX, 0.0, 0.0, 0.0), &huge).is_ok());
+    }
+
+    fn filled(w: i32, h: i32, color: Color) -> Surface {
+        let mut surface = Surface::new(w, h, SDL_PixelFormat::RGBA32).unwrap();
+        surface.fill_rect(None, color).unwrap();
+        surface
+    }
+
+    #[test]
+    fn lock_rows() {
+        let mut surface = Surface::new(3, 2, SDL_PixelFormat::RGBA32).unwrap();
+        {
+            let mut lock = surface.lock().unwrap();
+            assert!(lock.pitch() >= 12);
+            assert_eq!(lock.pixels().len(), lock.pitch() + 12);
+            assert_eq!(lock.row(1).unwrap().len(), 12);
+            assert!(lock.row(2).is_none());
+            assert!(lock.row_as::<u16>(0).is_err());
+            assert!(lock.set(2, 1, [1u8, 2, 3, 4]).unwrap());
+            assert!(!lock.set(3, 1, [0u8; 4]).unwrap());
+            assert_eq!(lock.get::<[u8; 4]>(2, 1).unwrap(), Some([1, 2, 3, 4]));
+            assert_eq!(lock.rows_mut().count(), 2);
+            lock.row_mut(0).unwrap()[..4].copy_from_slice(&[255, 0, 0, 255]);
+        }
+        assert_eq!(surface.read_pixel(0, 0).unwrap(), Color::RED);
+        assert_eq!(surface.read_pixel(2, 1).unwrap(), Color::rgba(1, 2, 3, 4));
+
+        let mut surface = Surface::new(10, 2, SDL_PixelFormat::INDEX1MSB).unwrap();
+        let lock = surface.lock().unwrap();
+        assert_eq!(lock.row(0).unwrap().len(), 2);
+        assert_eq!(lock.pixels().len(), lock.pitch() + 2);
+        assert!(lock.row_as::<u8>(0).is_err());
+    }
+
+    #[test]
+    fn fill() {
+        let mut surface = filled(4, 4, Color::BLACK);
+        surface
+            .fill_rect(Some(Rect::new(1, 1, 2, 2)), Color::BLUE)
+            .unwrap();
+        assert_eq!(surface.read_pixel(1, 1).unwrap(), Color::BLUE);
+        assert_eq!(surface.read_pixel(2, 2).unwrap(), Color::BLUE);
+        assert_eq!(surface.read_pixel(0, 0).unwrap(), Color::BLACK);
+        assert_eq!(surface.read_pixel(3, 2).unwrap(), Color::BLACK);
+        assert!(surface
+            .fill_rect(Some(Rect::new(3, 3, 2, 2)), Color::RED)
+            .is_err());
+        assert_eq!(surface.read_pixel(3, 3).unwrap(), Color::BLACK);
+    }
+
+    #[test]
+    fn blit_clipping() {
+        let src = filled(2, 2, Color::RED);
+        let mut dst = filled(4, 4, Color::BLACK);
+        src.blit(None, &mut dst, Some(Point::new(3, 3))).unwrap();
+        assert_eq!(dst.read_pixel(3, 3).unwrap(), Color::RED);
+        assert_eq!(dst.read_pixel(2, 2).unwrap(), Color::BLACK);
+
+        let mut dst = filled(4, 4, Color::BLACK);
+        src.blit(None, &mut dst, Some(Point::new(-1, -1))).unwrap();
+        assert_eq!(dst.read_pixel(0, 0).unwrap(), Color::RED);
+        assert_eq!(dst.read_pixel(1, 1).unwrap(), Color::BLACK);
+
+        let mut dst = filled(4, 4, Color::BLACK);
+        dst.set_clip_rect(Some(Rect::new(0, 0, 2, 2))).unwrap();
+        src.blit(None, &mut dst, Some(Point::new(1, 1))).unwrap();
+        assert_eq!(dst.read_pixel(1, 1).unwrap(), Color::RED);
+        assert_eq!(dst.read_pixel(2, 2).unwrap(), Color::BLACK);
+        assert_eq!(dst.read_pixel(2, 1).unwrap(), Color::BLACK);
+
+        assert!(src
+            .blit(Some(Rect::new(1, 1, 2, 2)), &mut dst, None)
+            .is_err());
+    }
+
+    #[test]
+    fn convert() {
+        let mut surface = filled(2, 1, Color::GREEN);
+        surface.write_pixel(1, 0, Color::rgb(10, 20, 30)).unwrap();
+        let converted = surface.convert(SDL_PixelFormat::ARGB8888).unwrap();
+        assert!(converted.format() == SDL_PixelFormat::ARGB8888);
+        assert_eq!((converted.width(), converted.height()), (2, 1));
+        assert_eq!(converted.read_pixel(0, 0).unwrap(), Color::GREEN);
+        assert_eq!(converted.read_pixel(1, 0).unwrap(), Color::rgb(10, 20, 30));
+    }
+
+    #[test]
+    fn bmp_round_trip() {
+        let mut surface = filled(3, 2, Color::WHITE);
+        surface.write_pixel(0, 0, Color::RED).unwrap();
+        surface.write_pixel(2, 1, Color::rgb(1, 2, 3)).unwrap();
+        let bmp = surface.save_bmp_to_vec().unwrap();
+        assert!(bmp.starts_with(b"BM"));
+        let loaded = Surface::load_bmp_from_bytes(&bmp).unwrap();
+        assert_eq!((loaded.width(), loaded.height()), (3, 2));
+        for y in 0..2 {
+            for x in 0..3 {
+                assert_eq!(
+                    loaded.read_pixel(x, y).unwrap(),
+                    surface.read_pixel(x, y).unwrap(),
+                    "({x}, {y})"
+                );
+            }
+        }
+        assert!(Surface::load_bmp_from_bytes(&bmp[..20]).is_err());
+    }
+}
@@ -0,0 +1,525 @@
+//! Text input and IME composition for a window.
+//!
+//! A [`TextInputSession`] enables text input events for a window while it's alive. While
+//! the user composes text with an input method, SDL sends `SDL_EVENT_TEXT_EDITING` and
+//! `SDL_EVENT_TEXT_EDITING_CANDIDATES` events; pass them to
+//! [`TextInputSession::handle_event`] and render the in-progress text from
+//! [`TextInputSession::composition`]. The final text arrives in `SDL_EVENT_TEXT_INPUT`
+//! events as usual.
+//!
+//! [`Composition`] is plain state that can be updated from synthetic events too:
+//!
+//! ```
+//! use sdl3_main::text_input::Composition;
+//!
+//! let mut composition = Composition::new();
+//! composition.editing("にほん", 1, 2);
+//! composition.set_candidates(["日本", "二本"], 0, false);
+//! assert_eq!(composition.selection_byte_range(), Some(3..9));
+//! assert_eq!(composition.selected_candidate(), Some("日本"));
+//! composition.commit();
+//! assert!(!composition.is_composing());
+//! ```
+
+use crate::{error::check, properties::Properties, rect::Rect, Result};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{ffi::CStr, fmt, marker::PhantomData, ops::Range, ptr, slice};
+use sdl3_sys::{
+    events::{
+        SDL_Event, SDL_EventType, SDL_TextEditingCandidatesEvent, SDL_TextEditingEvent,
+        SDL_EVENT_TEXT_EDITING, SDL_EVENT_TEXT_EDITING_CANDIDATES, SDL_EVENT_TEXT_INPUT,
+    },
+    keyboard::{
+        SDL_Capitalization, SDL_ClearComposition, SDL_SetTextInputArea, SDL_StartTextInput,
+        SDL_StartTextInputWithProperties, SDL_StopTextInput, SDL_TextInputActive,
+        SDL_TextInputType, SDL_PROP_TEXTINPUT_AUTOCORRECT_BOOLEAN,
+        SDL_PROP_TEXTINPUT_CAPITALIZATION_NUMBER, SDL_PROP_TEXTINPUT_MULTILINE_BOOLEAN,
+        SDL_PROP_TEXTINPUT_TYPE_NUMBER,
+    },
+    video::{SDL_GetWindowID, SDL_Window, SDL_WindowID},
+};
+
+/// Options for [`TextInputSession::start_with`]. Options that aren't set use SDL's defaults.
+/// See [`SDL_StartTextInputWithProperties`].
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct TextInputOptions {
+    input_type: Option<SDL_TextInputType>,
+    capitalization: Option<SDL_Capitalization>,
+    autocorrect: Option<bool>,
+    multiline: Option<bool>,
+}
+
+impl TextInputOptions {
+    /// Create a new `TextInputOptions` with all options unset.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            input_type: None,
+            capitalization: None,
+            autocorrect: None,
+            multiline: None,
+        }
+    }
+
+    /// Set the kind of text to input, which may change the on-screen keyboard.
+    #[inline]
+    pub const fn input_type(mut self, input_type: SDL_TextInputType) -> Self {
+        self.input_type = Some(input_type);
+        self
+    }
+
+    /// Set how text should be capitalized.
+    #[inline]
+    pub const fn capitalization(mut self, capitalization: SDL_Capitalization) -> Self {
+        self.capitalization = Some(capitalization);
+        self
+    }
+
+    /// Enable or disable auto correction.
+    #[inline]
+    pub const fn autocorrect(mut self, autocorrect: bool) -> Self {
+        self.autocorrect = Some(autocorrect);
+        self
+    }
+
+    /// Allow multiple lines of text. This affects what the return key does.
+    #[inline]
+    pub const fn multiline(mut self, multiline: bool) -> Self {
+        self.multiline = Some(multiline);
+        self
+    }
+
+    fn to_properties(self) -> Result<Properties> {
+        let props = Properties::new()?;
+        unsafe {
+            if let Some(input_type) = self.input_type {
+                props.set_number(SDL_PROP_TEXTINPUT_TYPE_NUMBER, input_type.0 as i64)?;
+            }
+            if let Some(capitalization) = self.capitalization {
+                props.set_number(
+                    SDL_PROP_TEXTINPUT_CAPITALIZATION_NUMBER,
+                    capitalization.0 as i64,
+                )?;
+            }
+            if let Some(autocorrect) = self.autocorrect {
+                props.set_boolean(SDL_PROP_TEXTINPUT_AUTOCORRECT_BOOLEAN, autocorrect)?;
+            }
+            if let Some(multiline) = self.multiline {
+                props.set_boolean(SDL_PROP_TEXTINPUT_MULTILINE_BOOLEAN, multiline)?;
+            }
+        }
+        Ok(props)
+    }
+}
+
+impl fmt::Debug for TextInputOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TextInputOptions")
+            .field("input_type", &self.input_type.map(|t| t.0))
+            .field("capitalization", &self.capitalization.map(|c| c.0))
+            .field("autocorrect", &self.autocorrect)
+            .field("multiline", &self.multiline)
+            .finish()
+    }
+}
+
+/// Text being composed with an input method, and the candidates it offers.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Composition {
+    text: String,
+    cursor: Option<usize>,
+    selection_len: Option<usize>,
+    candidates: Vec<String>,
+    selected_candidate: Option<usize>,
+    horizontal: bool,
+}
+
+impl Composition {
+    /// Create a new empty `Composition`.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            text: String::new(),
+            cursor: None,
+            selection_len: None,
+            candidates: Vec::new(),
+            selected_candidate: None,
+            horizontal: false,
+        }
+    }
+
+    /// Returns true if there's text being composed.
+    #[inline]
+    pub fn is_composing(&self) -> bool {
+        !self.text.is_empty()
+    }
+
+    /// Get the text being composed.
+    #[inline]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Get the cursor position in the composed text, in characters.
+    #[inline]
+    pub fn cursor(&self) -> Option<usize> {
+        self.cursor
+    }
+
+    /// Get the length of the selected text after the cursor, in characters.
+    #[inline]
+    pub fn selection_len(&self) -> Option<usize> {
+        self.selection_len
+    }
+
+    /// Get the byte range of the selected text in [`text`](Self::text), for rendering.
+    /// Returns an empty range at the cursor if there's a cursor but no selection.
+    pub fn selection_byte_range(&self) -> Option<Range<usize>> {
+        let byte_offset = |chars: usize| {
+            self.text
+                .char_indices()
+                .nth(chars)
+                .map_or(self.text.len(), |(i, _)| i)
+        };
+        let start = self.cursor?;
+        let end = start + self.selection_len.unwrap_or(0);
+        Some(byte_offset(start)..byte_offset(end))
+    }
+
+    /// Get the candidates for the composed text.
+    #[inline]
+    pub fn candidates(&self) -> &[String] {
+        &self.candidates
+    }
+
+    /// Get the index of the selected candidate.
+    #[inline]
+    pub fn selected_candidate_index(&self) -> Option<usize> {
+        self.selected_candidate
+    }
+
+    /// Get the selected candidate.
+    #[inline]
+    pub fn selected_candidate(&self) -> Option<&str> {
+        self.selected_candidate
+            .and_then(|i| self.candidates.get(i))
+            .map(|s| s.as_str())
+    }
+
+    /// Returns true if the candidates should be listed horizontally.
+    #[inline]
+    pub fn is_horizontal(&self) -> bool {
+        self.horizontal
+    }
+
+    /// Update the composed text. `start` and `length` are the cursor and selection length in
+    /// characters, or negative if not set, like in [`SDL_TextEditingEvent`].
+    pub fn editing(&mut self, text: &str, start: i32, length: i32) {
+        self.text.clear();
+        self.text.push_str(text);
+        self.cursor = usize::try_from(start).ok();
+        self.selection_len = usize::try_from(length).ok();
+        if text.is_empty() {
+            self.clear_candidates();
+        }
+    }
+
+    /// Replace the candidates. `selected` is the index of the selected candidate, or
+    /// negative if none is selected, like in [`SDL_TextEditingCandidatesEvent`].
+    pub fn set_candidates<'a>(
+        &mut self,
+        candidates: impl IntoIterator<Item = &'a str>,
+        selected: i32,
+        horizontal: bool,
+    ) {
+        self.candidates.clear();
+        self.candidates
+            .extend(candidates.into_iter().map(|s| s.to_string()));
+        self.selected_candidate = usize::try_from(selected)
+            .ok()
+            .filter(|&i| i < self.candidates.len());
+        self.horizontal = horizontal;
+    }
+
+    /// Clear the candidates.
+    pub fn clear_candidates(&mut self) {
+        self.candidates.clear();
+        self.selected_candidate = None;
+    }
+
+    /// Clear everything. This happens when the composed text is committed as text input.
+    pub fn commit(&mut self) {
+        self.text.clear();
+        self.cursor = None;
+        self.selection_len = None;
+        self.clear_candidates();
+    }
+
+    /// Update the composition from a text editing event.
+    ///
+    /// # Safety
+    /// `event.text` must be null or point to a valid string.
+    pub unsafe fn text_editing(&mut self, event: &SDL_TextEditingEvent) {
+        let text = if event.text.is_null() {
+            Default::default()
+        } else {
+            unsafe { CStr::from_ptr(event.text) }.to_string_lossy()
+        };
+        self.editing(&text, event.start, event.length);
+    }
+
+    /// Update the candidates from a candidates event.
+    ///
+    /// # Safety
+    /// `event.candidates` must be null or point to `event.num_candidates` valid strings.
+    pub unsafe fn text_editing_candidates(&mut self, event: &SDL_TextEditingCandidatesEvent) {
+        let candidates = match usize::try_from(event.num_candidates) {
+            Ok(n) if n > 0 && !event.candidates.is_null() => unsafe {
+                slice::from_raw_parts(event.candidates, n)
+            },
+            _ => &[],
+        };
+        let candidates: Vec<_> = candidates
+            .iter()
+            .filter(|c| !c.is_null())
+            .map(|&c| unsafe { CStr::from_ptr(c) }.to_string_lossy())
+            .collect();
+        self.set_candidates(
+            candidates.iter().map(|c| &**c),
+            event.selected_candidate,
+            event.horizontal,
+        );
+    }
+
+    /// Update the composition from an event. Returns true if the event was a text editing,
+    /// candidates or text input event.
+    ///
+    /// # Safety
+    /// `event` must be a valid event, like the ones you get from SDL.
+    pub unsafe fn handle_event(&mut self, event: &SDL_Event) -> bool {
+        match SDL_EventType(unsafe { event.r#type }) {
+            SDL_EVENT_TEXT_EDITING => unsafe { self.text_editing(&event.edit) },
+            SDL_EVENT_TEXT_EDITING_CANDIDATES => unsafe {
+                self.text_editing_candidates(&event.edit_candidates)
+            },
+            SDL_EVENT_TEXT_INPUT => self.commit(),
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// Text input for a window. Input is started when this is created and stopped when it's
+/// dropped. See [`SDL_StartTextInput`].
+pub struct TextInputSession {
+    window: *mut SDL_Window,
+    window_id: SDL_WindowID,
+    composition: Composition,
+    _not_send_sync: PhantomData<*const ()>,
+}
+
+impl TextInputSession {
+    /// Start text input for a window with default options.
+    ///
+    /// # Safety
+    /// `window` must be a valid window that outlives the session
+    pub unsafe fn start(window: *mut SDL_Window) -> Result<Self> {
+        check(unsafe { SDL_StartTextInput(window) })?;
+        Ok(unsafe { Self::new(window) })
+    }
+
+    /// Start text input for a window with options.
+    /// See [`SDL_StartTextInputWithProperties`].
+    ///
+    /// # Safety
+    /// `window` must be a valid window that outlives the session
+    pub unsafe fn start_with(window: *mut SDL_Window, options: &TextInputOptions) -> Result<Self> {
+        let props = options.to_properties()?;
+        check(unsafe { SDL_StartTextInputWithProperties(window, props.id()) })?;
+        Ok(unsafe { Self::new(window) })
+    }
+
+    unsafe fn new(window: *mut SDL_Window) -> Self {
+        Self {
+            window,
+            window_id: unsafe { SDL_GetWindowID(window) },
+            composition: Composition::new(),
+            _not_send_sync: PhantomData,
+        }
+    }
+
+    /// Get the window this session is for.
+    #[inline(always)]
+    pub fn window(&self) -> *mut SDL_Window {
+        self.window
+    }
+
+    /// Returns true if text input is active for the window. See [`SDL_TextInputActive`].
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        unsafe { SDL_TextInputActive(self.window) }
+    }
+
+    /// Set the area where text is being input, so the input method can place its windows
+    /// next to it. `cursor` is the offset of the cursor from the left edge of `area`.
+    /// See [`SDL_SetTextInputArea`].
+    pub fn set_area(&self, area: Option<Rect>, cursor: i32) -> Result<()> {
+        let area = area.as_ref().map_or(ptr::null(), Rect::as_sdl);
+        check(unsafe { SDL_SetTextInputArea(self.window, area, cursor) })
+    }
+
+    /// Cancel the current composition. See [`SDL_ClearComposition`].
+    pub fn clear_composition(&mut self) -> Result<()> {
+        check(unsafe { SDL_ClearComposition(self.window) })?;
+        self.composition.commit();
+        Ok(())
+    }
+
+    /// Get the current composition.
+    #[inline(always)]
+    pub fn composition(&self) -> &Composition {
+        &self.composition
+    }
+
+    /// Update the composition from an event for this session's window. Returns true if
+    /// the event was handled.
+    ///
+    /// # Safety
+    /// `event` must be a valid event, like the ones you get from SDL.
+    pub unsafe fn handle_event(&mut self, event: &SDL_Event) -> bool {
+        let window_id = match SDL_EventType(unsafe { event.r#type }) {
+            SDL_EVENT_TEXT_EDITING => unsafe { event.edit.windowID },
+            SDL_EVENT_TEXT_EDITING_CANDIDATES => unsafe { event.edit_candidates.windowID },
+            SDL_EVENT_TEXT_INPUT => unsafe { event.text.windowID },
+            _ => return false,
+        };
+        window_id == self.window_id && unsafe { self.composition.handle_event(event) }
+    }
+}
+
+impl Drop for TextInputSession {
+    fn drop(&mut self) {
+        unsafe { SDL_StopTextInput(self.window) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::{
+        ffi::c_char,
+        mem::{self, ManuallyDrop},
+    };
+    use sdl3_sys::events::SDL_EVENT_KEY_DOWN;
+
+    fn editing(window_id: SDL_WindowID, text: &CStr, start: i32, length: i32) -> SDL_Event {
+        let mut event: SDL_Event = unsafe { mem::zeroed() };
+        event.edit.r#type = SDL_EVENT_TEXT_EDITING;
+        event.edit.windowID = window_id;
+        event.edit.text = text.as_ptr();
+        (event.edit.start, event.edit.length) = (start, length);
+        event
+    }
+
+    fn candidates(
+        window_id: SDL_WindowID,
+        candidates: &[*const c_char],
+        selected: i32,
+    ) -> SDL_Event {
+        let mut event: SDL_Event = unsafe { mem::zeroed() };
+        event.edit_candidates.r#type = SDL_EVENT_TEXT_EDITING_CANDIDATES;
+        event.edit_candidates.windowID = window_id;
+        event.edit_candidates.candidates = candidates.as_ptr();
+        event.edit_candidates.num_candidates = candidates.len() as i32;
+        event.edit_candidates.selected_candidate = selected;
+        event.edit_candidates.horizontal = true;
+        event
+    }
+
+    fn text_input(window_id: SDL_WindowID) -> SDL_Event {
+        let mut event: SDL_Event = unsafe { mem::zeroed() };
+        event.text.r#type = SDL_EVENT_TEXT_INPUT;
+        event.text.windowID = window_id;
+        event.text.text = c"日本".as_ptr();
+        event
+    }
+
+    #[test]
+    fn composition_events() {
+        let mut composition = Composition::new();
+        assert!(unsafe { composition.handle_event(&editing(1, c"にほんご", 1, 2)) });
+        assert_eq!(composition.text(), "にほんご");
+        assert_eq!(composition.cursor(), Some(1));
+        assert_eq!(composition.selection_len(), Some(2));
+        assert_eq!(composition.selection_byte_range(), Some(3..9));
+
+        let list = [c"日本語".as_ptr(), ptr::null(), c"二本".as_ptr()];
+        assert!(unsafe { composition.handle_event(&candidates(1, &list, 1)) });
+        // null entries are skipped
+        assert_eq!(composition.candidates(), ["日本語", "二本"]);
+        assert_eq!(composition.selected_candidate(), Some("二本"));
+        assert!(composition.is_horizontal());
+
+        assert!(unsafe { composition.handle_event(&candidates(1, &list, 5)) });
+        assert_eq!(composition.selected_candidate_index(), None);
+        assert_eq!(composition.selected_candidate(), None);
+        assert!(unsafe { composition.handle_event(&candidates(1, &list, -1)) });
+        assert_eq!(composition.selected_candidate_index(), None);
+
+        let mut event = candidates(1, &list, 0);
+        event.edit_candidates.candidates = ptr::null();
+        assert!(unsafe { composition.handle_event(&event) });
+        assert!(composition.candidates().is_empty());
+
+        // negative start and length mean there's no cursor or selection
+        assert!(unsafe { composition.handle_event(&editing(1, c"かな", -1, -1)) });
+        assert_eq!(composition.cursor(), None);
+        assert_eq!(composition.selection_len(), None);
+        assert_eq!(composition.selection_byte_range(), None);
+        assert!(unsafe { composition.handle_event(&editing(1, c"かな", 2, -1)) });
+        assert_eq!(composition.selection_byte_range(), Some(6..6));
+        // positions past the end are clamped to the end of the text
+        assert!(unsafe { composition.handle_event(&editing(1, c"かな", 1, 5)) });
+        assert_eq!(composition.selection_byte_range(), Some(3..6));
+
+        assert!(unsafe { composition.handle_event(&candidates(1, &list, 0)) });
+        assert!(unsafe { composition.handle_event(&text_input(1)) });
+        assert!(!composition.is_composing());
+        assert_eq!(composition.cursor(), None);
+        assert!(composition.candidates().is_empty());
+        assert_eq!(composition.selected_candidate(), None);
+
+        let mut event: SDL_Event = unsafe { mem::zeroed() };
+        event.r#type = SDL_EVENT_KEY_DOWN.0;
+        assert!(!unsafe { composition.handle_event(&event) });
+    }
+
+    #[test]
+    fn session_filters_windows() {
+        // never started, so it must not be dropped
+        let mut session = ManuallyDrop::new(TextInputSession {
+            window: ptr::null_mut(),
+            window_id: 7,
+            composition: Composition::new(),
+            _not_send_sync: PhantomData,
+        });
+        assert!(!unsafe { session.handle_event(&editing(8, c"a", 0, 0)) });
+        assert!(!session.composition().is_composing());
+        assert!(unsafe { session.handle_event(&editing(7, c"a", 0, 0)) });
+        assert_eq!(session.composition().text(), "a");
+
+        let list = [c"A".as_ptr()];
+        assert!(!unsafe { session.handle_event(&candidates(8, &list, 0)) });
+        assert!(session.composition().candidates().is_empty());
+        assert!(unsafe { session.handle_event(&candidates(7, &list, 0)) });
+        assert_eq!(session.composition().selected_candidate(), Some("A"));
+
+        assert!(!unsafe { session.handle_event(&text_input(8)) });
+        assert!(session.composition().is_composing());
+        assert!(unsafe { session.handle_event(&text_input(7)) });
+        assert!(!session.composition().is_composing());
+    }
+}
@@ -0,0 +1,442 @@
+//! Typed user events sent through the SDL event queue.
+//!
+//! [`UserEvents::<T>::register`] registers an SDL event type for the Rust type `T`. Values
+//! pushed with [`UserEvents::push`], from any thread, arrive on the event queue and are
+//! turned back into `T` with [`UserEvents::decode`], or by taking an [`Event<T>`] in the
+//! function tagged with [`app_event`](crate::app_event).
+//!
+//! The values are kept on the Rust side and the events only carry a key, so nothing leaks if
+//! an event never reaches `decode`. When events are removed with `SDL_FlushEvents` or an event
+//! filter, their values are dropped by a later [`UserEvents::push`], or by
+//! [`UserEvents::flush`] or [`UserEvents::collect_orphans`].
+//!
+//! ```no_run
+//! use sdl3_main::user_event::{Event, UserEvents};
+//! use sdl3_sys::events::{SDL_Event, SDL_PollEvent};
+//!
+//! struct JobDone {
+//!     id: u32,
+//!     result: Vec<u8>,
+//! }
+//!
+//! let events = UserEvents::<JobDone>::register()?;
+//! let sender = events.clone();
+//! std::thread::spawn(move || {
+//!     let result = vec![1, 2, 3];
+//!     sender.push(JobDone { id: 1, result }).unwrap();
+//! });
+//!
+//! let mut event: SDL_Event = unsafe { core::mem::zeroed() };
+//! while unsafe { SDL_PollEvent(&mut event) } {
+//!     // `Event::<JobDone>` can be taken directly by the `app_event` function instead
+//!     if let Event::User(done) = Event::<JobDone>::from_sdl(&event) {
+//!         println!("job {} produced {} bytes", done.id, done.result.len());
+//!     }
+//! }
+//! # Ok::<(), sdl3_main::Error>(())
+//! ```
+
+use crate::{app::PassEventVal, Error, Result};
+use alloc::{boxed::Box, collections::BTreeMap, sync::Arc};
+use core::{
+    any::{Any, TypeId},
+    ffi::c_void,
+    fmt, mem,
+};
+use sdl3_sys::events::{
+    SDL_Event, SDL_FlushEvent, SDL_HasEvent, SDL_PushEvent, SDL_RegisterEvents, SDL_UserEvent,
+    SDL_EVENT_LAST, SDL_EVENT_USER,
+};
+use std::sync::Mutex;
+
+type Registry = BTreeMap<TypeId, Box<dyn Any + Send + Sync>>;
+
+static REGISTRY: Mutex<Registry> = Mutex::new(BTreeMap::new());
+
+struct Pending<T> {
+    value: T,
+    // false until SDL_PushEvent returns, so cleanup doesn't drop values on their way in
+    pushed: bool,
+    // set when the queue had no events of this type; dropped if that's still the case on
+    // the next push
+    orphaned: bool,
+}
+
+struct State<T> {
+    next_key: usize,
+    pending: BTreeMap<usize, Pending<T>>,
+}
+
+struct Shared<T> {
+    event_type: u32,
+    state: Mutex<State<T>>,
+}
+
+/// A registered user event type carrying values of type `T`.
+///
+/// This is a cheap handle that can be cloned and sent to other threads.
+pub struct UserEvents<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T: Send + 'static> UserEvents<T> {
+    /// Get the event type for `T`, registering it with [`SDL_RegisterEvents`] the first time
+    /// this is called for `T`.
+    ///
+    /// Events of this type that are taken from the queue must be decoded before later events
+    /// of the same type are taken from the queue. Values of events that are removed from the
+    /// queue without being decoded are dropped once the queue has been seen without any events
+    /// of this type on two calls to [`push`](Self::push), so an event that's held on to
+    /// while later events are processed may lose its value.
+    pub fn register() -> Result<Self> {
+        let mut registry = REGISTRY.lock().unwrap();
+        if let Some(events) = registry
+            .get(&TypeId::of::<T>())
+            .and_then(|e| e.downcast_ref::<Self>())
+        {
+            return Ok(events.clone());
+        }
+        let event_type = unsafe { SDL_RegisterEvents(1) };
+        if event_type == 0 {
+            return Err(Error::new("no more user event types available"));
+        }
+        let events = Self {
+            shared: Arc::new(Shared {
+                event_type,
+                state: Mutex::new(State {
+                    // 0 is never used, so events without a key can't match a value
+                    next_key: 1,
+                    pending: BTreeMap::new(),
+                }),
+            }),
+        };
+        registry.insert(TypeId::of::<T>(), Box::new(events.clone()));
+        Ok(events)
+    }
+
+    /// Get the event type for `T` if it has been registered.
+    pub fn get() -> Option<Self> {
+        REGISTRY
+            .lock()
+            .unwrap()
+            .get(&TypeId::of::<T>())
+            .and_then(|e| e.downcast_ref::<Self>())
+            .cloned()
+    }
+
+    /// Get the SDL event type number.
+    #[inline]
+    pub fn event_type(&self) -> u32 {
+        self.shared.event_type
+    }
+
+    /// Push a value onto the event queue. This can be called from any thread.
+    /// See [`SDL_PushEvent`].
+    ///
+    /// If the event is filtered out or can't be pushed, the value is dropped.
+    pub fn push(&self, value: T) -> Result<()> {
+        let key = {
+            let mut state = self.shared.state.lock().unwrap();
+            if !unsafe { SDL_HasEvent(self.shared.event_type) } {
+                // none of the pushed values are on the queue. values that weren't on the
+                // queue at the previous push either have been removed without being decoded
+                state.pending.retain(|_, p| !(p.pushed && p.orphaned));
+                for p in state.pending.values_mut() {
+                    p.orphaned = p.pushed;
+                }
+            }
+            let key = state.next_key;
+            state.next_key = key.checked_add(1).unwrap_or(1);
+            state.pending.insert(
+                key,
+                Pending {
+                    value,
+                    pushed: false,
+                    orphaned: false,
+                },
+            );
+            key
+        };
+
+        // the lock isn't held here, as event watchers run on this thread and may decode
+        let mut event: SDL_Event = unsafe { mem::zeroed() };
+        event.user = SDL_UserEvent {
+            r#type: self.shared.event_type,
+            reserved: 0,
+            timestamp: 0,
+            windowID: 0,
+            code: 0,
+            data1: key as *mut c_void,
+            data2: core::ptr::null_mut(),
+        };
+        let pushed = unsafe { SDL_PushEvent(&mut event) };
+
+        let mut state = self.shared.state.lock().unwrap();
+        if pushed {
+            // it may already have been decoded
+            if let Some(pending) = state.pending.get_mut(&key) {
+                pending.pushed = true;
+            }
+            Ok(())
+        } else {
+            state.pending.remove(&key);
+            Err(Error::from_sdl())
+        }
+    }
+
+    /// Check if `event` is of this event type.
+    #[inline]
+    pub fn is_event(&self, event: &SDL_Event) -> bool {
+        unsafe { event.r#type == self.shared.event_type }
+    }
+
+    /// Take the value carried by `event`, or `None` if it's another type of event or the
+    /// value has already been taken.
+    pub fn decode(&self, event: &SDL_Event) -> Option<T> {
+        if !self.is_event(event) {
+            return None;
+        }
+        let key = unsafe { event.user.data1 } as usize;
+        if key == 0 {
+            return None;
+        }
+        let mut state = self.shared.state.lock().unwrap();
+        state.pending.remove(&key).map(|p| p.value)
+    }
+
+    /// Decode an event, taking the value if it's an event of this type. This is like
+    /// [`Event::from_sdl`], but doesn't have to look up the registered event type.
+    pub fn event(&self, event: &SDL_Event) -> Event<T> {
+        match self.decode(event) {
+            Some(value) => Event::User(value),
+            None => Event::Sdl(*event),
+        }
+    }
+
+    /// Get the number of values that have been pushed but not decoded or dropped.
+    pub fn pending(&self) -> usize {
+        self.shared.state.lock().unwrap().pending.len()
+    }
+
+    /// Remove all events of this type from the queue and drop their values.
+    /// See [`SDL_FlushEvent`].
+    ///
+    /// Events that have already been taken from the queue can't be decoded after this.
+    pub fn flush(&self) {
+        let mut state = self.shared.state.lock().unwrap();
+        unsafe { SDL_FlushEvent(self.shared.event_type) };
+        state.pending.retain(|_, p| !p.pushed);
+    }
+
+    /// Drop the values of events that are no longer on the queue, for example because
+    /// they were removed with `SDL_FlushEvents`. Returns the number of values dropped.
+    ///
+    /// [`push`](Self::push) does this automatically, but it waits until it's safe for
+    /// events that are being processed. This drops the values right away, so call it where no
+    /// events of this type have been taken from the queue without being decoded yet, like at
+    /// the start of an iteration of the main loop. It only does anything when there are no
+    /// events of this type left on the queue.
+    pub fn collect_orphans(&self) -> usize {
+        let mut state = self.shared.state.lock().unwrap();
+        if unsafe { SDL_HasEvent(self.shared.event_type) } {
+            return 0;
+        }
+        let before = state.pending.len();
+        state.pending.retain(|_, p| !p.pushed);
+        before - state.pending.len()
+    }
+}
+
+impl<T> Clone for UserEvents<T> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<T> fmt::Debug for UserEvents<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UserEvents")
+            .field("event_type", &self.shared.event_type)
+            .finish()
+    }
+}
+
+/// An event that's either a user event carrying a `T` or any other event.
+///
+/// This implements [`PassEventVal`], so it can be taken by the function tagged with
+/// [`app_event`](crate::app_event). If `T` hasn't been registered with
+/// [`UserEvents::register`], every event is passed as [`Event::Sdl`].
+pub enum Event<T> {
+    /// A user event registered for `T`
+    User(T),
+
+    /// Any other event
+    Sdl(SDL_Event),
+}
+
+impl<T: Send + 'static> Event<T> {
+    /// Decode an event, taking the value if it's a user event for `T`.
+    ///
+    /// This looks up the registered event type for user events. Use [`UserEvents::event`]
+    /// if you have the [`UserEvents`] at hand.
+    pub fn from_sdl(event: &SDL_Event) -> Self {
+        // only user events need the registry
+        if !(SDL_EVENT_USER.0..SDL_EVENT_LAST.0).contains(&unsafe { event.r#type }) {
+            return Self::Sdl(*event);
+        }
+        match UserEvents::<T>::get() {
+            Some(events) => events.event(event),
+            None => Self::Sdl(*event),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Event<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::User(value) => f.debug_tuple("User").field(value).finish(),
+            Self::Sdl(event) => f
+                .debug_tuple("Sdl")
+                .field(&unsafe { event.r#type })
+                .finish(),
+        }
+    }
+}
+
+impl<T: Send + 'static> PassEventVal for Event<T> {
+    #[inline]
+    fn pass_event_val<R>(event: &mut SDL_Event, f: impl FnOnce(Self) -> R) -> R {
+        f(Self::from_sdl(event))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sdl3_sys::{
+        events::{SDL_FlushEvents, SDL_PeepEvents, SDL_EVENT_QUIT, SDL_GETEVENT},
+        init::{SDL_Init, SDL_QuitSubSystem, SDL_INIT_EVENTS},
+    };
+
+    // each test uses its own event type, so tests running at the same time don't take each
+    // other's events
+    fn take<T: Send + 'static>(events: &UserEvents<T>) -> SDL_Event {
+        let mut event: SDL_Event = unsafe { mem::zeroed() };
+        let t = events.event_type();
+        assert_eq!(
+            unsafe { SDL_PeepEvents(&mut event, 1, SDL_GETEVENT, t, t) },
+            1
+        );
+        event
+    }
+
+    fn with_events(f: impl FnOnce()) {
+        assert!(unsafe { SDL_Init(SDL_INIT_EVENTS) });
+        f();
+        unsafe { SDL_QuitSubSystem(SDL_INIT_EVENTS) };
+    }
+
+    #[test]
+    fn push_and_decode() {
+        #[derive(Debug, PartialEq)]
+        struct Value(u32);
+
+        with_events(|| {
+            let events = UserEvents::<Value>::register().unwrap();
+            let again = UserEvents::<Value>::register().unwrap();
+            assert_eq!(events.event_type(), again.event_type());
+            assert_eq!(
+                UserEvents::<Value>::get().unwrap().event_type(),
+                events.event_type()
+            );
+
+            events.push(Value(1)).unwrap();
+            assert_eq!(events.pending(), 1);
+            let event = take(&events);
+            assert!(events.is_event(&event));
+            assert_eq!(events.decode(&event), Some(Value(1)));
+            assert_eq!(events.decode(&event), None);
+            assert_eq!(events.pending(), 0);
+
+            events.push(Value(2)).unwrap();
+            let event = take(&events);
+            assert!(matches!(
+                Event::<Value>::from_sdl(&event),
+                Event::User(Value(2))
+            ));
+            assert!(matches!(Event::<Value>::from_sdl(&event), Event::Sdl(_)));
+
+            let mut quit: SDL_Event = unsafe { mem::zeroed() };
+            quit.r#type = SDL_EVENT_QUIT.0;
+            assert_eq!(events.decode(&quit), None);
+            assert!(matches!(events.event(&quit), Event::Sdl(_)));
+        });
+    }
+
+    #[test]
+    fn push_from_thread() {
+        struct Value(std::string::String);
+
+        with_events(|| {
+            let events = UserEvents::<Value>::register().unwrap();
+            let sender = events.clone();
+            std::thread::spawn(move || sender.push(Value("hello".into())).unwrap())
+                .join()
+                .unwrap();
+            let event = take(&events);
+            assert_eq!(events.decode(&event).unwrap().0, "hello");
+        });
+    }
+
+    #[test]
+    fn orphans() {
+        struct Value(u32);
+
+        with_events(|| {
+            let events = UserEvents::<Value>::register().unwrap();
+            let t = events.event_type();
+            events.push(Value(1)).unwrap();
+            unsafe { SDL_FlushEvents(t, t) };
+            assert_eq!(events.pending(), 1);
+
+            // the queue is empty, so the flushed value is marked but kept for now
+            events.push(Value(2)).unwrap();
+            assert_eq!(events.pending(), 2);
+            assert_eq!(events.decode(&take(&events)).unwrap().0, 2);
+
+            // still empty on the next push, so the flushed value is dropped
+            events.push(Value(3)).unwrap();
+            assert_eq!(events.pending(), 1);
+            assert_eq!(events.decode(&take(&events)).unwrap().0, 3);
+            assert_eq!(events.pending(), 0);
+
+            events.push(Value(4)).unwrap();
+            unsafe { SDL_FlushEvents(t, t) };
+            assert_eq!(events.collect_orphans(), 1);
+            assert_eq!(events.pending(), 0);
+
+            events.push(Value(5)).unwrap();
+            events.flush();
+            assert_eq!(events.pending(), 0);
+        });
+    }
+
+    #[test]
+    fn taken_event_survives_a_push() {
+        struct Value(u32);
+
+        with_events(|| {
+            let events = UserEvents::<Value>::register().unwrap();
+            events.push(Value(1)).unwrap();
+            let held = take(&events);
+
+            events.push(Value(2)).unwrap();
+            assert_eq!(events.pending(), 2);
+            assert_eq!(events.decode(&held).unwrap().0, 1);
+            assert_eq!(events.decode(&take(&events)).unwrap().0, 2);
+            assert_eq!(events.pending(), 0);
+        });
+    }
+}
@@ -0,0 +1,719 @@
+//! Virtual joysticks and gamepads, for testing input handling without hardware.
+//!
+//! Build a joystick with [`VirtualJoystickBuilder`] and attach it with
+//! [`VirtualJoystickBuilder::attach`]. The returned [`VirtualJoystick`] can then set its
+//! inputs directly, or play back an [`InputScript`].
+//!
+//! Virtual joysticks don't need a video driver, so this works in headless CI. The joystick
+//! subsystem must be initialized (`SDL_INIT_JOYSTICK` or `SDL_INIT_GAMEPAD`).
+//!
+//! ```no_run
+//! use sdl3_main::virtual_joystick::{InputScript, VirtualJoystickBuilder};
+//! use sdl3_sys::gamepad::SDL_GamepadButton;
+//!
+//! let joystick = VirtualJoystickBuilder::gamepad()
+//!     .name("Test Pad")
+//!     .on_rumble(|low, high| {
+//!         println!("rumble {low} {high}");
+//!         true
+//!     })
+//!     .attach()?;
+//!
+//! let mut player = InputScript::new()
+//!     .button(SDL_GamepadButton::SOUTH.0, true)
+//!     .wait(100)
+//!     .button(SDL_GamepadButton::SOUTH.0, false)
+//!     .play();
+//!
+//! // call this with the elapsed time in milliseconds each frame
+//! player.update(&joystick, 50)?;
+//! # Ok::<(), sdl3_main::Error>(())
+//! ```
+
+use crate::{
+    error::{check, guard},
+    pixels::Color,
+    Error, Result,
+};
+use alloc::{boxed::Box, ffi::CString, vec::Vec};
+use core::{
+    ffi::{c_int, c_void},
+    ptr::{self, NonNull},
+    slice,
+};
+use sdl3_sys::{
+    gamepad::{SDL_GamepadAxis, SDL_GamepadButton},
+    joystick::{
+        SDL_AttachVirtualJoystick, SDL_CloseJoystick, SDL_DetachVirtualJoystick, SDL_Joystick,
+        SDL_JoystickID, SDL_JoystickType, SDL_OpenJoystick, SDL_SendJoystickVirtualSensorData,
+        SDL_SetJoystickVirtualAxis, SDL_SetJoystickVirtualBall, SDL_SetJoystickVirtualButton,
+        SDL_SetJoystickVirtualHat, SDL_SetJoystickVirtualTouchpad, SDL_VirtualJoystickDesc,
+        SDL_VirtualJoystickSensorDesc, SDL_VirtualJoystickTouchpadDesc,
+    },
+    sensor::SDL_SensorType,
+};
+
+type Callback<F> = Option<Box<F>>;
+type EffectFn = dyn FnMut(&[u8]) -> bool + Send;
+
+#[derive(Default)]
+struct Callbacks {
+    update: Callback<dyn FnMut() + Send>,
+    set_player_index: Callback<dyn FnMut(i32) + Send>,
+    rumble: Callback<dyn FnMut(u16, u16) -> bool + Send>,
+    rumble_triggers: Callback<dyn FnMut(u16, u16) -> bool + Send>,
+    set_led: Callback<dyn FnMut(Color) -> bool + Send>,
+    send_effect: Callback<EffectFn>,
+    set_sensors_enabled: Callback<dyn FnMut(bool) -> bool + Send>,
+}
+
+macro_rules! callbacks {
+    ($userdata:ident) => {
+        unsafe { &mut *($userdata as *mut Callbacks) }
+    };
+}
+
+extern "C" fn update_cb(userdata: *mut c_void) {
+    if let Some(f) = &mut callbacks!(userdata).update {
+        guard((), f)
+    }
+}
+
+extern "C" fn set_player_index_cb(userdata: *mut c_void, player_index: c_int) {
+    if let Some(f) = &mut callbacks!(userdata).set_player_index {
+        guard((), || f(player_index))
+    }
+}
+
+extern "C" fn rumble_cb(userdata: *mut c_void, low: u16, high: u16) -> bool {
+    match &mut callbacks!(userdata).rumble {
+        Some(f) => guard(false, || f(low, high)),
+        None => false,
+    }
+}
+
+extern "C" fn rumble_triggers_cb(userdata: *mut c_void, left: u16, right: u16) -> bool {
+    match &mut callbacks!(userdata).rumble_triggers {
+        Some(f) => guard(false, || f(left, right)),
+        None => false,
+    }
+}
+
+extern "C" fn set_led_cb(userdata: *mut c_void, r: u8, g: u8, b: u8) -> bool {
+    match &mut callbacks!(userdata).set_led {
+        Some(f) => guard(false, || f(Color::rgb(r, g, b))),
+        None => false,
+    }
+}
+
+extern "C" fn send_effect_cb(userdata: *mut c_void, data: *const c_void, size: c_int) -> bool {
+    match &mut callbacks!(userdata).send_effect {
+        Some(f) => {
+            let data: &[u8] = if data.is_null() || size <= 0 {
+                &[]
+            } else {
+                unsafe { slice::from_raw_parts(data.cast(), size as usize) }
+            };
+            guard(false, || f(data))
+        }
+        None => false,
+    }
+}
+
+extern "C" fn set_sensors_enabled_cb(userdata: *mut c_void, enabled: bool) -> bool {
+    match &mut callbacks!(userdata).set_sensors_enabled {
+        Some(f) => guard(false, || f(enabled)),
+        None => false,
+    }
+}
+
+/// Builder for a virtual joystick. See [`SDL_VirtualJoystickDesc`].
+///
+/// Callbacks may be called on any thread that uses the joystick, so they must be `Send`.
+/// Callbacks that return `bool` report whether the request succeeded; requests without a
+/// callback fail.
+pub struct VirtualJoystickBuilder {
+    joystick_type: SDL_JoystickType,
+    vendor_id: u16,
+    product_id: u16,
+    naxes: u16,
+    nbuttons: u16,
+    nballs: u16,
+    nhats: u16,
+    button_mask: u32,
+    axis_mask: u32,
+    name: Option<CString>,
+    touchpads: Vec<SDL_VirtualJoystickTouchpadDesc>,
+    sensors: Vec<SDL_VirtualJoystickSensorDesc>,
+    callbacks: Box<Callbacks>,
+}
+
+impl VirtualJoystickBuilder {
+    /// Create a builder for a joystick of the given type with no inputs.
+    pub fn new(joystick_type: SDL_JoystickType) -> Self {
+        Self {
+            joystick_type,
+            vendor_id: 0,
+            product_id: 0,
+            naxes: 0,
+            nbuttons: 0,
+            nballs: 0,
+            nhats: 0,
+            button_mask: 0,
+            axis_mask: 0,
+            name: None,
+            touchpads: Vec::new(),
+            sensors: Vec::new(),
+            callbacks: Box::default(),
+        }
+    }
+
+    /// Create a builder for a gamepad with all standard gamepad buttons and axes.
+    ///
+    /// SDL creates a gamepad mapping for it automatically, where joystick button and axis
+    /// indices are the same as the [`SDL_GamepadButton`] and [`SDL_GamepadAxis`] values.
+    pub fn gamepad() -> Self {
+        Self::new(SDL_JoystickType::GAMEPAD)
+            .axes(SDL_GamepadAxis::COUNT.0 as u16)
+            .buttons(SDL_GamepadButton::COUNT.0 as u16)
+    }
+
+    /// Set the name of the joystick.
+    ///
+    /// Names with nul bytes are truncated at the first nul.
+    pub fn name(mut self, name: &str) -> Self {
+        let name = name.split('\0').next().unwrap_or_default();
+        self.name = CString::new(name).ok();
+        self
+    }
+
+    /// Set the USB vendor and product IDs.
+    pub fn vendor_product(mut self, vendor_id: u16, product_id: u16) -> Self {
+        self.vendor_id = vendor_id;
+        self.product_id = product_id;
+        self
+    }
+
+    /// Set the number of axes.
+    pub fn axes(mut self, count: u16) -> Self {
+        self.naxes = count;
+        self
+    }
+
+    /// Set the number of buttons.
+    pub fn buttons(mut self, count: u16) -> Self {
+        self.nbuttons = count;
+        self
+    }
+
+    /// Set the number of trackballs.
+    pub fn balls(mut self, count: u16) -> Self {
+        self.nballs = count;
+        self
+    }
+
+    /// Set the number of hats.
+    pub fn hats(mut self, count: u16) -> Self {
+        self.nhats = count;
+        self
+    }
+
+    /// Set which gamepad buttons are valid, as a mask of `1 << SDL_GamepadButton`.
+    ///
+    /// If this is 0, the first [`buttons`](Self::buttons) gamepad buttons are used.
+    pub fn button_mask(mut self, mask: u32) -> Self {
+        self.button_mask = mask;
+        self
+    }
+
+    /// Set which gamepad axes are valid, as a mask of `1 << SDL_GamepadAxis`.
+    ///
+    /// If this is 0, the first [`axes`](Self::axes) gamepad axes are used.
+    pub fn axis_mask(mut self, mask: u32) -> Self {
+        self.axis_mask = mask;
+        self
+    }
+
+    /// Add a touchpad that supports `fingers` simultaneous fingers.
+    pub fn touchpad(mut self, fingers: u16) -> Self {
+        self.touchpads.push(SDL_VirtualJoystickTouchpadDesc {
+            nfingers: fingers,
+            padding: [0; 3],
+        });
+        self
+    }
+
+    /// Add a sensor. `rate` is the update frequency in Hz, and may be 0.
+    pub fn sensor(mut self, sensor_type: SDL_SensorType, rate: f32) -> Self {
+        self.sensors.push(SDL_VirtualJoystickSensorDesc {
+            r#type: sensor_type,
+            rate,
+        });
+        self
+    }
+
+    /// Set a callback that's called when the joystick state should be updated.
+    pub fn on_update(mut self, f: impl FnMut() + Send + 'static) -> Self {
+        self.callbacks.update = Some(Box::new(f));
+        self
+    }
+
+    /// Set a callback that's called when the player index is set.
+    pub fn on_set_player_index(mut self, f: impl FnMut(i32) + Send + 'static) -> Self {
+        self.callbacks.set_player_index = Some(Box::new(f));
+        self
+    }
+
+    /// Set a callback that implements `SDL_RumbleJoystick`. The arguments are the low and
+    /// high frequency rumble intensities.
+    pub fn on_rumble(mut self, f: impl FnMut(u16, u16) -> bool + Send + 'static) -> Self {
+        self.callbacks.rumble = Some(Box::new(f));
+        self
+    }
+
+    /// Set a callback that implements `SDL_RumbleJoystickTriggers`. The arguments are the
+    /// left and right trigger rumble intensities.
+    pub fn on_rumble_triggers(mut self, f: impl FnMut(u16, u16) -> bool + Send + 'static) -> Self {
+        self.callbacks.rumble_triggers = Some(Box::new(f));
+        self
+    }
+
+    /// Set a callback that implements `SDL_SetJoystickLED`. The alpha of the color is 255.
+    pub fn on_set_led(mut self, f: impl FnMut(Color) -> bool + Send + 'static) -> Self {
+        self.callbacks.set_led = Some(Box::new(f));
+        self
+    }
+
+    /// Set a callback that implements `SDL_SendJoystickEffect`.
+    pub fn on_send_effect(mut self, f: impl FnMut(&[u8]) -> bool + Send + 'static) -> Self {
+        self.callbacks.send_effect = Some(Box::new(f));
+        self
+    }
+
+    /// Set a callback that implements `SDL_SetGamepadSensorEnabled`.
+    pub fn on_set_sensors_enabled(mut self, f: impl FnMut(bool) -> bool + Send + 'static) -> Self {
+        self.callbacks.set_sensors_enabled = Some(Box::new(f));
+        self
+    }
+
+    /// Attach the virtual joystick and open it. See [`SDL_AttachVirtualJoystick`].
+    pub fn attach(self) -> Result<VirtualJoystick> {
+        let mut callbacks = self.callbacks;
+        let userdata = &mut *callbacks as *mut Callbacks as *mut c_void;
+        let cb = &callbacks;
+        let mut desc = SDL_VirtualJoystickDesc::new();
+        desc.r#type = self.joystick_type.0 as u16;
+        desc.vendor_id = self.vendor_id;
+        desc.product_id = self.product_id;
+        desc.naxes = self.naxes;
+        desc.nbuttons = self.nbuttons;
+        desc.nballs = self.nballs;
+        desc.nhats = self.nhats;
+        desc.ntouchpads =
+            u16::try_from(self.touchpads.len()).map_err(|_| Error::new("too many touchpads"))?;
+        desc.nsensors =
+            u16::try_from(self.sensors.len()).map_err(|_| Error::new("too many sensors"))?;
+        desc.button_mask = self.button_mask;
+        desc.axis_mask = self.axis_mask;
+        desc.name = self.name.as_ref().map_or(ptr::null(), |n| n.as_ptr());
+        desc.touchpads = self.touchpads.as_ptr();
+        desc.sensors = self.sensors.as_ptr();
+        desc.userdata = userdata;
+        desc.Update = cb.update.as_ref().map(|_| update_cb as _);
+        desc.SetPlayerIndex = cb
+            .set_player_index
+            .as_ref()
+            .map(|_| set_player_index_cb as _);
+        desc.Rumble = cb.rumble.as_ref().map(|_| rumble_cb as _);
+        desc.RumbleTriggers = cb.rumble_triggers.as_ref().map(|_| rumble_triggers_cb as _);
+        desc.SetLED = cb.set_led.as_ref().map(|_| set_led_cb as _);
+        desc.SendEffect = cb.send_effect.as_ref().map(|_| send_effect_cb as _);
+        desc.SetSensorsEnabled = cb
+            .set_sensors_enabled
+            .as_ref()
+            .map(|_| set_sensors_enabled_cb as _);
+        // the callbacks are owned by `VirtualJoystick` and freed after detaching, so
+        // `Cleanup` isn't needed
+
+        // SDL copies the description, including the name and the touchpad and sensor arrays
+        let id = unsafe { SDL_AttachVirtualJoystick(&desc) };
+        if id == 0 {
+            return Err(Error::from_sdl());
+        }
+        let Some(joystick) = NonNull::new(unsafe { SDL_OpenJoystick(id) }) else {
+            let err = Error::from_sdl();
+            unsafe { SDL_DetachVirtualJoystick(id) };
+            return Err(err);
+        };
+        Ok(VirtualJoystick {
+            id,
+            joystick,
+            _callbacks: callbacks,
+        })
+    }
+}
+
+/// An attached and opened virtual joystick. It's closed and detached when this is dropped.
+///
+/// The joystick subsystem must stay initialized while this exists.
+pub struct VirtualJoystick {
+    id: SDL_JoystickID,
+    joystick: NonNull<SDL_Joystick>,
+    // must be dropped after detaching the joystick
+    _callbacks: Box<Callbacks>,
+}
+
+impl VirtualJoystick {
+    /// Get the instance ID of the joystick.
+    #[inline(always)]
+    pub fn id(&self) -> SDL_JoystickID {
+        self.id
+    }
+
+    /// Get the raw joystick pointer. The joystick is owned by `self`.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *mut SDL_Joystick {
+        self.joystick.as_ptr()
+    }
+
+    /// Set the value of an axis. See [`SDL_SetJoystickVirtualAxis`].
+    pub fn set_axis(&self, axis: i32, value: i16) -> Result<()> {
+        check(unsafe { SDL_SetJoystickVirtualAxis(self.as_ptr(), axis, value) })
+    }
+
+    /// Report relative trackball motion. See [`SDL_SetJoystickVirtualBall`].
+    pub fn set_ball(&self, ball: i32, xrel: i16, yrel: i16) -> Result<()> {
+        check(unsafe { SDL_SetJoystickVirtualBall(self.as_ptr(), ball, xrel, yrel) })
+    }
+
+    /// Set the state of a button. See [`SDL_SetJoystickVirtualButton`].
+    pub fn set_button(&self, button: i32, down: bool) -> Result<()> {
+        check(unsafe { SDL_SetJoystickVirtualButton(self.as_ptr(), button, down) })
+    }
+
+    /// Set the value of a hat (a combination of `SDL_HAT_*` flags). See
+    /// [`SDL_SetJoystickVirtualHat`].
+    pub fn set_hat(&self, hat: i32, value: u8) -> Result<()> {
+        check(unsafe { SDL_SetJoystickVirtualHat(self.as_ptr(), hat, value) })
+    }
+
+    /// Set the state of a finger on a touchpad. See [`SDL_SetJoystickVirtualTouchpad`].
+    ///
+    /// `x` and `y` are normalized to `0.0..=1.0`.
+    pub fn set_touchpad(
+        &self,
+        touchpad: i32,
+        finger: i32,
+        down: bool,
+        x: f32,
+        y: f32,
+        pressure: f32,
+    ) -> Result<()> {
+        check(unsafe {
+            SDL_SetJoystickVirtualTouchpad(self.as_ptr(), touchpad, finger, down, x, y, pressure)
+        })
+    }
+
+    /// Send sensor data. See [`SDL_SendJoystickVirtualSensorData`].
+    pub fn send_sensor_data(
+        &self,
+        sensor_type: SDL_SensorType,
+        sensor_timestamp: u64,
+        data: &[f32],
+    ) -> Result<()> {
+        let len = crate::error::len_to_c_int(data.len())?;
+        check(unsafe {
+            SDL_SendJoystickVirtualSensorData(
+                self.as_ptr(),
+                sensor_type,
+                sensor_timestamp,
+                data.as_ptr(),
+                len,
+            )
+        })
+    }
+
+    /// Apply a single scripted input.
+    pub fn apply(&self, input: &ScriptedInput) -> Result<()> {
+        match *input {
+            ScriptedInput::Axis(axis, value) => self.set_axis(axis, value),
+            ScriptedInput::Ball(ball, xrel, yrel) => self.set_ball(ball, xrel, yrel),
+            ScriptedInput::Button(button, down) => self.set_button(button, down),
+            ScriptedInput::Hat(hat, value) => self.set_hat(hat, value),
+            ScriptedInput::Touchpad {
+                touchpad,
+                finger,
+                down,
+                x,
+                y,
+                pressure,
+            } => self.set_touchpad(touchpad, finger, down, x, y, pressure),
+        }
+    }
+}
+
+impl Drop for VirtualJoystick {
+    fn drop(&mut self) {
+        unsafe {
+            SDL_CloseJoystick(self.as_ptr());
+            SDL_DetachVirtualJoystick(self.id);
+        }
+    }
+}
+
+/// An input change in an [`InputScript`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ScriptedInput {
+    /// Set an axis: `(axis, value)`
+    Axis(i32, i16),
+
+    /// Move a trackball: `(ball, xrel, yrel)`
+    Ball(i32, i16, i16),
+
+    /// Set a button: `(button, down)`
+    Button(i32, bool),
+
+    /// Set a hat: `(hat, value)`
+    Hat(i32, u8),
+
+    /// Set a finger on a touchpad
+    Touchpad {
+        /// Touchpad index
+        touchpad: i32,
+        /// Finger index
+        finger: i32,
+        /// `true` if the finger is touching the touchpad
+        down: bool,
+        /// Normalized x position
+        x: f32,
+        /// Normalized y position
+        y: f32,
+        /// Normalized pressure
+        pressure: f32,
+    },
+}
+
+/// A timed sequence of inputs for a [`VirtualJoystick`].
+///
+/// Inputs are added at the current time of the script, which starts at 0 and is advanced
+/// with [`wait`](Self::wait). Times are in milliseconds, and are relative to when playback
+/// starts, so playback is deterministic regardless of frame timing.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InputScript {
+    steps: Vec<(u64, ScriptedInput)>,
+    cursor: u64,
+}
+
+impl InputScript {
+    /// Create an empty script.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an input at the current time.
+    pub fn input(mut self, input: ScriptedInput) -> Self {
+        self.steps.push((self.cursor, input));
+        self
+    }
+
+    /// Advance the current time by `ms` milliseconds.
+    pub fn wait(mut self, ms: u64) -> Self {
+        self.cursor += ms;
+        self
+    }
+
+    /// Set an axis at the current time.
+    pub fn axis(self, axis: i32, value: i16) -> Self {
+        self.input(ScriptedInput::Axis(axis, value))
+    }
+
+    /// Move a trackball at the current time.
+    pub fn ball(self, ball: i32, xrel: i16, yrel: i16) -> Self {
+        self.input(ScriptedInput::Ball(ball, xrel, yrel))
+    }
+
+    /// Set a button at the current time.
+    pub fn button(self, button: i32, down: bool) -> Self {
+        self.input(ScriptedInput::Button(button, down))
+    }
+
+    /// Press a button at the current time and release it `hold_ms` milliseconds later.
+    /// This advances the current time to the release.
+    pub fn tap(self, button: i32, hold_ms: u64) -> Self {
+        self.button(button, true)
+            .wait(hold_ms)
+            .button(button, false)
+    }
+
+    /// Set a hat at the current time.
+    pub fn hat(self, hat: i32, value: u8) -> Self {
+        self.input(ScriptedInput::Hat(hat, value))
+    }
+
+    /// Get the time of the last input in milliseconds.
+    pub fn duration(&self) -> u64 {
+        self.steps.last().map_or(0, |(t, _)| *t)
+    }
+
+    /// Start playing the script.
+    pub fn play(self) -> InputPlayer {
+        InputPlayer {
+            script: self,
+            next: 0,
+        }
+    }
+}
+
+/// Plays back an [`InputScript`]. Create it with [`InputScript::play`].
+#[derive(Clone, Debug)]
+pub struct InputPlayer {
+    script: InputScript,
+    next: usize,
+}
+
+impl InputPlayer {
+    /// Apply all inputs with a time up to and including `elapsed_ms` that haven't been
+    /// applied yet. `elapsed_ms` is the time since playback started.
+    ///
+    /// Returns `true` if the script has finished.
+    pub fn update(&mut self, joystick: &VirtualJoystick, elapsed_ms: u64) -> Result<bool> {
+        while let Some(input) = self.next_due(elapsed_ms) {
+            joystick.apply(&input)?;
+        }
+        Ok(self.is_finished())
+    }
+
+    fn next_due(&mut self, elapsed_ms: u64) -> Option<ScriptedInput> {
+        let &(t, input) = self.script.steps.get(self.next)?;
+        if t > elapsed_ms {
+            return None;
+        }
+        self.next += 1;
+        Some(input)
+    }
+
+    /// Apply all remaining inputs.
+    pub fn finish(&mut self, joystick: &VirtualJoystick) -> Result<()> {
+        self.update(joystick, u64::MAX).map(|_| ())
+    }
+
+    /// Returns `true` if all inputs have been applied.
+    pub fn is_finished(&self) -> bool {
+        self.next >= self.script.steps.len()
+    }
+
+    /// Restart playback from the beginning.
+    pub fn rewind(&mut self) {
+        self.next = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sdl3_sys::{
+        init::{SDL_Init, SDL_QuitSubSystem, SDL_INIT_JOYSTICK},
+        joystick::{SDL_GetJoystickAxis, SDL_GetJoystickButton, SDL_UpdateJoysticks},
+    };
+
+    fn due(player: &mut InputPlayer, elapsed_ms: u64) -> Vec<ScriptedInput> {
+        core::iter::from_fn(|| player.next_due(elapsed_ms)).collect()
+    }
+
+    #[test]
+    fn script_timing() {
+        let script = InputScript::new()
+            .axis(0, 100)
+            .wait(50)
+            .tap(1, 30)
+            .wait(20)
+            .hat(0, 1);
+        assert_eq!(
+            script.steps,
+            [
+                (0, ScriptedInput::Axis(0, 100)),
+                (50, ScriptedInput::Button(1, true)),
+                (80, ScriptedInput::Button(1, false)),
+                (100, ScriptedInput::Hat(0, 1)),
+            ]
+        );
+        assert_eq!(script.duration(), 100);
+        assert_eq!(InputScript::new().wait(10).duration(), 0);
+    }
+
+    #[test]
+    fn player() {
+        let mut player = InputScript::new()
+            .axis(0, 1)
+            .axis(1, 2)
+            .wait(100)
+            .tap(0, 0)
+            .wait(50)
+            .axis(0, 3)
+            .play();
+
+        assert_eq!(
+            due(&mut player, 0),
+            [ScriptedInput::Axis(0, 1), ScriptedInput::Axis(1, 2)]
+        );
+        assert_eq!(due(&mut player, 99), []);
+        assert_eq!(
+            due(&mut player, 100),
+            [
+                ScriptedInput::Button(0, true),
+                ScriptedInput::Button(0, false)
+            ]
+        );
+        assert!(!player.is_finished());
+        // time going backwards doesn't replay anything
+        assert_eq!(due(&mut player, 0), []);
+        assert_eq!(due(&mut player, 1000), [ScriptedInput::Axis(0, 3)]);
+        assert!(player.is_finished());
+        assert_eq!(due(&mut player, u64::MAX), []);
+
+        player.rewind();
+        assert!(!player.is_finished());
+        assert_eq!(due(&mut player, u64::MAX).len(), 5);
+    }
+
+    #[test]
+    fn attach() {
+        assert!(unsafe { SDL_Init(SDL_INIT_JOYSTICK) });
+        {
+            let joystick = VirtualJoystickBuilder::new(SDL_JoystickType::GAMEPAD)
+                .name("Test")
+                .axes(2)
+                .buttons(4)
+                .attach()
+                .unwrap();
+            // virtual inputs are applied when the joysticks are updated
+            let get_button = |button| unsafe {
+                SDL_UpdateJoysticks();
+                SDL_GetJoystickButton(joystick.as_ptr(), button)
+            };
+            let get_axis = |axis| unsafe {
+                SDL_UpdateJoysticks();
+                SDL_GetJoystickAxis(joystick.as_ptr(), axis)
+            };
+
+            joystick.set_button(2, true).unwrap();
+            joystick.set_axis(1, -1234).unwrap();
+            assert!(get_button(2));
+            assert!(!get_button(0));
+            assert_eq!(get_axis(1), -1234);
+            assert_eq!(get_axis(0), 0);
+
+            let mut player = InputScript::new()
+                .button(2, false)
+                .wait(10)
+                .axis(0, i16::MAX)
+                .play();
+            assert!(!player.update(&joystick, 5).unwrap());
+            assert!(!get_button(2));
+            assert_eq!(get_axis(0), 0);
+            assert!(player.update(&joystick, 10).unwrap());
+            assert_eq!(get_axis(0), i16::MAX);
+        }
+        unsafe { SDL_QuitSubSystem(SDL_INIT_JOYSTICK) };
+    }
+}
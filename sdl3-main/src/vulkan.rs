@@ -0,0 +1,244 @@
+//! Vulkan helpers for [`sdl3_sys::vulkan`] using the `ash` crate (v0.38).
+//!
+//! This module is available with the `use-ash-v0-38` feature, which also enables the
+//! feature of the same name in `sdl3-sys` so the Vulkan types there are `ash` types.
+//!
+//! [`VulkanLibrary`] loads the Vulkan loader through SDL and provides an [`ash::Entry`],
+//! [`instance_extensions`] gets the extensions SDL needs for creating surfaces, and
+//! [`VulkanSurface`] owns a surface created for a window.
+//!
+//! ```no_run
+//! use ash::vk;
+//! use ash_v0_38 as ash;
+//! use sdl3_main::{
+//!     vulkan::{instance_extensions, VulkanLibrary, VulkanSurface},
+//!     window::Window,
+//! };
+//! use sdl3_sys::video::SDL_WINDOW_VULKAN;
+//!
+//! let library = VulkanLibrary::load()?;
+//! let window = Window::new("Vulkan", 800, 600, SDL_WINDOW_VULKAN)?;
+//!
+//! let extensions: Vec<_> = instance_extensions()?.iter().map(|e| e.as_ptr()).collect();
+//! let create_info = vk::InstanceCreateInfo::default().enabled_extension_names(&extensions);
+//! let instance = unsafe { library.entry().create_instance(&create_info, None) }
+//!     .expect("couldn't create instance");
+//!
+//! let surface = unsafe { VulkanSurface::new(&window, &instance) }?;
+//! // ...
+//! drop(surface);
+//! unsafe { instance.destroy_instance(None) };
+//! # Ok::<(), sdl3_main::Error>(())
+//! ```
+
+use crate::{window::Window, Error, Result};
+use alloc::{ffi::CString, vec::Vec};
+use ash_v0_38::{self as ash, vk};
+use core::{
+    ffi::{c_char, CStr},
+    fmt,
+    marker::PhantomData,
+    mem, ptr, slice,
+};
+use sdl3_sys::vulkan::{
+    SDL_Vulkan_CreateSurface, SDL_Vulkan_DestroySurface, SDL_Vulkan_GetInstanceExtensions,
+    SDL_Vulkan_GetPresentationSupport, SDL_Vulkan_GetVkGetInstanceProcAddr, SDL_Vulkan_LoadLibrary,
+    SDL_Vulkan_UnloadLibrary,
+};
+
+/// The Vulkan loader, loaded with [`SDL_Vulkan_LoadLibrary`]. The library is unloaded when
+/// this is dropped.
+///
+/// SDL counts how many times the library has been loaded, so this can be used alongside
+/// windows created with `SDL_WINDOW_VULKAN`, which load it too.
+pub struct VulkanLibrary {
+    entry: ash::Entry,
+    _not_send_sync: PhantomData<*const ()>,
+}
+
+impl VulkanLibrary {
+    /// Load the default Vulkan loader for the platform.
+    pub fn load() -> Result<Self> {
+        unsafe { Self::load_path(ptr::null()) }
+    }
+
+    /// Load the Vulkan loader from `path`.
+    pub fn load_from(path: &str) -> Result<Self> {
+        let path = CString::new(path).map_err(|_| Error::new("path contains nul"))?;
+        unsafe { Self::load_path(path.as_ptr()) }
+    }
+
+    unsafe fn load_path(path: *const c_char) -> Result<Self> {
+        if !unsafe { SDL_Vulkan_LoadLibrary(path) } {
+            return Err(Error::from_sdl());
+        }
+        let Some(get_instance_proc_addr) = (unsafe { SDL_Vulkan_GetVkGetInstanceProcAddr() })
+        else {
+            let error = Error::from_sdl();
+            unsafe { SDL_Vulkan_UnloadLibrary() };
+            return Err(error);
+        };
+        // SDL returns it as a generic function pointer
+        let get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr =
+            unsafe { mem::transmute(get_instance_proc_addr) };
+        // the function stays valid until the library is unloaded, which happens after
+        // `entry` has been dropped
+        let entry = unsafe {
+            ash::Entry::from_static_fn(ash::StaticFn {
+                get_instance_proc_addr,
+            })
+        };
+        Ok(Self {
+            entry,
+            _not_send_sync: PhantomData,
+        })
+    }
+
+    /// Get the `ash` entry for creating instances.
+    #[inline(always)]
+    pub fn entry(&self) -> &ash::Entry {
+        &self.entry
+    }
+}
+
+impl fmt::Debug for VulkanLibrary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VulkanLibrary").finish_non_exhaustive()
+    }
+}
+
+impl Drop for VulkanLibrary {
+    fn drop(&mut self) {
+        unsafe { SDL_Vulkan_UnloadLibrary() };
+    }
+}
+
+/// Get the instance extensions SDL needs to create surfaces, to enable when creating the
+/// instance. See [`SDL_Vulkan_GetInstanceExtensions`].
+///
+/// The extension names are static strings in SDL. The video subsystem must be initialized
+/// and the Vulkan loader must be loaded, e.g. with [`VulkanLibrary`].
+pub fn instance_extensions() -> Result<Vec<&'static CStr>> {
+    // SDL doesn't check this itself, and some video drivers don't implement Vulkan at all.
+    // This fails if video isn't initialized or no loader has been loaded.
+    if unsafe { SDL_Vulkan_GetVkGetInstanceProcAddr() }.is_none() {
+        return Err(Error::from_sdl());
+    }
+    let mut count = 0;
+    let names = unsafe { SDL_Vulkan_GetInstanceExtensions(&mut count) };
+    if names.is_null() {
+        return Err(Error::from_sdl());
+    }
+    Ok(unsafe { slice::from_raw_parts(names, count as usize) }
+        .iter()
+        .map(|&name| unsafe { CStr::from_ptr(name) })
+        .collect())
+}
+
+/// Check if a queue family of a physical device can present to surfaces created by SDL.
+/// See [`SDL_Vulkan_GetPresentationSupport`].
+///
+/// # Safety
+/// `physical_device` must be a valid physical device of `instance`, and `instance` must have
+/// been created with the extensions from [`instance_extensions`] enabled
+pub unsafe fn presentation_support(
+    instance: &ash::Instance,
+    physical_device: vk::PhysicalDevice,
+    queue_family_index: u32,
+) -> bool {
+    unsafe {
+        SDL_Vulkan_GetPresentationSupport(instance.handle(), physical_device, queue_family_index)
+    }
+}
+
+/// A Vulkan surface for a window, created with [`SDL_Vulkan_CreateSurface`] and destroyed
+/// with [`SDL_Vulkan_DestroySurface`] when this is dropped.
+///
+/// This borrows the window and the instance, so it's destroyed before them.
+pub struct VulkanSurface<'a> {
+    instance: vk::Instance,
+    surface: vk::SurfaceKHR,
+    _borrow: PhantomData<(&'a Window, &'a ash::Instance)>,
+}
+
+impl<'a> VulkanSurface<'a> {
+    /// Create a surface for `window`. The window must have been created with
+    /// `SDL_WINDOW_VULKAN`.
+    ///
+    /// # Safety
+    /// `instance` must have been created with the extensions from [`instance_extensions`]
+    /// enabled, and must not be destroyed before this is dropped
+    pub unsafe fn new(window: &'a Window, instance: &'a ash::Instance) -> Result<Self> {
+        let instance = instance.handle();
+        let mut surface = vk::SurfaceKHR::null();
+        if unsafe { SDL_Vulkan_CreateSurface(window.as_ptr(), instance, ptr::null(), &mut surface) }
+        {
+            Ok(Self {
+                instance,
+                surface,
+                _borrow: PhantomData,
+            })
+        } else {
+            Err(Error::from_sdl())
+        }
+    }
+
+    /// Get the surface handle.
+    #[inline(always)]
+    pub fn handle(&self) -> vk::SurfaceKHR {
+        self.surface
+    }
+
+    /// Give up ownership of the surface. It won't be destroyed automatically.
+    #[inline]
+    pub fn into_raw(self) -> vk::SurfaceKHR {
+        let surface = self.surface;
+        mem::forget(self);
+        surface
+    }
+}
+
+impl fmt::Debug for VulkanSurface<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("VulkanSurface").field(&self.surface).finish()
+    }
+}
+
+impl Drop for VulkanSurface<'_> {
+    fn drop(&mut self) {
+        unsafe { SDL_Vulkan_DestroySurface(self.instance, self.surface, ptr::null()) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sdl3_sys::{
+        hints::{SDL_SetHint, SDL_HINT_VIDEO_DRIVER},
+        init::{SDL_Init, SDL_QuitSubSystem, SDL_WasInit, SDL_INIT_VIDEO},
+    };
+
+    #[test]
+    fn instance_extensions_need_a_loader() {
+        // other tests may have video initialized at the same time
+        if unsafe { SDL_WasInit(SDL_INIT_VIDEO) } == 0 {
+            assert!(instance_extensions().is_err());
+        }
+        unsafe {
+            SDL_SetHint(SDL_HINT_VIDEO_DRIVER, c"offscreen".as_ptr());
+            if !SDL_Init(SDL_INIT_VIDEO) {
+                // the offscreen driver isn't available in this build of SDL
+                return;
+            }
+        }
+        assert!(instance_extensions().is_err());
+        // Vulkan may not be installed
+        if let Ok(library) = VulkanLibrary::load() {
+            let extensions = instance_extensions().unwrap();
+            assert!(extensions.contains(&c"VK_KHR_surface"), "{extensions:?}");
+            drop(library);
+            assert!(instance_extensions().is_err());
+        }
+        unsafe { SDL_QuitSubSystem(SDL_INIT_VIDEO) };
+    }
+}
@@ -0,0 +1,733 @@
+//! Safe wrappers for windows from [`sdl3_sys::video`].
+//!
+//! [`WindowBuilder`] maps the `SDL_PROP_WINDOW_CREATE_*` properties of
+//! [`SDL_CreateWindowWithProperties`] to typed methods, and [`Window`] owns the created
+//! [`SDL_Window`].
+//!
+//! ```no_run
+//! use sdl3_main::window::Window;
+//!
+//! let window = Window::builder("Hello")
+//!     .size(800, 600)
+//!     .centered()
+//!     .resizable(true)
+//!     .hidden(true)
+//!     .build()?;
+//! window.set_minimum_size(320, 240)?;
+//! window.set_opacity(0.9)?;
+//! window.show()?;
+//! assert_eq!(window.title(), "Hello");
+//! # Ok::<(), sdl3_main::Error>(())
+//! ```
+//!
+//! The wrappers in other modules that take a raw window pointer can be given
+//! [`Window::as_ptr`].
+//!
+//! With the `use-raw-window-handle-v0-6` feature, [`Window`] implements `HasWindowHandle` and
+//! `HasDisplayHandle` from `raw-window-handle` v0.6, so it can be passed to crates like `wgpu`.
+
+use crate::{
+    display::{Display, DisplayMode},
+    error::check,
+    properties::Properties,
+    surface::Surface,
+    Error, Result,
+};
+use alloc::{ffi::CString, string::String, vec::Vec};
+use core::{
+    ffi::{c_char, c_void, CStr},
+    fmt,
+    marker::PhantomData,
+    ptr::{self, NonNull},
+};
+use sdl3_sys::video::{
+    SDL_CreateWindow, SDL_CreateWindowWithProperties, SDL_DestroyWindow, SDL_GetDisplayForWindow,
+    SDL_GetWindowAspectRatio, SDL_GetWindowDisplayScale, SDL_GetWindowFlags,
+    SDL_GetWindowFullscreenMode, SDL_GetWindowID, SDL_GetWindowMaximumSize,
+    SDL_GetWindowMinimumSize, SDL_GetWindowOpacity, SDL_GetWindowPixelDensity,
+    SDL_GetWindowPosition, SDL_GetWindowSize, SDL_GetWindowSizeInPixels, SDL_GetWindowTitle,
+    SDL_HideWindow, SDL_MaximizeWindow, SDL_MinimizeWindow, SDL_RaiseWindow, SDL_RestoreWindow,
+    SDL_SetWindowAlwaysOnTop, SDL_SetWindowAspectRatio, SDL_SetWindowBordered,
+    SDL_SetWindowFullscreen, SDL_SetWindowFullscreenMode, SDL_SetWindowIcon,
+    SDL_SetWindowMaximumSize, SDL_SetWindowMinimumSize, SDL_SetWindowOpacity,
+    SDL_SetWindowPosition, SDL_SetWindowResizable, SDL_SetWindowSize, SDL_SetWindowTitle,
+    SDL_ShowWindow, SDL_SyncWindow, SDL_Window, SDL_WindowFlags, SDL_WindowID,
+    SDL_PROP_WINDOW_CREATE_ALWAYS_ON_TOP_BOOLEAN, SDL_PROP_WINDOW_CREATE_BORDERLESS_BOOLEAN,
+    SDL_PROP_WINDOW_CREATE_COCOA_VIEW_POINTER, SDL_PROP_WINDOW_CREATE_COCOA_WINDOW_POINTER,
+    SDL_PROP_WINDOW_CREATE_EXTERNAL_GRAPHICS_CONTEXT_BOOLEAN, SDL_PROP_WINDOW_CREATE_FLAGS_NUMBER,
+    SDL_PROP_WINDOW_CREATE_FOCUSABLE_BOOLEAN, SDL_PROP_WINDOW_CREATE_FULLSCREEN_BOOLEAN,
+    SDL_PROP_WINDOW_CREATE_HEIGHT_NUMBER, SDL_PROP_WINDOW_CREATE_HIDDEN_BOOLEAN,
+    SDL_PROP_WINDOW_CREATE_HIGH_PIXEL_DENSITY_BOOLEAN, SDL_PROP_WINDOW_CREATE_MAXIMIZED_BOOLEAN,
+    SDL_PROP_WINDOW_CREATE_MENU_BOOLEAN, SDL_PROP_WINDOW_CREATE_METAL_BOOLEAN,
+    SDL_PROP_WINDOW_CREATE_MINIMIZED_BOOLEAN, SDL_PROP_WINDOW_CREATE_MODAL_BOOLEAN,
+    SDL_PROP_WINDOW_CREATE_MOUSE_GRABBED_BOOLEAN, SDL_PROP_WINDOW_CREATE_OPENGL_BOOLEAN,
+    SDL_PROP_WINDOW_CREATE_PARENT_POINTER, SDL_PROP_WINDOW_CREATE_RESIZABLE_BOOLEAN,
+    SDL_PROP_WINDOW_CREATE_TITLE_STRING, SDL_PROP_WINDOW_CREATE_TOOLTIP_BOOLEAN,
+    SDL_PROP_WINDOW_CREATE_TRANSPARENT_BOOLEAN, SDL_PROP_WINDOW_CREATE_UTILITY_BOOLEAN,
+    SDL_PROP_WINDOW_CREATE_VULKAN_BOOLEAN,
+    SDL_PROP_WINDOW_CREATE_WAYLAND_CREATE_EGL_WINDOW_BOOLEAN,
+    SDL_PROP_WINDOW_CREATE_WAYLAND_SURFACE_ROLE_CUSTOM_BOOLEAN,
+    SDL_PROP_WINDOW_CREATE_WAYLAND_WL_SURFACE_POINTER, SDL_PROP_WINDOW_CREATE_WIDTH_NUMBER,
+    SDL_PROP_WINDOW_CREATE_WIN32_HWND_POINTER,
+    SDL_PROP_WINDOW_CREATE_WIN32_PIXEL_FORMAT_HWND_POINTER,
+    SDL_PROP_WINDOW_CREATE_X11_WINDOW_NUMBER, SDL_PROP_WINDOW_CREATE_X_NUMBER,
+    SDL_PROP_WINDOW_CREATE_Y_NUMBER, SDL_WINDOWPOS_CENTERED, SDL_WINDOW_FULLSCREEN,
+};
+
+enum PropValue {
+    Number(i64),
+    Boolean(bool),
+    String(String),
+    Pointer(*mut c_void),
+}
+
+/// Builder for a [`Window`]. See [`SDL_CreateWindowWithProperties`].
+///
+/// Each method sets one of the `SDL_PROP_WINDOW_CREATE_*` properties. Properties that
+/// aren't set use SDL's defaults. Setting a property again replaces the earlier value.
+pub struct WindowBuilder {
+    props: Vec<(*const c_char, PropValue)>,
+}
+
+impl WindowBuilder {
+    /// Create a new builder for a window with the given title.
+    pub fn new(title: &str) -> Self {
+        Self { props: Vec::new() }.set(
+            SDL_PROP_WINDOW_CREATE_TITLE_STRING,
+            PropValue::String(title.into()),
+        )
+    }
+
+    fn set(mut self, name: *const c_char, value: PropValue) -> Self {
+        self.props.retain(|(n, _)| *n != name);
+        self.props.push((name, value));
+        self
+    }
+
+    #[inline]
+    fn set_bool(self, name: *const c_char, value: bool) -> Self {
+        self.set(name, PropValue::Boolean(value))
+    }
+
+    /// Set the window title.
+    pub fn title(self, title: &str) -> Self {
+        self.set(
+            SDL_PROP_WINDOW_CREATE_TITLE_STRING,
+            PropValue::String(title.into()),
+        )
+    }
+
+    /// Set the size of the window's client area.
+    pub fn size(self, width: i32, height: i32) -> Self {
+        self.set(
+            SDL_PROP_WINDOW_CREATE_WIDTH_NUMBER,
+            PropValue::Number(width as i64),
+        )
+        .set(
+            SDL_PROP_WINDOW_CREATE_HEIGHT_NUMBER,
+            PropValue::Number(height as i64),
+        )
+    }
+
+    /// Set the position of the window. Coordinates can also be made with
+    /// [`SDL_WINDOWPOS_CENTERED_DISPLAY`](sdl3_sys::video::SDL_WINDOWPOS_CENTERED_DISPLAY)
+    /// and [`SDL_WINDOWPOS_UNDEFINED_DISPLAY`](sdl3_sys::video::SDL_WINDOWPOS_UNDEFINED_DISPLAY).
+    pub fn position(self, x: i32, y: i32) -> Self {
+        self.set(SDL_PROP_WINDOW_CREATE_X_NUMBER, PropValue::Number(x as i64))
+            .set(SDL_PROP_WINDOW_CREATE_Y_NUMBER, PropValue::Number(y as i64))
+    }
+
+    /// Center the window on the primary display.
+    pub fn centered(self) -> Self {
+        self.position(SDL_WINDOWPOS_CENTERED, SDL_WINDOWPOS_CENTERED)
+    }
+
+    /// Set window flags. Flags are combined with the other properties by SDL.
+    pub fn flags(self, flags: SDL_WindowFlags) -> Self {
+        self.set(
+            SDL_PROP_WINDOW_CREATE_FLAGS_NUMBER,
+            PropValue::Number(flags as i64),
+        )
+    }
+
+    /// Set if the window should always be on top.
+    pub fn always_on_top(self, value: bool) -> Self {
+        self.set_bool(SDL_PROP_WINDOW_CREATE_ALWAYS_ON_TOP_BOOLEAN, value)
+    }
+
+    /// Set if the window should have no window decoration.
+    pub fn borderless(self, value: bool) -> Self {
+        self.set_bool(SDL_PROP_WINDOW_CREATE_BORDERLESS_BOOLEAN, value)
+    }
+
+    /// Set if the window should accept keyboard input. Defaults to `true`.
+    pub fn focusable(self, value: bool) -> Self {
+        self.set_bool(SDL_PROP_WINDOW_CREATE_FOCUSABLE_BOOLEAN, value)
+    }
+
+    /// Set if the application manages the graphics context for the window itself.
+    pub fn external_graphics_context(self, value: bool) -> Self {
+        self.set_bool(
+            SDL_PROP_WINDOW_CREATE_EXTERNAL_GRAPHICS_CONTEXT_BOOLEAN,
+            value,
+        )
+    }
+
+    /// Set if the window should start in fullscreen mode at desktop resolution.
+    pub fn fullscreen(self, value: bool) -> Self {
+        self.set_bool(SDL_PROP_WINDOW_CREATE_FULLSCREEN_BOOLEAN, value)
+    }
+
+    /// Set if the window should start hidden.
+    pub fn hidden(self, value: bool) -> Self {
+        self.set_bool(SDL_PROP_WINDOW_CREATE_HIDDEN_BOOLEAN, value)
+    }
+
+    /// Set if the window should use a high pixel density buffer if possible.
+    pub fn high_pixel_density(self, value: bool) -> Self {
+        self.set_bool(SDL_PROP_WINDOW_CREATE_HIGH_PIXEL_DENSITY_BOOLEAN, value)
+    }
+
+    /// Set if the window should start maximized.
+    pub fn maximized(self, value: bool) -> Self {
+        self.set_bool(SDL_PROP_WINDOW_CREATE_MAXIMIZED_BOOLEAN, value)
+    }
+
+    /// Set if the window should start minimized.
+    pub fn minimized(self, value: bool) -> Self {
+        self.set_bool(SDL_PROP_WINDOW_CREATE_MINIMIZED_BOOLEAN, value)
+    }
+
+    /// Set if the window should be a popup menu. This requires a [`parent`](Self::parent).
+    pub fn menu(self, value: bool) -> Self {
+        self.set_bool(SDL_PROP_WINDOW_CREATE_MENU_BOOLEAN, value)
+    }
+
+    /// Set if the window should be a tooltip. This requires a [`parent`](Self::parent).
+    pub fn tooltip(self, value: bool) -> Self {
+        self.set_bool(SDL_PROP_WINDOW_CREATE_TOOLTIP_BOOLEAN, value)
+    }
+
+    /// Set if the window should be a utility window, which isn't shown in the task bar.
+    pub fn utility(self, value: bool) -> Self {
+        self.set_bool(SDL_PROP_WINDOW_CREATE_UTILITY_BOOLEAN, value)
+    }
+
+    /// Set if the window should be modal for its [`parent`](Self::parent).
+    pub fn modal(self, value: bool) -> Self {
+        self.set_bool(SDL_PROP_WINDOW_CREATE_MODAL_BOOLEAN, value)
+    }
+
+    /// Set if the window should start with the mouse grabbed.
+    pub fn mouse_grabbed(self, value: bool) -> Self {
+        self.set_bool(SDL_PROP_WINDOW_CREATE_MOUSE_GRABBED_BOOLEAN, value)
+    }
+
+    /// Set if the window should be resizable by the user.
+    pub fn resizable(self, value: bool) -> Self {
+        self.set_bool(SDL_PROP_WINDOW_CREATE_RESIZABLE_BOOLEAN, value)
+    }
+
+    /// Set if the window should have a transparent buffer.
+    pub fn transparent(self, value: bool) -> Self {
+        self.set_bool(SDL_PROP_WINDOW_CREATE_TRANSPARENT_BOOLEAN, value)
+    }
+
+    /// Set if the window will be used with OpenGL rendering.
+    pub fn opengl(self, value: bool) -> Self {
+        self.set_bool(SDL_PROP_WINDOW_CREATE_OPENGL_BOOLEAN, value)
+    }
+
+    /// Set if the window will be used with Vulkan rendering.
+    pub fn vulkan(self, value: bool) -> Self {
+        self.set_bool(SDL_PROP_WINDOW_CREATE_VULKAN_BOOLEAN, value)
+    }
+
+    /// Set if the window will be used with Metal rendering.
+    pub fn metal(self, value: bool) -> Self {
+        self.set_bool(SDL_PROP_WINDOW_CREATE_METAL_BOOLEAN, value)
+    }
+
+    /// Set the parent of the window. This is required for popups, tooltips and modal windows.
+    ///
+    /// SDL destroys child windows when their parent is destroyed.
+    ///
+    /// # Safety
+    /// `parent` must be a valid window that outlives the created window
+    pub unsafe fn parent(self, parent: *mut SDL_Window) -> Self {
+        self.set(
+            SDL_PROP_WINDOW_CREATE_PARENT_POINTER,
+            PropValue::Pointer(parent as *mut c_void),
+        )
+    }
+
+    /// Wrap an existing `NSWindow` (macOS).
+    ///
+    /// # Safety
+    /// `window` must be a valid `NSWindow*` that outlives the created window
+    pub unsafe fn cocoa_window(self, window: *mut c_void) -> Self {
+        self.set(
+            SDL_PROP_WINDOW_CREATE_COCOA_WINDOW_POINTER,
+            PropValue::Pointer(window),
+        )
+    }
+
+    /// Use an existing `NSView` (macOS).
+    ///
+    /// # Safety
+    /// `view` must be a valid `NSView*` that outlives the created window
+    pub unsafe fn cocoa_view(self, view: *mut c_void) -> Self {
+        self.set(
+            SDL_PROP_WINDOW_CREATE_COCOA_VIEW_POINTER,
+            PropValue::Pointer(view),
+        )
+    }
+
+    /// Set if the application manages the role of the Wayland surface itself.
+    pub fn wayland_surface_role_custom(self, value: bool) -> Self {
+        self.set_bool(
+            SDL_PROP_WINDOW_CREATE_WAYLAND_SURFACE_ROLE_CUSTOM_BOOLEAN,
+            value,
+        )
+    }
+
+    /// Set if SDL should create a `wl_egl_window` for the window even though it isn't
+    /// created with OpenGL.
+    pub fn wayland_create_egl_window(self, value: bool) -> Self {
+        self.set_bool(
+            SDL_PROP_WINDOW_CREATE_WAYLAND_CREATE_EGL_WINDOW_BOOLEAN,
+            value,
+        )
+    }
+
+    /// Wrap an existing `wl_surface` (Wayland).
+    ///
+    /// # Safety
+    /// `surface` must be a valid `wl_surface*` that outlives the created window
+    pub unsafe fn wayland_wl_surface(self, surface: *mut c_void) -> Self {
+        self.set(
+            SDL_PROP_WINDOW_CREATE_WAYLAND_WL_SURFACE_POINTER,
+            PropValue::Pointer(surface),
+        )
+    }
+
+    /// Wrap an existing `HWND` (Windows).
+    ///
+    /// # Safety
+    /// `hwnd` must be a valid window handle that outlives the created window
+    pub unsafe fn win32_hwnd(self, hwnd: *mut c_void) -> Self {
+        self.set(
+            SDL_PROP_WINDOW_CREATE_WIN32_HWND_POINTER,
+            PropValue::Pointer(hwnd),
+        )
+    }
+
+    /// Use the pixel format of another `HWND` (Windows), for sharing OpenGL contexts.
+    ///
+    /// # Safety
+    /// `hwnd` must be a valid window handle
+    pub unsafe fn win32_pixel_format_hwnd(self, hwnd: *mut c_void) -> Self {
+        self.set(
+            SDL_PROP_WINDOW_CREATE_WIN32_PIXEL_FORMAT_HWND_POINTER,
+            PropValue::Pointer(hwnd),
+        )
+    }
+
+    /// Wrap an existing X11 window.
+    ///
+    /// # Safety
+    /// `window` must be a valid X11 window that outlives the created window
+    pub unsafe fn x11_window(self, window: u64) -> Self {
+        self.set(
+            SDL_PROP_WINDOW_CREATE_X11_WINDOW_NUMBER,
+            PropValue::Number(window as i64),
+        )
+    }
+
+    /// Create the window.
+    pub fn build(&self) -> Result<Window> {
+        let props = Properties::new()?;
+        for (name, value) in &self.props {
+            unsafe {
+                match value {
+                    PropValue::Number(value) => props.set_number(*name, *value),
+                    PropValue::Boolean(value) => props.set_boolean(*name, *value),
+                    PropValue::String(value) => props.set_string(
+                        *name,
+                        &CString::new(value.as_str())
+                            .map_err(|_| Error::new("string contains nul"))?,
+                    ),
+                    PropValue::Pointer(value) => props.set_pointer(*name, *value),
+                }?;
+            }
+        }
+        unsafe { Window::from_created(SDL_CreateWindowWithProperties(props.id())) }
+    }
+}
+
+impl fmt::Debug for WindowBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut map = f.debug_map();
+        for (name, value) in &self.props {
+            let name = unsafe { CStr::from_ptr(*name) };
+            match value {
+                PropValue::Number(v) => map.entry(&name, v),
+                PropValue::Boolean(v) => map.entry(&name, v),
+                PropValue::String(v) => map.entry(&name, v),
+                PropValue::Pointer(v) => map.entry(&name, v),
+            };
+        }
+        map.finish()
+    }
+}
+
+/// An owned window. The window is destroyed when this is dropped.
+///
+/// Windows must only be used on the main thread, so this is neither `Send` nor `Sync`.
+pub struct Window {
+    ptr: NonNull<SDL_Window>,
+    _not_send_sync: PhantomData<*const ()>,
+}
+
+impl Window {
+    /// Create a window. See [`SDL_CreateWindow`].
+    pub fn new(title: &str, width: i32, height: i32, flags: SDL_WindowFlags) -> Result<Self> {
+        let title = CString::new(title).map_err(|_| Error::new("title contains nul"))?;
+        unsafe { Self::from_created(SDL_CreateWindow(title.as_ptr(), width, height, flags)) }
+    }
+
+    /// Start building a window with more options. See [`WindowBuilder`].
+    #[inline]
+    pub fn builder(title: &str) -> WindowBuilder {
+        WindowBuilder::new(title)
+    }
+
+    unsafe fn from_created(ptr: *mut SDL_Window) -> Result<Self> {
+        match NonNull::new(ptr) {
+            Some(ptr) => Ok(unsafe { Self::from_raw(ptr) }),
+            None => Err(Error::from_sdl()),
+        }
+    }
+
+    /// Take ownership of a raw window.
+    ///
+    /// # Safety
+    /// `ptr` must be a valid window that isn't owned by anything else
+    #[inline(always)]
+    pub unsafe fn from_raw(ptr: NonNull<SDL_Window>) -> Self {
+        Self {
+            ptr,
+            _not_send_sync: PhantomData,
+        }
+    }
+
+    /// Give up ownership of the raw window. It won't be destroyed automatically.
+    #[inline]
+    pub fn into_raw(self) -> *mut SDL_Window {
+        let ptr = self.ptr.as_ptr();
+        core::mem::forget(self);
+        ptr
+    }
+
+    /// Get the raw window pointer.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *mut SDL_Window {
+        self.ptr.as_ptr()
+    }
+
+    /// Get the window ID, as used in events.
+    #[inline]
+    pub fn id(&self) -> SDL_WindowID {
+        unsafe { SDL_GetWindowID(self.as_ptr()) }
+    }
+
+    /// Get the window flags.
+    #[inline]
+    pub fn flags(&self) -> SDL_WindowFlags {
+        unsafe { SDL_GetWindowFlags(self.as_ptr()) }
+    }
+
+    /// Get the display the window is on, or `None` on error.
+    pub fn display(&self) -> Option<Display> {
+        let id = unsafe { SDL_GetDisplayForWindow(self.as_ptr()) };
+        (id != 0).then_some(Display(id))
+    }
+
+    /// Get the window title.
+    pub fn title(&self) -> String {
+        let title = unsafe { SDL_GetWindowTitle(self.as_ptr()) };
+        if title.is_null() {
+            return String::new();
+        }
+        unsafe { CStr::from_ptr(title) }
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    /// Set the window title.
+    pub fn set_title(&self, title: &str) -> Result<()> {
+        let title = CString::new(title).map_err(|_| Error::new("title contains nul"))?;
+        check(unsafe { SDL_SetWindowTitle(self.as_ptr(), title.as_ptr()) })
+    }
+
+    /// Get the size of the window's client area.
+    pub fn size(&self) -> Result<(i32, i32)> {
+        let (mut w, mut h) = (0, 0);
+        check(unsafe { SDL_GetWindowSize(self.as_ptr(), &mut w, &mut h) })?;
+        Ok((w, h))
+    }
+
+    /// Request a new size for the window's client area. See [`SDL_SetWindowSize`].
+    pub fn set_size(&self, width: i32, height: i32) -> Result<()> {
+        check(unsafe { SDL_SetWindowSize(self.as_ptr(), width, height) })
+    }
+
+    /// Get the size of the window's client area in pixels.
+    pub fn size_in_pixels(&self) -> Result<(i32, i32)> {
+        let (mut w, mut h) = (0, 0);
+        check(unsafe { SDL_GetWindowSizeInPixels(self.as_ptr(), &mut w, &mut h) })?;
+        Ok((w, h))
+    }
+
+    /// Get the position of the window.
+    pub fn position(&self) -> Result<(i32, i32)> {
+        let (mut x, mut y) = (0, 0);
+        check(unsafe { SDL_GetWindowPosition(self.as_ptr(), &mut x, &mut y) })?;
+        Ok((x, y))
+    }
+
+    /// Request a new position for the window. See [`SDL_SetWindowPosition`].
+    pub fn set_position(&self, x: i32, y: i32) -> Result<()> {
+        check(unsafe { SDL_SetWindowPosition(self.as_ptr(), x, y) })
+    }
+
+    /// Get the minimum size of the window's client area.
+    pub fn minimum_size(&self) -> Result<(i32, i32)> {
+        let (mut w, mut h) = (0, 0);
+        check(unsafe { SDL_GetWindowMinimumSize(self.as_ptr(), &mut w, &mut h) })?;
+        Ok((w, h))
+    }
+
+    /// Set the minimum size of the window's client area. `0` means no limit.
+    pub fn set_minimum_size(&self, width: i32, height: i32) -> Result<()> {
+        check(unsafe { SDL_SetWindowMinimumSize(self.as_ptr(), width, height) })
+    }
+
+    /// Get the maximum size of the window's client area.
+    pub fn maximum_size(&self) -> Result<(i32, i32)> {
+        let (mut w, mut h) = (0, 0);
+        check(unsafe { SDL_GetWindowMaximumSize(self.as_ptr(), &mut w, &mut h) })?;
+        Ok((w, h))
+    }
+
+    /// Set the maximum size of the window's client area. `0` means no limit.
+    pub fn set_maximum_size(&self, width: i32, height: i32) -> Result<()> {
+        check(unsafe { SDL_SetWindowMaximumSize(self.as_ptr(), width, height) })
+    }
+
+    /// Get the minimum and maximum aspect ratio of the window's client area.
+    pub fn aspect_ratio(&self) -> Result<(f32, f32)> {
+        let (mut min, mut max) = (0.0, 0.0);
+        check(unsafe { SDL_GetWindowAspectRatio(self.as_ptr(), &mut min, &mut max) })?;
+        Ok((min, max))
+    }
+
+    /// Set the minimum and maximum aspect ratio (width / height) of the window's client area.
+    /// `0.0` means no limit. See [`SDL_SetWindowAspectRatio`].
+    pub fn set_aspect_ratio(&self, min: f32, max: f32) -> Result<()> {
+        check(unsafe { SDL_SetWindowAspectRatio(self.as_ptr(), min, max) })
+    }
+
+    /// Get the window's pixel density. See [`SDL_GetWindowPixelDensity`].
+    pub fn pixel_density(&self) -> Result<f32> {
+        let density = unsafe { SDL_GetWindowPixelDensity(self.as_ptr()) };
+        if density == 0.0 {
+            Err(Error::from_sdl())
+        } else {
+            Ok(density)
+        }
+    }
+
+    /// Get the content scale of the window. See [`SDL_GetWindowDisplayScale`].
+    pub fn display_scale(&self) -> Result<f32> {
+        let scale = unsafe { SDL_GetWindowDisplayScale(self.as_ptr()) };
+        if scale == 0.0 {
+            Err(Error::from_sdl())
+        } else {
+            Ok(scale)
+        }
+    }
+
+    /// Check if the window is fullscreen.
+    #[inline]
+    pub fn is_fullscreen(&self) -> bool {
+        self.flags() & SDL_WINDOW_FULLSCREEN != 0
+    }
+
+    /// Request that the window be fullscreen or windowed. See [`SDL_SetWindowFullscreen`].
+    pub fn set_fullscreen(&self, fullscreen: bool) -> Result<()> {
+        check(unsafe { SDL_SetWindowFullscreen(self.as_ptr(), fullscreen) })
+    }
+
+    /// Get the display mode used when the window is fullscreen, or `None` for borderless
+    /// fullscreen at desktop resolution.
+    pub fn fullscreen_mode(&self) -> Option<DisplayMode> {
+        unsafe { DisplayMode::from_ptr(SDL_GetWindowFullscreenMode(self.as_ptr())) }.ok()
+    }
+
+    /// Set the display mode to use when the window is fullscreen, or `None` for borderless
+    /// fullscreen at desktop resolution. See [`SDL_SetWindowFullscreenMode`].
+    ///
+    /// The mode must come from [`Display::fullscreen_modes`] or
+    /// [`Display::closest_fullscreen_mode`].
+    pub fn set_fullscreen_mode(&self, mode: Option<&DisplayMode>) -> Result<()> {
+        let mode = mode.map_or(ptr::null(), |m| m.as_raw() as *const _);
+        check(unsafe { SDL_SetWindowFullscreenMode(self.as_ptr(), mode) })
+    }
+
+    /// Set the window icon. SDL copies the surface. See [`SDL_SetWindowIcon`].
+    pub fn set_icon(&self, icon: &Surface) -> Result<()> {
+        check(unsafe { SDL_SetWindowIcon(self.as_ptr(), icon.as_ptr()) })
+    }
+
+    /// Get the opacity of the window, from `0.0` (transparent) to `1.0` (opaque).
+    pub fn opacity(&self) -> Result<f32> {
+        let opacity = unsafe { SDL_GetWindowOpacity(self.as_ptr()) };
+        if opacity < 0.0 {
+            Err(Error::from_sdl())
+        } else {
+            Ok(opacity)
+        }
+    }
+
+    /// Set the opacity of the window, from `0.0` (transparent) to `1.0` (opaque).
+    pub fn set_opacity(&self, opacity: f32) -> Result<()> {
+        check(unsafe { SDL_SetWindowOpacity(self.as_ptr(), opacity) })
+    }
+
+    /// Set if the window has window decoration.
+    pub fn set_bordered(&self, bordered: bool) -> Result<()> {
+        check(unsafe { SDL_SetWindowBordered(self.as_ptr(), bordered) })
+    }
+
+    /// Set if the window is resizable by the user.
+    pub fn set_resizable(&self, resizable: bool) -> Result<()> {
+        check(unsafe { SDL_SetWindowResizable(self.as_ptr(), resizable) })
+    }
+
+    /// Set if the window is always on top.
+    pub fn set_always_on_top(&self, on_top: bool) -> Result<()> {
+        check(unsafe { SDL_SetWindowAlwaysOnTop(self.as_ptr(), on_top) })
+    }
+
+    /// Show the window.
+    pub fn show(&self) -> Result<()> {
+        check(unsafe { SDL_ShowWindow(self.as_ptr()) })
+    }
+
+    /// Hide the window.
+    pub fn hide(&self) -> Result<()> {
+        check(unsafe { SDL_HideWindow(self.as_ptr()) })
+    }
+
+    /// Request that the window be raised above other windows and get input focus.
+    pub fn raise(&self) -> Result<()> {
+        check(unsafe { SDL_RaiseWindow(self.as_ptr()) })
+    }
+
+    /// Request that the window be maximized.
+    pub fn maximize(&self) -> Result<()> {
+        check(unsafe { SDL_MaximizeWindow(self.as_ptr()) })
+    }
+
+    /// Request that the window be minimized.
+    pub fn minimize(&self) -> Result<()> {
+        check(unsafe { SDL_MinimizeWindow(self.as_ptr()) })
+    }
+
+    /// Request that the window be restored from being minimized or maximized.
+    pub fn restore(&self) -> Result<()> {
+        check(unsafe { SDL_RestoreWindow(self.as_ptr()) })
+    }
+
+    /// Wait for pending size, position and state requests to be applied.
+    /// See [`SDL_SyncWindow`].
+    pub fn sync(&self) -> Result<()> {
+        check(unsafe { SDL_SyncWindow(self.as_ptr()) })
+    }
+}
+
+impl fmt::Debug for Window {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Window")
+            .field("ptr", &self.ptr)
+            .field("id", &self.id())
+            .finish()
+    }
+}
+
+impl Drop for Window {
+    fn drop(&mut self) {
+        unsafe { SDL_DestroyWindow(self.as_ptr()) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sdl3_sys::{
+        hints::{SDL_SetHint, SDL_HINT_VIDEO_DRIVER},
+        init::{SDL_Init, SDL_QuitSubSystem, SDL_INIT_VIDEO},
+        video::SDL_WINDOW_HIDDEN,
+    };
+
+    #[test]
+    fn nul_in_title() {
+        assert!(Window::new("a\0b", 100, 100, SDL_WINDOW_HIDDEN).is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        unsafe {
+            SDL_SetHint(SDL_HINT_VIDEO_DRIVER, c"offscreen,dummy".as_ptr());
+            assert!(SDL_Init(SDL_INIT_VIDEO));
+        }
+        {
+            let window = Window::builder("Test")
+                .size(320, 200)
+                .hidden(true)
+                .build()
+                .unwrap();
+            assert_eq!(window.title(), "Test");
+            assert_eq!(window.size().unwrap(), (320, 200));
+
+            window.set_title("Tëst ウィンドウ").unwrap();
+            assert_eq!(window.title(), "Tëst ウィンドウ");
+            assert!(window.set_title("a\0b").is_err());
+            assert_eq!(window.title(), "Tëst ウィンドウ");
+            assert!(Window::builder("a\0b").hidden(true).build().is_err());
+
+            window.set_size(640, 480).unwrap();
+            window.sync().unwrap();
+            assert_eq!(window.size().unwrap(), (640, 480));
+
+            window.set_minimum_size(100, 80).unwrap();
+            window.set_maximum_size(1000, 800).unwrap();
+            assert_eq!(window.minimum_size().unwrap(), (100, 80));
+            assert_eq!(window.maximum_size().unwrap(), (1000, 800));
+            assert!(window.set_minimum_size(2000, 80).is_err());
+
+            window.set_aspect_ratio(1.0, 2.0).unwrap();
+            assert_eq!(window.aspect_ratio().unwrap(), (1.0, 2.0));
+            window.set_aspect_ratio(0.0, 0.0).unwrap();
+            assert_eq!(window.aspect_ratio().unwrap(), (0.0, 0.0));
+
+            // not all drivers support opacity, but it has to round trip if it's set
+            if window.set_opacity(0.5).is_ok() {
+                assert!((window.opacity().unwrap() - 0.5).abs() < 0.01);
+            }
+            assert!(window.flags() & SDL_WINDOW_HIDDEN != 0);
+        }
+        unsafe { SDL_QuitSubSystem(SDL_INIT_VIDEO) };
+    }
+}
@@ -6,6 +6,7 @@ use crate::{
         IntegerLiteral, Item, Items, Literal, ParseErr, PreProcBlock, PrimitiveType, StructFields,
         StructKind, StructOrUnion, Type, TypeDef, TypeEnum,
     },
+    SdlVersion, MIN_VERSION_FEATURES,
 };
 use std::{
     collections::HashSet,
@@ -20,6 +21,7 @@ mod expr;
 pub use expr::Value;
 mod item;
 mod patch;
+pub use patch::patch_emit_module_end;
 use patch::{patch_emit_define, patch_emit_function, patch_emit_macro_call, patch_emit_type_def};
 mod state;
 pub use state::{Cfg, DefineState, EmitContext, InnerEmitContext, Sym, SymKind};
@@ -776,6 +778,21 @@ impl<const ALLOW_KEYWORDS: bool> Emit for IdentOrKwT<ALLOW_KEYWORDS> {
     }
 }
 
+/// Disable an item if a minimum SDL version feature older than `since` is enabled.
+/// Docs always include the item.
+fn emit_min_version_cfg(ctx: &mut EmitContext, since: SdlVersion) -> EmitResult {
+    let mut older = Cfg::none();
+    for version in MIN_VERSION_FEATURES.iter().filter(|v| **v < since) {
+        older = older.any(Cfg::one(format!("feature = \"{}\"", version.feature())));
+    }
+    if !older.is_none() {
+        let cfg = Cfg::one("doc".to_owned()).any(older.not());
+        ctx.emit_cfg(&cfg, |ctx, pred| Ok(write!(ctx, "{pred}")?))?;
+        writeln!(ctx)?;
+    }
+    Ok(())
+}
+
 impl Emit for Function {
     fn emit(&self, ctx: &mut EmitContext) -> EmitResult {
         if patch_emit_function(ctx, self)? {
@@ -796,6 +813,9 @@ impl Emit for Function {
             )?;
             emit_extern_start(ctx, &self.abi, false)?;
             self.doc.emit(ctx)?;
+            if let Some(since) = ctx.record_availability(self.ident.as_str(), self.doc.as_ref()) {
+                emit_min_version_cfg(ctx, since)?;
+            }
             write!(ctx, "pub fn ")?;
             self.ident.emit(ctx)?;
             self.args.emit(ctx)?;
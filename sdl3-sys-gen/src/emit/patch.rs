@@ -87,6 +87,7 @@ const EMIT_FUNCTION_PATCHES: &[EmitFunctionPatch] = &[
         patch: |ctx, f| {
             let ident = f.ident.as_str().strip_suffix("Function").unwrap();
             let ordering = ident.strip_prefix("SDL_MemoryBarrier").unwrap();
+            ctx.record_availability(ident, f.doc.as_ref());
             writeln!(
                 ctx,
                 str_block! {r#"
@@ -375,6 +376,7 @@ const EMIT_DEFINE_PATCHES: &[EmitDefinePatch] = &[
         module: Some("atomic"),
         match_ident: |i| i == "SDL_CompilerBarrier",
         patch: |ctx, define| {
+            ctx.record_availability(define.ident.as_str(), define.doc.as_ref());
             define.doc.emit(ctx)?;
             ctx.write_str(str_block! {r#"
                 #[inline(always)]
@@ -390,6 +392,7 @@ const EMIT_DEFINE_PATCHES: &[EmitDefinePatch] = &[
         module: Some("atomic"),
         match_ident: |i| i == "SDL_CPUPauseInstruction",
         patch: |ctx, define| {
+            ctx.record_availability(define.ident.as_str(), define.doc.as_ref());
             define.doc.emit(ctx)?;
             // TODO: add more archs
             ctx.write_str(str_block! {r#"
@@ -412,7 +415,8 @@ const EMIT_DEFINE_PATCHES: &[EmitDefinePatch] = &[
     EmitDefinePatch {
         module: Some("stdinc"),
         match_ident: |i| matches!(i, "SDL_clamp"),
-        patch: |ctx, _| {
+        patch: |ctx, define| {
+            ctx.record_availability(define.ident.as_str(), define.doc.as_ref());
             writeln!(ctx, "#[inline(always)]")?;
             writeln!(
                 ctx,
@@ -501,6 +505,7 @@ const EMIT_DEFINE_PATCHES: &[EmitDefinePatch] = &[
         module: Some("stdinc"),
         match_ident: |i| matches!(i, "SDL_min" | "SDL_max"),
         patch: |ctx, define| {
+            ctx.record_availability(define.ident.as_str(), define.doc.as_ref());
             writeln!(ctx, "#[inline(always)]")?;
             writeln!(
                 ctx,
@@ -1064,3 +1069,442 @@ fn patch_eval<T: ?Sized>(
     }
     Ok(None)
 }
+
+type ModuleEndPatch = EmitPatch<Items>;
+
+const EMIT_MODULE_END_PATCHES: &[ModuleEndPatch] = &[
+    ModuleEndPatch {
+        module: Some("scancode"),
+        match_ident: |_| true,
+        patch: |ctx, _| {
+            writeln!(ctx, "/// (`sdl3-sys`) Names of the named scancodes, in scancode order. These are the same names that [`SDL_GetScancodeName`] returns.")?;
+            writeln!(ctx, "///")?;
+            writeln!(ctx, "/// Some scancodes share a name; [`SDL_GetScancodeFromName`] returns the first match.")?;
+            writeln!(
+                ctx,
+                "pub const SDL_SCANCODE_NAMES: &[(SDL_Scancode, &::core::primitive::str)] = &["
+            )?;
+            ctx.increase_indent();
+            for (ident, name) in SCANCODE_NAMES {
+                writeln!(ctx, "(SDL_SCANCODE_{ident}, {name:?}),")?;
+            }
+            ctx.decrease_indent();
+            writeln!(ctx, "];")?;
+            writeln!(ctx)?;
+            Ok(false)
+        },
+    },
+    ModuleEndPatch {
+        module: Some("keycode"),
+        match_ident: |_| true,
+        patch: |ctx, items| {
+            fn collect<'a>(items: &'a Items, out: &mut Vec<&'a str>) {
+                for item in items.iter() {
+                    match item {
+                        Item::Define(define) => {
+                            let ident = define.ident.as_str();
+                            if define.args.is_none()
+                                && ident.starts_with("SDLK_")
+                                && !matches!(ident, "SDLK_SCANCODE_MASK" | "SDLK_UNKNOWN")
+                            {
+                                out.push(ident);
+                            }
+                        }
+                        Item::PreProcBlock(ppb) => {
+                            collect(&ppb.block, out);
+                            let mut else_block = ppb.else_block.as_deref();
+                            while let Some(ppb) = else_block {
+                                collect(&ppb.block, out);
+                                else_block = ppb.else_block.as_deref();
+                            }
+                        }
+                        Item::Block(block) => collect(&block.items, out),
+                        _ => (),
+                    }
+                }
+            }
+            let mut keycodes = Vec::new();
+            collect(items, &mut keycodes);
+
+            writeln!(
+                ctx,
+                "/// (`sdl3-sys`) All the keycodes defined by SDL, excluding [`SDLK_UNKNOWN`]."
+            )?;
+            writeln!(ctx, "pub const SDL_KEYCODES: &[SDL_Keycode] = &[")?;
+            ctx.increase_indent();
+            for ident in keycodes {
+                writeln!(ctx, "{ident},")?;
+            }
+            ctx.decrease_indent();
+            writeln!(ctx, "];")?;
+            writeln!(ctx)?;
+
+            writeln!(ctx, "/// (`sdl3-sys`) The keycodes SDL's default US keyboard layout produces for each scancode, as `(scancode, keycode, shifted keycode)`.")?;
+            writeln!(ctx, "///")?;
+            writeln!(ctx, "/// Scancodes that aren't listed here map to [`SDL_SCANCODE_TO_KEYCODE`] of the scancode, with or without shift.")?;
+            writeln!(
+                ctx,
+                "pub const SDL_US_KEYMAP: &[(SDL_Scancode, SDL_Keycode, SDL_Keycode)] = &["
+            )?;
+            ctx.increase_indent();
+            for (ident, key, shifted) in US_KEYMAP {
+                writeln!(
+                    ctx,
+                    "(SDL_SCANCODE_{ident}, {key:?} as SDL_Keycode, {shifted:?} as SDL_Keycode),"
+                )?;
+            }
+            ctx.decrease_indent();
+            writeln!(ctx, "];")?;
+            writeln!(ctx)?;
+            Ok(false)
+        },
+    },
+    ModuleEndPatch {
+        module: Some("version"),
+        match_ident: |_| true,
+        patch: |ctx, _| {
+            ctx.write_str(str_block! {r#"
+                /// (`sdl3-sys`) Check that the SDL library linked at runtime is at least version `at_least`,
+                /// e.g. `SDL_VERSIONNUM(3, 1, 6)`. If it's older, this sets an error that can be
+                /// retrieved with [`SDL_GetError`] and returns false.
+                ///
+                /// The `sdl-X-Y-Z` features of `sdl3-sys` disable functions that are newer than SDL X.Y.Z.
+                /// Call this at startup with the same version to make sure the installed SDL has all the
+                /// functions that are available.
+                pub fn require(at_least: ::core::ffi::c_int) -> ::core::primitive::bool {
+                    let version = unsafe { SDL_GetVersion() };
+                    if version >= at_least {
+                        return true;
+                    }
+                    unsafe {
+                        crate::error::SDL_SetError(
+                            c"SDL version %d.%d.%d is required, but version %d.%d.%d is linked".as_ptr(),
+                            SDL_VERSIONNUM_MAJOR(at_least),
+                            SDL_VERSIONNUM_MINOR(at_least),
+                            SDL_VERSIONNUM_MICRO(at_least),
+                            SDL_VERSIONNUM_MAJOR(version),
+                            SDL_VERSIONNUM_MINOR(version),
+                            SDL_VERSIONNUM_MICRO(version),
+                        )
+                    }
+                }
+
+            "#})?;
+            Ok(false)
+        },
+    },
+];
+
+pub fn patch_emit_module_end(ctx: &mut EmitContext, items: &Items) -> EmitResult {
+    let module = ctx.module().to_owned();
+    patch_emit(ctx, items, &module, EMIT_MODULE_END_PATCHES)?;
+    Ok(())
+}
+
+// These match the names in SDL's `SDL_scancode_names` table
+const SCANCODE_NAMES: &[(&str, &str)] = &[
+    ("A", "A"),
+    ("B", "B"),
+    ("C", "C"),
+    ("D", "D"),
+    ("E", "E"),
+    ("F", "F"),
+    ("G", "G"),
+    ("H", "H"),
+    ("I", "I"),
+    ("J", "J"),
+    ("K", "K"),
+    ("L", "L"),
+    ("M", "M"),
+    ("N", "N"),
+    ("O", "O"),
+    ("P", "P"),
+    ("Q", "Q"),
+    ("R", "R"),
+    ("S", "S"),
+    ("T", "T"),
+    ("U", "U"),
+    ("V", "V"),
+    ("W", "W"),
+    ("X", "X"),
+    ("Y", "Y"),
+    ("Z", "Z"),
+    ("1", "1"),
+    ("2", "2"),
+    ("3", "3"),
+    ("4", "4"),
+    ("5", "5"),
+    ("6", "6"),
+    ("7", "7"),
+    ("8", "8"),
+    ("9", "9"),
+    ("0", "0"),
+    ("RETURN", "Return"),
+    ("ESCAPE", "Escape"),
+    ("BACKSPACE", "Backspace"),
+    ("TAB", "Tab"),
+    ("SPACE", "Space"),
+    ("MINUS", "-"),
+    ("EQUALS", "="),
+    ("LEFTBRACKET", "["),
+    ("RIGHTBRACKET", "]"),
+    ("BACKSLASH", "\\"),
+    ("NONUSHASH", "#"),
+    ("SEMICOLON", ";"),
+    ("APOSTROPHE", "'"),
+    ("GRAVE", "`"),
+    ("COMMA", ","),
+    ("PERIOD", "."),
+    ("SLASH", "/"),
+    ("CAPSLOCK", "CapsLock"),
+    ("F1", "F1"),
+    ("F2", "F2"),
+    ("F3", "F3"),
+    ("F4", "F4"),
+    ("F5", "F5"),
+    ("F6", "F6"),
+    ("F7", "F7"),
+    ("F8", "F8"),
+    ("F9", "F9"),
+    ("F10", "F10"),
+    ("F11", "F11"),
+    ("F12", "F12"),
+    ("PRINTSCREEN", "PrintScreen"),
+    ("SCROLLLOCK", "ScrollLock"),
+    ("PAUSE", "Pause"),
+    ("INSERT", "Insert"),
+    ("HOME", "Home"),
+    ("PAGEUP", "PageUp"),
+    ("DELETE", "Delete"),
+    ("END", "End"),
+    ("PAGEDOWN", "PageDown"),
+    ("RIGHT", "Right"),
+    ("LEFT", "Left"),
+    ("DOWN", "Down"),
+    ("UP", "Up"),
+    ("NUMLOCKCLEAR", "Numlock"),
+    ("KP_DIVIDE", "Keypad /"),
+    ("KP_MULTIPLY", "Keypad *"),
+    ("KP_MINUS", "Keypad -"),
+    ("KP_PLUS", "Keypad +"),
+    ("KP_ENTER", "Keypad Enter"),
+    ("KP_1", "Keypad 1"),
+    ("KP_2", "Keypad 2"),
+    ("KP_3", "Keypad 3"),
+    ("KP_4", "Keypad 4"),
+    ("KP_5", "Keypad 5"),
+    ("KP_6", "Keypad 6"),
+    ("KP_7", "Keypad 7"),
+    ("KP_8", "Keypad 8"),
+    ("KP_9", "Keypad 9"),
+    ("KP_0", "Keypad 0"),
+    ("KP_PERIOD", "Keypad ."),
+    ("NONUSBACKSLASH", "NonUSBackslash"),
+    ("APPLICATION", "Application"),
+    ("POWER", "Power"),
+    ("KP_EQUALS", "Keypad ="),
+    ("F13", "F13"),
+    ("F14", "F14"),
+    ("F15", "F15"),
+    ("F16", "F16"),
+    ("F17", "F17"),
+    ("F18", "F18"),
+    ("F19", "F19"),
+    ("F20", "F20"),
+    ("F21", "F21"),
+    ("F22", "F22"),
+    ("F23", "F23"),
+    ("F24", "F24"),
+    ("EXECUTE", "Execute"),
+    ("HELP", "Help"),
+    ("MENU", "Menu"),
+    ("SELECT", "Select"),
+    ("STOP", "Stop"),
+    ("AGAIN", "Again"),
+    ("UNDO", "Undo"),
+    ("CUT", "Cut"),
+    ("COPY", "Copy"),
+    ("PASTE", "Paste"),
+    ("FIND", "Find"),
+    ("MUTE", "Mute"),
+    ("VOLUMEUP", "VolumeUp"),
+    ("VOLUMEDOWN", "VolumeDown"),
+    ("KP_COMMA", "Keypad ,"),
+    ("KP_EQUALSAS400", "Keypad = (AS400)"),
+    ("INTERNATIONAL1", "International 1"),
+    ("INTERNATIONAL2", "International 2"),
+    ("INTERNATIONAL3", "International 3"),
+    ("INTERNATIONAL4", "International 4"),
+    ("INTERNATIONAL5", "International 5"),
+    ("INTERNATIONAL6", "International 6"),
+    ("INTERNATIONAL7", "International 7"),
+    ("INTERNATIONAL8", "International 8"),
+    ("INTERNATIONAL9", "International 9"),
+    ("LANG1", "Language 1"),
+    ("LANG2", "Language 2"),
+    ("LANG3", "Language 3"),
+    ("LANG4", "Language 4"),
+    ("LANG5", "Language 5"),
+    ("LANG6", "Language 6"),
+    ("LANG7", "Language 7"),
+    ("LANG8", "Language 8"),
+    ("LANG9", "Language 9"),
+    ("ALTERASE", "AltErase"),
+    ("SYSREQ", "SysReq"),
+    ("CANCEL", "Cancel"),
+    ("CLEAR", "Clear"),
+    ("PRIOR", "Prior"),
+    ("RETURN2", "Return"),
+    ("SEPARATOR", "Separator"),
+    ("OUT", "Out"),
+    ("OPER", "Oper"),
+    ("CLEARAGAIN", "Clear / Again"),
+    ("CRSEL", "CrSel"),
+    ("EXSEL", "ExSel"),
+    ("KP_00", "Keypad 00"),
+    ("KP_000", "Keypad 000"),
+    ("THOUSANDSSEPARATOR", "ThousandsSeparator"),
+    ("DECIMALSEPARATOR", "DecimalSeparator"),
+    ("CURRENCYUNIT", "CurrencyUnit"),
+    ("CURRENCYSUBUNIT", "CurrencySubUnit"),
+    ("KP_LEFTPAREN", "Keypad ("),
+    ("KP_RIGHTPAREN", "Keypad )"),
+    ("KP_LEFTBRACE", "Keypad {"),
+    ("KP_RIGHTBRACE", "Keypad }"),
+    ("KP_TAB", "Keypad Tab"),
+    ("KP_BACKSPACE", "Keypad Backspace"),
+    ("KP_A", "Keypad A"),
+    ("KP_B", "Keypad B"),
+    ("KP_C", "Keypad C"),
+    ("KP_D", "Keypad D"),
+    ("KP_E", "Keypad E"),
+    ("KP_F", "Keypad F"),
+    ("KP_XOR", "Keypad XOR"),
+    ("KP_POWER", "Keypad ^"),
+    ("KP_PERCENT", "Keypad %"),
+    ("KP_LESS", "Keypad <"),
+    ("KP_GREATER", "Keypad >"),
+    ("KP_AMPERSAND", "Keypad &"),
+    ("KP_DBLAMPERSAND", "Keypad &&"),
+    ("KP_VERTICALBAR", "Keypad |"),
+    ("KP_DBLVERTICALBAR", "Keypad ||"),
+    ("KP_COLON", "Keypad :"),
+    ("KP_HASH", "Keypad #"),
+    ("KP_SPACE", "Keypad Space"),
+    ("KP_AT", "Keypad @"),
+    ("KP_EXCLAM", "Keypad !"),
+    ("KP_MEMSTORE", "Keypad MemStore"),
+    ("KP_MEMRECALL", "Keypad MemRecall"),
+    ("KP_MEMCLEAR", "Keypad MemClear"),
+    ("KP_MEMADD", "Keypad MemAdd"),
+    ("KP_MEMSUBTRACT", "Keypad MemSubtract"),
+    ("KP_MEMMULTIPLY", "Keypad MemMultiply"),
+    ("KP_MEMDIVIDE", "Keypad MemDivide"),
+    ("KP_PLUSMINUS", "Keypad +/-"),
+    ("KP_CLEAR", "Keypad Clear"),
+    ("KP_CLEARENTRY", "Keypad ClearEntry"),
+    ("KP_BINARY", "Keypad Binary"),
+    ("KP_OCTAL", "Keypad Octal"),
+    ("KP_DECIMAL", "Keypad Decimal"),
+    ("KP_HEXADECIMAL", "Keypad Hexadecimal"),
+    ("LCTRL", "Left Ctrl"),
+    ("LSHIFT", "Left Shift"),
+    ("LALT", "Left Alt"),
+    ("LGUI", "Left GUI"),
+    ("RCTRL", "Right Ctrl"),
+    ("RSHIFT", "Right Shift"),
+    ("RALT", "Right Alt"),
+    ("RGUI", "Right GUI"),
+    ("MODE", "ModeSwitch"),
+    ("SLEEP", "Sleep"),
+    ("WAKE", "Wake"),
+    ("CHANNEL_INCREMENT", "ChannelUp"),
+    ("CHANNEL_DECREMENT", "ChannelDown"),
+    ("MEDIA_PLAY", "MediaPlay"),
+    ("MEDIA_PAUSE", "MediaPause"),
+    ("MEDIA_RECORD", "MediaRecord"),
+    ("MEDIA_FAST_FORWARD", "MediaFastForward"),
+    ("MEDIA_REWIND", "MediaRewind"),
+    ("MEDIA_NEXT_TRACK", "MediaTrackNext"),
+    ("MEDIA_PREVIOUS_TRACK", "MediaTrackPrevious"),
+    ("MEDIA_STOP", "MediaStop"),
+    ("MEDIA_EJECT", "Eject"),
+    ("MEDIA_PLAY_PAUSE", "MediaPlayPause"),
+    ("MEDIA_SELECT", "MediaSelect"),
+    ("AC_NEW", "AC New"),
+    ("AC_OPEN", "AC Open"),
+    ("AC_CLOSE", "AC Close"),
+    ("AC_EXIT", "AC Exit"),
+    ("AC_SAVE", "AC Save"),
+    ("AC_PRINT", "AC Print"),
+    ("AC_PROPERTIES", "AC Properties"),
+    ("AC_SEARCH", "AC Search"),
+    ("AC_HOME", "AC Home"),
+    ("AC_BACK", "AC Back"),
+    ("AC_FORWARD", "AC Forward"),
+    ("AC_STOP", "AC Stop"),
+    ("AC_REFRESH", "AC Refresh"),
+    ("AC_BOOKMARKS", "AC Bookmarks"),
+    ("SOFTLEFT", "SoftLeft"),
+    ("SOFTRIGHT", "SoftRight"),
+    ("CALL", "Call"),
+    ("ENDCALL", "EndCall"),
+];
+
+// SDL's default keymap (`SDL_GetDefaultKeyFromScancode`)
+const US_KEYMAP: &[(&str, char, char)] = &[
+    ("A", 'a', 'A'),
+    ("B", 'b', 'B'),
+    ("C", 'c', 'C'),
+    ("D", 'd', 'D'),
+    ("E", 'e', 'E'),
+    ("F", 'f', 'F'),
+    ("G", 'g', 'G'),
+    ("H", 'h', 'H'),
+    ("I", 'i', 'I'),
+    ("J", 'j', 'J'),
+    ("K", 'k', 'K'),
+    ("L", 'l', 'L'),
+    ("M", 'm', 'M'),
+    ("N", 'n', 'N'),
+    ("O", 'o', 'O'),
+    ("P", 'p', 'P'),
+    ("Q", 'q', 'Q'),
+    ("R", 'r', 'R'),
+    ("S", 's', 'S'),
+    ("T", 't', 'T'),
+    ("U", 'u', 'U'),
+    ("V", 'v', 'V'),
+    ("W", 'w', 'W'),
+    ("X", 'x', 'X'),
+    ("Y", 'y', 'Y'),
+    ("Z", 'z', 'Z'),
+    ("1", '1', '!'),
+    ("2", '2', '@'),
+    ("3", '3', '#'),
+    ("4", '4', '$'),
+    ("5", '5', '%'),
+    ("6", '6', '^'),
+    ("7", '7', '&'),
+    ("8", '8', '*'),
+    ("9", '9', '('),
+    ("0", '0', ')'),
+    ("RETURN", '\r', '\r'),
+    ("ESCAPE", '\x1b', '\x1b'),
+    ("BACKSPACE", '\x08', '\x08'),
+    ("TAB", '\t', '\t'),
+    ("SPACE", ' ', ' '),
+    ("MINUS", '-', '_'),
+    ("EQUALS", '=', '+'),
+    ("LEFTBRACKET", '[', '{'),
+    ("RIGHTBRACKET", ']', '}'),
+    ("BACKSLASH", '\\', '|'),
+    ("NONUSHASH", '#', '#'),
+    ("SEMICOLON", ';', ':'),
+    ("APOSTROPHE", '\'', '"'),
+    ("GRAVE", '`', '~'),
+    ("COMMA", ',', '<'),
+    ("PERIOD", '.', '>'),
+    ("SLASH", '/', '?'),
+    ("DELETE", '\x7f', '\x7f'),
+];
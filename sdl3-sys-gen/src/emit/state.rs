@@ -4,7 +4,7 @@ use crate::{
         CanCopy, DefineArg, DefineValue, DocComment, Expr, GetSpan, Ident, IdentOrKw, ParseErr,
         PrimitiveType, RustCode, Span, StructFields, StructKind, Type, TypeEnum,
     },
-    Defer, Gen,
+    Defer, Gen, SdlVersion,
 };
 use core::{fmt::Display, mem};
 use std::{
@@ -477,6 +477,23 @@ impl<'a, 'b> EmitContext<'a, 'b> {
         self.inner_map(|ctx| ctx.module.as_str())
     }
 
+    /// Record the availability of a function in the current module from its doc comment.
+    /// Functions without a documented version are recorded as unknown
+    pub fn record_availability(
+        &self,
+        function: &str,
+        doc: Option<&DocComment>,
+    ) -> Option<SdlVersion> {
+        // prerelease headers document functions that are new in them with the
+        // version of the next release
+        let since = doc
+            .and_then(|doc| doc.available_since())
+            .map(|since| since.min(self.gen.header_version()));
+        self.gen
+            .record_availability(&self.module(), function, since);
+        since
+    }
+
     pub fn preproc_state(&self) -> Rc<RefCell<PreProcState>> {
         Rc::clone(&self.inner().preproc_state)
     }
@@ -67,6 +67,37 @@ fn skip_emit(_module: &str) -> bool {
     false
 }
 
+/// SDL versions that can be selected as the minimum supported version with the `sdl-X-Y-Z`
+/// features of `sdl3-sys`. Functions that are newer than the selected version are disabled.
+/// These features must also be listed in `sdl3-sys/Cargo.toml`.
+pub const MIN_VERSION_FEATURES: &[SdlVersion] = &[SdlVersion(3, 1, 3), SdlVersion(3, 1, 6)];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct SdlVersion(pub u32, pub u32, pub u32);
+
+impl SdlVersion {
+    /// Parse a version like `3.1.6` at the start of `s`
+    pub fn parse_prefix(s: &str) -> Option<Self> {
+        let mut parts = s.splitn(3, '.').map(|part| {
+            let end = part
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(part.len());
+            part[..end].parse().ok()
+        });
+        Some(Self(parts.next()??, parts.next()??, parts.next()??))
+    }
+
+    pub fn feature(self) -> String {
+        format!("sdl-{}-{}-{}", self.0, self.1, self.2)
+    }
+}
+
+impl Display for SdlVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.0, self.1, self.2)
+    }
+}
+
 fn format_and_write(input: String, path: &Path) -> Result<(), Error> {
     let mut fmt = Command::new("rustfmt")
         .arg("--edition")
@@ -245,7 +276,16 @@ pub fn generate(source_crate_path: &Path, target_crate_path: &Path) -> Result<()
         }],
     )?;
 
-    let mut gen = Gen::new(headers_path.clone(), output_path.to_owned(), revision)?;
+    let Some(header_version) = SdlVersion::parse_prefix(version) else {
+        return Err("couldn't parse SDL version".into());
+    };
+
+    let mut gen = Gen::new(
+        headers_path.clone(),
+        output_path.to_owned(),
+        revision,
+        header_version,
+    )?;
 
     for entry in read_dir(headers_path)? {
         let entry = entry?;
@@ -280,6 +320,7 @@ pub fn generate(source_crate_path: &Path, target_crate_path: &Path) -> Result<()
         gen.emit(module)?;
     }
     gen.emit_top_level()?; // rewrite final mod.rs in sorted order
+    gen.write_availability(&target_crate_path.join("availability.csv"))?;
 
     Ok(())
 }
@@ -290,6 +331,8 @@ pub struct Gen {
     parsed: BTreeMap<String, Items>,
     emitted: RefCell<BTreeMap<String, InnerEmitContext>>,
     skipped: RefCell<HashSet<String>>,
+    availability: RefCell<BTreeMap<String, (String, Option<SdlVersion>)>>,
+    header_version: SdlVersion,
     headers_path: PathBuf,
     output_path: PathBuf,
 }
@@ -299,6 +342,7 @@ impl Gen {
         headers_path: PathBuf,
         output_path: PathBuf,
         revision: String,
+        header_version: SdlVersion,
     ) -> Result<Self, Error> {
         DirBuilder::new().recursive(true).create(&output_path)?;
         Ok(Self {
@@ -306,6 +350,8 @@ impl Gen {
             parsed: BTreeMap::new(),
             emitted: RefCell::new(BTreeMap::new()),
             skipped: RefCell::new(HashSet::new()),
+            availability: RefCell::new(BTreeMap::new()),
+            header_version,
             headers_path,
             output_path,
         })
@@ -356,6 +402,7 @@ impl Gen {
                 DefineValue::parse_expr(&format!("{:?}", self.revision))?,
             )?;
             self.parsed[module].emit(&mut ctx)?;
+            emit::patch_emit_module_end(&mut ctx, &self.parsed[module])?;
             let emitted = ctx.into_inner();
             writeln!(output)?;
             writeln!(output, "#[cfg(doc)]")?;
@@ -410,6 +457,35 @@ impl Gen {
         Ok(())
     }
 
+    /// The version of the SDL headers
+    pub fn header_version(&self) -> SdlVersion {
+        self.header_version
+    }
+
+    /// Record the version a function is available since, or `None` if it isn't documented.
+    /// Undocumented alternative definitions don't replace a documented version
+    pub fn record_availability(&self, module: &str, function: &str, since: Option<SdlVersion>) {
+        let mut availability = self.availability.borrow_mut();
+        if since.is_some() || !availability.contains_key(function) {
+            availability.insert(function.to_owned(), (module.to_owned(), since));
+        }
+    }
+
+    /// Write the version each function is available since as csv, sorted by function name
+    pub fn write_availability(&self, path: &Path) -> Result<(), Error> {
+        let mut output = String::new();
+        writeln!(output, "function,module,since")?;
+        for (function, (module, since)) in self.availability.borrow().iter() {
+            if let Some(since) = since {
+                writeln!(output, "{function},{module},{since}")?;
+            } else {
+                writeln!(output, "{function},{module},unknown")?;
+            }
+        }
+        fs::write(path, output)?;
+        Ok(())
+    }
+
     fn patch_module(&self, module: &str, input: String) -> String {
         match module {
             "main" => LinesPatch {
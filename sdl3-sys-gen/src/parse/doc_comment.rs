@@ -1,5 +1,5 @@
 use super::{GetSpan, Parse, ParseContext, ParseErr, ParseRawRes, ParseRev, Span, WsAndComments};
-use crate::common_doc_prefix;
+use crate::{common_doc_prefix, SdlVersion};
 use std::{
     borrow::Cow,
     fmt::{self, Display},
@@ -134,6 +134,13 @@ impl DocComment {
             Ok(pre)
         }
     }
+
+    /// Get the version from "available since SDL X.Y.Z", if the comment has it
+    pub fn available_since(&self) -> Option<SdlVersion> {
+        const MATCH: &str = "available since SDL ";
+        let doc = self.doc.as_str();
+        SdlVersion::parse_prefix(&doc[doc.find(MATCH)? + MATCH.len()..])
+    }
 }
 
 impl Parse for DocComment {
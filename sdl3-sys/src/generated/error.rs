@@ -77,6 +77,7 @@ extern "C" {
     /// - [`SDL_ClearError`]
     /// - [`SDL_GetError`]
     /// - [`SDL_SetError`]
+    #[cfg(any(not(feature = "sdl-3-1-3"), doc))]
     pub fn SDL_SetErrorV(
         fmt: *const ::core::ffi::c_char,
         ap: crate::ffi::VaList,
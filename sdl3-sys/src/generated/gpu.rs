@@ -5490,6 +5490,7 @@ extern "C" {
     /// ### See also
     /// - [`SDL_AcquireGPUCommandBuffer`]
     /// - [`SDL_AcquireGPUSwapchainTexture`]
+    #[cfg(any(not(feature = "sdl-3-1-3"), doc))]
     pub fn SDL_CancelGPUCommandBuffer(
         command_buffer: *mut SDL_GPUCommandBuffer,
     ) -> ::core::primitive::bool;
@@ -5644,6 +5645,7 @@ extern "C" {
     ///
     /// ### Availability
     /// This function is available since SDL 3.2.0.
+    #[cfg(any(not(feature = "sdl-3-1-3"), doc))]
     pub fn SDL_CalculateGPUTextureFormatSize(
         format: SDL_GPUTextureFormat,
         width: Uint32,
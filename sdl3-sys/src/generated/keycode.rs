@@ -1104,5 +1104,357 @@ pub const SDL_KMOD_ALT: SDL_Keymod = (SDL_KMOD_LALT | SDL_KMOD_RALT);
 /// Any GUI key is down.
 pub const SDL_KMOD_GUI: SDL_Keymod = (SDL_KMOD_LGUI | SDL_KMOD_RGUI);
 
+/// (`sdl3-sys`) All the keycodes defined by SDL, excluding [`SDLK_UNKNOWN`].
+pub const SDL_KEYCODES: &[SDL_Keycode] = &[
+    SDLK_RETURN,
+    SDLK_ESCAPE,
+    SDLK_BACKSPACE,
+    SDLK_TAB,
+    SDLK_SPACE,
+    SDLK_EXCLAIM,
+    SDLK_DBLAPOSTROPHE,
+    SDLK_HASH,
+    SDLK_DOLLAR,
+    SDLK_PERCENT,
+    SDLK_AMPERSAND,
+    SDLK_APOSTROPHE,
+    SDLK_LEFTPAREN,
+    SDLK_RIGHTPAREN,
+    SDLK_ASTERISK,
+    SDLK_PLUS,
+    SDLK_COMMA,
+    SDLK_MINUS,
+    SDLK_PERIOD,
+    SDLK_SLASH,
+    SDLK_0,
+    SDLK_1,
+    SDLK_2,
+    SDLK_3,
+    SDLK_4,
+    SDLK_5,
+    SDLK_6,
+    SDLK_7,
+    SDLK_8,
+    SDLK_9,
+    SDLK_COLON,
+    SDLK_SEMICOLON,
+    SDLK_LESS,
+    SDLK_EQUALS,
+    SDLK_GREATER,
+    SDLK_QUESTION,
+    SDLK_AT,
+    SDLK_LEFTBRACKET,
+    SDLK_BACKSLASH,
+    SDLK_RIGHTBRACKET,
+    SDLK_CARET,
+    SDLK_UNDERSCORE,
+    SDLK_GRAVE,
+    SDLK_A,
+    SDLK_B,
+    SDLK_C,
+    SDLK_D,
+    SDLK_E,
+    SDLK_F,
+    SDLK_G,
+    SDLK_H,
+    SDLK_I,
+    SDLK_J,
+    SDLK_K,
+    SDLK_L,
+    SDLK_M,
+    SDLK_N,
+    SDLK_O,
+    SDLK_P,
+    SDLK_Q,
+    SDLK_R,
+    SDLK_S,
+    SDLK_T,
+    SDLK_U,
+    SDLK_V,
+    SDLK_W,
+    SDLK_X,
+    SDLK_Y,
+    SDLK_Z,
+    SDLK_LEFTBRACE,
+    SDLK_PIPE,
+    SDLK_RIGHTBRACE,
+    SDLK_TILDE,
+    SDLK_DELETE,
+    SDLK_PLUSMINUS,
+    SDLK_CAPSLOCK,
+    SDLK_F1,
+    SDLK_F2,
+    SDLK_F3,
+    SDLK_F4,
+    SDLK_F5,
+    SDLK_F6,
+    SDLK_F7,
+    SDLK_F8,
+    SDLK_F9,
+    SDLK_F10,
+    SDLK_F11,
+    SDLK_F12,
+    SDLK_PRINTSCREEN,
+    SDLK_SCROLLLOCK,
+    SDLK_PAUSE,
+    SDLK_INSERT,
+    SDLK_HOME,
+    SDLK_PAGEUP,
+    SDLK_END,
+    SDLK_PAGEDOWN,
+    SDLK_RIGHT,
+    SDLK_LEFT,
+    SDLK_DOWN,
+    SDLK_UP,
+    SDLK_NUMLOCKCLEAR,
+    SDLK_KP_DIVIDE,
+    SDLK_KP_MULTIPLY,
+    SDLK_KP_MINUS,
+    SDLK_KP_PLUS,
+    SDLK_KP_ENTER,
+    SDLK_KP_1,
+    SDLK_KP_2,
+    SDLK_KP_3,
+    SDLK_KP_4,
+    SDLK_KP_5,
+    SDLK_KP_6,
+    SDLK_KP_7,
+    SDLK_KP_8,
+    SDLK_KP_9,
+    SDLK_KP_0,
+    SDLK_KP_PERIOD,
+    SDLK_APPLICATION,
+    SDLK_POWER,
+    SDLK_KP_EQUALS,
+    SDLK_F13,
+    SDLK_F14,
+    SDLK_F15,
+    SDLK_F16,
+    SDLK_F17,
+    SDLK_F18,
+    SDLK_F19,
+    SDLK_F20,
+    SDLK_F21,
+    SDLK_F22,
+    SDLK_F23,
+    SDLK_F24,
+    SDLK_EXECUTE,
+    SDLK_HELP,
+    SDLK_MENU,
+    SDLK_SELECT,
+    SDLK_STOP,
+    SDLK_AGAIN,
+    SDLK_UNDO,
+    SDLK_CUT,
+    SDLK_COPY,
+    SDLK_PASTE,
+    SDLK_FIND,
+    SDLK_MUTE,
+    SDLK_VOLUMEUP,
+    SDLK_VOLUMEDOWN,
+    SDLK_KP_COMMA,
+    SDLK_KP_EQUALSAS400,
+    SDLK_ALTERASE,
+    SDLK_SYSREQ,
+    SDLK_CANCEL,
+    SDLK_CLEAR,
+    SDLK_PRIOR,
+    SDLK_RETURN2,
+    SDLK_SEPARATOR,
+    SDLK_OUT,
+    SDLK_OPER,
+    SDLK_CLEARAGAIN,
+    SDLK_CRSEL,
+    SDLK_EXSEL,
+    SDLK_KP_00,
+    SDLK_KP_000,
+    SDLK_THOUSANDSSEPARATOR,
+    SDLK_DECIMALSEPARATOR,
+    SDLK_CURRENCYUNIT,
+    SDLK_CURRENCYSUBUNIT,
+    SDLK_KP_LEFTPAREN,
+    SDLK_KP_RIGHTPAREN,
+    SDLK_KP_LEFTBRACE,
+    SDLK_KP_RIGHTBRACE,
+    SDLK_KP_TAB,
+    SDLK_KP_BACKSPACE,
+    SDLK_KP_A,
+    SDLK_KP_B,
+    SDLK_KP_C,
+    SDLK_KP_D,
+    SDLK_KP_E,
+    SDLK_KP_F,
+    SDLK_KP_XOR,
+    SDLK_KP_POWER,
+    SDLK_KP_PERCENT,
+    SDLK_KP_LESS,
+    SDLK_KP_GREATER,
+    SDLK_KP_AMPERSAND,
+    SDLK_KP_DBLAMPERSAND,
+    SDLK_KP_VERTICALBAR,
+    SDLK_KP_DBLVERTICALBAR,
+    SDLK_KP_COLON,
+    SDLK_KP_HASH,
+    SDLK_KP_SPACE,
+    SDLK_KP_AT,
+    SDLK_KP_EXCLAM,
+    SDLK_KP_MEMSTORE,
+    SDLK_KP_MEMRECALL,
+    SDLK_KP_MEMCLEAR,
+    SDLK_KP_MEMADD,
+    SDLK_KP_MEMSUBTRACT,
+    SDLK_KP_MEMMULTIPLY,
+    SDLK_KP_MEMDIVIDE,
+    SDLK_KP_PLUSMINUS,
+    SDLK_KP_CLEAR,
+    SDLK_KP_CLEARENTRY,
+    SDLK_KP_BINARY,
+    SDLK_KP_OCTAL,
+    SDLK_KP_DECIMAL,
+    SDLK_KP_HEXADECIMAL,
+    SDLK_LCTRL,
+    SDLK_LSHIFT,
+    SDLK_LALT,
+    SDLK_LGUI,
+    SDLK_RCTRL,
+    SDLK_RSHIFT,
+    SDLK_RALT,
+    SDLK_RGUI,
+    SDLK_MODE,
+    SDLK_SLEEP,
+    SDLK_WAKE,
+    SDLK_CHANNEL_INCREMENT,
+    SDLK_CHANNEL_DECREMENT,
+    SDLK_MEDIA_PLAY,
+    SDLK_MEDIA_PAUSE,
+    SDLK_MEDIA_RECORD,
+    SDLK_MEDIA_FAST_FORWARD,
+    SDLK_MEDIA_REWIND,
+    SDLK_MEDIA_NEXT_TRACK,
+    SDLK_MEDIA_PREVIOUS_TRACK,
+    SDLK_MEDIA_STOP,
+    SDLK_MEDIA_EJECT,
+    SDLK_MEDIA_PLAY_PAUSE,
+    SDLK_MEDIA_SELECT,
+    SDLK_AC_NEW,
+    SDLK_AC_OPEN,
+    SDLK_AC_CLOSE,
+    SDLK_AC_EXIT,
+    SDLK_AC_SAVE,
+    SDLK_AC_PRINT,
+    SDLK_AC_PROPERTIES,
+    SDLK_AC_SEARCH,
+    SDLK_AC_HOME,
+    SDLK_AC_BACK,
+    SDLK_AC_FORWARD,
+    SDLK_AC_STOP,
+    SDLK_AC_REFRESH,
+    SDLK_AC_BOOKMARKS,
+    SDLK_SOFTLEFT,
+    SDLK_SOFTRIGHT,
+    SDLK_CALL,
+    SDLK_ENDCALL,
+];
+
+/// (`sdl3-sys`) The keycodes SDL's default US keyboard layout produces for each scancode, as `(scancode, keycode, shifted keycode)`.
+///
+/// Scancodes that aren't listed here map to [`SDL_SCANCODE_TO_KEYCODE`] of the scancode, with or without shift.
+pub const SDL_US_KEYMAP: &[(SDL_Scancode, SDL_Keycode, SDL_Keycode)] = &[
+    (SDL_SCANCODE_A, 'a' as SDL_Keycode, 'A' as SDL_Keycode),
+    (SDL_SCANCODE_B, 'b' as SDL_Keycode, 'B' as SDL_Keycode),
+    (SDL_SCANCODE_C, 'c' as SDL_Keycode, 'C' as SDL_Keycode),
+    (SDL_SCANCODE_D, 'd' as SDL_Keycode, 'D' as SDL_Keycode),
+    (SDL_SCANCODE_E, 'e' as SDL_Keycode, 'E' as SDL_Keycode),
+    (SDL_SCANCODE_F, 'f' as SDL_Keycode, 'F' as SDL_Keycode),
+    (SDL_SCANCODE_G, 'g' as SDL_Keycode, 'G' as SDL_Keycode),
+    (SDL_SCANCODE_H, 'h' as SDL_Keycode, 'H' as SDL_Keycode),
+    (SDL_SCANCODE_I, 'i' as SDL_Keycode, 'I' as SDL_Keycode),
+    (SDL_SCANCODE_J, 'j' as SDL_Keycode, 'J' as SDL_Keycode),
+    (SDL_SCANCODE_K, 'k' as SDL_Keycode, 'K' as SDL_Keycode),
+    (SDL_SCANCODE_L, 'l' as SDL_Keycode, 'L' as SDL_Keycode),
+    (SDL_SCANCODE_M, 'm' as SDL_Keycode, 'M' as SDL_Keycode),
+    (SDL_SCANCODE_N, 'n' as SDL_Keycode, 'N' as SDL_Keycode),
+    (SDL_SCANCODE_O, 'o' as SDL_Keycode, 'O' as SDL_Keycode),
+    (SDL_SCANCODE_P, 'p' as SDL_Keycode, 'P' as SDL_Keycode),
+    (SDL_SCANCODE_Q, 'q' as SDL_Keycode, 'Q' as SDL_Keycode),
+    (SDL_SCANCODE_R, 'r' as SDL_Keycode, 'R' as SDL_Keycode),
+    (SDL_SCANCODE_S, 's' as SDL_Keycode, 'S' as SDL_Keycode),
+    (SDL_SCANCODE_T, 't' as SDL_Keycode, 'T' as SDL_Keycode),
+    (SDL_SCANCODE_U, 'u' as SDL_Keycode, 'U' as SDL_Keycode),
+    (SDL_SCANCODE_V, 'v' as SDL_Keycode, 'V' as SDL_Keycode),
+    (SDL_SCANCODE_W, 'w' as SDL_Keycode, 'W' as SDL_Keycode),
+    (SDL_SCANCODE_X, 'x' as SDL_Keycode, 'X' as SDL_Keycode),
+    (SDL_SCANCODE_Y, 'y' as SDL_Keycode, 'Y' as SDL_Keycode),
+    (SDL_SCANCODE_Z, 'z' as SDL_Keycode, 'Z' as SDL_Keycode),
+    (SDL_SCANCODE_1, '1' as SDL_Keycode, '!' as SDL_Keycode),
+    (SDL_SCANCODE_2, '2' as SDL_Keycode, '@' as SDL_Keycode),
+    (SDL_SCANCODE_3, '3' as SDL_Keycode, '#' as SDL_Keycode),
+    (SDL_SCANCODE_4, '4' as SDL_Keycode, '$' as SDL_Keycode),
+    (SDL_SCANCODE_5, '5' as SDL_Keycode, '%' as SDL_Keycode),
+    (SDL_SCANCODE_6, '6' as SDL_Keycode, '^' as SDL_Keycode),
+    (SDL_SCANCODE_7, '7' as SDL_Keycode, '&' as SDL_Keycode),
+    (SDL_SCANCODE_8, '8' as SDL_Keycode, '*' as SDL_Keycode),
+    (SDL_SCANCODE_9, '9' as SDL_Keycode, '(' as SDL_Keycode),
+    (SDL_SCANCODE_0, '0' as SDL_Keycode, ')' as SDL_Keycode),
+    (
+        SDL_SCANCODE_RETURN,
+        '\r' as SDL_Keycode,
+        '\r' as SDL_Keycode,
+    ),
+    (
+        SDL_SCANCODE_ESCAPE,
+        '\u{1b}' as SDL_Keycode,
+        '\u{1b}' as SDL_Keycode,
+    ),
+    (
+        SDL_SCANCODE_BACKSPACE,
+        '\u{8}' as SDL_Keycode,
+        '\u{8}' as SDL_Keycode,
+    ),
+    (SDL_SCANCODE_TAB, '\t' as SDL_Keycode, '\t' as SDL_Keycode),
+    (SDL_SCANCODE_SPACE, ' ' as SDL_Keycode, ' ' as SDL_Keycode),
+    (SDL_SCANCODE_MINUS, '-' as SDL_Keycode, '_' as SDL_Keycode),
+    (SDL_SCANCODE_EQUALS, '=' as SDL_Keycode, '+' as SDL_Keycode),
+    (
+        SDL_SCANCODE_LEFTBRACKET,
+        '[' as SDL_Keycode,
+        '{' as SDL_Keycode,
+    ),
+    (
+        SDL_SCANCODE_RIGHTBRACKET,
+        ']' as SDL_Keycode,
+        '}' as SDL_Keycode,
+    ),
+    (
+        SDL_SCANCODE_BACKSLASH,
+        '\\' as SDL_Keycode,
+        '|' as SDL_Keycode,
+    ),
+    (
+        SDL_SCANCODE_NONUSHASH,
+        '#' as SDL_Keycode,
+        '#' as SDL_Keycode,
+    ),
+    (
+        SDL_SCANCODE_SEMICOLON,
+        ';' as SDL_Keycode,
+        ':' as SDL_Keycode,
+    ),
+    (
+        SDL_SCANCODE_APOSTROPHE,
+        '\'' as SDL_Keycode,
+        '"' as SDL_Keycode,
+    ),
+    (SDL_SCANCODE_GRAVE, '`' as SDL_Keycode, '~' as SDL_Keycode),
+    (SDL_SCANCODE_COMMA, ',' as SDL_Keycode, '<' as SDL_Keycode),
+    (SDL_SCANCODE_PERIOD, '.' as SDL_Keycode, '>' as SDL_Keycode),
+    (SDL_SCANCODE_SLASH, '/' as SDL_Keycode, '?' as SDL_Keycode),
+    (
+        SDL_SCANCODE_DELETE,
+        '\u{7f}' as SDL_Keycode,
+        '\u{7f}' as SDL_Keycode,
+    ),
+];
+
 #[cfg(doc)]
 use crate::everything::*;
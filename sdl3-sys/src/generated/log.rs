@@ -654,6 +654,7 @@ extern "C" {
     /// ### See also
     /// - [`SDL_SetLogOutputFunction`]
     /// - [`SDL_GetLogOutputFunction`]
+    #[cfg(any(not(feature = "sdl-3-1-3"), doc))]
     pub fn SDL_GetDefaultLogOutputFunction() -> SDL_LogOutputFunction;
 }
 
@@ -3426,6 +3426,7 @@ extern "C" {
     ///
     /// ### See also
     /// - [`SDL_DEBUG_TEXT_FONT_CHARACTER_SIZE`]
+    #[cfg(any(not(feature = "sdl-3-1-3"), doc))]
     pub fn SDL_RenderDebugText(
         renderer: *mut SDL_Renderer,
         x: ::core::ffi::c_float,
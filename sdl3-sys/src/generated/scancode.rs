@@ -1294,5 +1294,257 @@ pub const SDL_SCANCODE_RESERVED: SDL_Scancode = SDL_Scancode::RESERVED;
 /// not a key, just marks the number of scancodes for array bounds
 pub const SDL_SCANCODE_COUNT: SDL_Scancode = SDL_Scancode::COUNT;
 
+/// (`sdl3-sys`) Names of the named scancodes, in scancode order. These are the same names that [`SDL_GetScancodeName`] returns.
+///
+/// Some scancodes share a name; [`SDL_GetScancodeFromName`] returns the first match.
+pub const SDL_SCANCODE_NAMES: &[(SDL_Scancode, &::core::primitive::str)] = &[
+    (SDL_SCANCODE_A, "A"),
+    (SDL_SCANCODE_B, "B"),
+    (SDL_SCANCODE_C, "C"),
+    (SDL_SCANCODE_D, "D"),
+    (SDL_SCANCODE_E, "E"),
+    (SDL_SCANCODE_F, "F"),
+    (SDL_SCANCODE_G, "G"),
+    (SDL_SCANCODE_H, "H"),
+    (SDL_SCANCODE_I, "I"),
+    (SDL_SCANCODE_J, "J"),
+    (SDL_SCANCODE_K, "K"),
+    (SDL_SCANCODE_L, "L"),
+    (SDL_SCANCODE_M, "M"),
+    (SDL_SCANCODE_N, "N"),
+    (SDL_SCANCODE_O, "O"),
+    (SDL_SCANCODE_P, "P"),
+    (SDL_SCANCODE_Q, "Q"),
+    (SDL_SCANCODE_R, "R"),
+    (SDL_SCANCODE_S, "S"),
+    (SDL_SCANCODE_T, "T"),
+    (SDL_SCANCODE_U, "U"),
+    (SDL_SCANCODE_V, "V"),
+    (SDL_SCANCODE_W, "W"),
+    (SDL_SCANCODE_X, "X"),
+    (SDL_SCANCODE_Y, "Y"),
+    (SDL_SCANCODE_Z, "Z"),
+    (SDL_SCANCODE_1, "1"),
+    (SDL_SCANCODE_2, "2"),
+    (SDL_SCANCODE_3, "3"),
+    (SDL_SCANCODE_4, "4"),
+    (SDL_SCANCODE_5, "5"),
+    (SDL_SCANCODE_6, "6"),
+    (SDL_SCANCODE_7, "7"),
+    (SDL_SCANCODE_8, "8"),
+    (SDL_SCANCODE_9, "9"),
+    (SDL_SCANCODE_0, "0"),
+    (SDL_SCANCODE_RETURN, "Return"),
+    (SDL_SCANCODE_ESCAPE, "Escape"),
+    (SDL_SCANCODE_BACKSPACE, "Backspace"),
+    (SDL_SCANCODE_TAB, "Tab"),
+    (SDL_SCANCODE_SPACE, "Space"),
+    (SDL_SCANCODE_MINUS, "-"),
+    (SDL_SCANCODE_EQUALS, "="),
+    (SDL_SCANCODE_LEFTBRACKET, "["),
+    (SDL_SCANCODE_RIGHTBRACKET, "]"),
+    (SDL_SCANCODE_BACKSLASH, "\\"),
+    (SDL_SCANCODE_NONUSHASH, "#"),
+    (SDL_SCANCODE_SEMICOLON, ";"),
+    (SDL_SCANCODE_APOSTROPHE, "'"),
+    (SDL_SCANCODE_GRAVE, "`"),
+    (SDL_SCANCODE_COMMA, ","),
+    (SDL_SCANCODE_PERIOD, "."),
+    (SDL_SCANCODE_SLASH, "/"),
+    (SDL_SCANCODE_CAPSLOCK, "CapsLock"),
+    (SDL_SCANCODE_F1, "F1"),
+    (SDL_SCANCODE_F2, "F2"),
+    (SDL_SCANCODE_F3, "F3"),
+    (SDL_SCANCODE_F4, "F4"),
+    (SDL_SCANCODE_F5, "F5"),
+    (SDL_SCANCODE_F6, "F6"),
+    (SDL_SCANCODE_F7, "F7"),
+    (SDL_SCANCODE_F8, "F8"),
+    (SDL_SCANCODE_F9, "F9"),
+    (SDL_SCANCODE_F10, "F10"),
+    (SDL_SCANCODE_F11, "F11"),
+    (SDL_SCANCODE_F12, "F12"),
+    (SDL_SCANCODE_PRINTSCREEN, "PrintScreen"),
+    (SDL_SCANCODE_SCROLLLOCK, "ScrollLock"),
+    (SDL_SCANCODE_PAUSE, "Pause"),
+    (SDL_SCANCODE_INSERT, "Insert"),
+    (SDL_SCANCODE_HOME, "Home"),
+    (SDL_SCANCODE_PAGEUP, "PageUp"),
+    (SDL_SCANCODE_DELETE, "Delete"),
+    (SDL_SCANCODE_END, "End"),
+    (SDL_SCANCODE_PAGEDOWN, "PageDown"),
+    (SDL_SCANCODE_RIGHT, "Right"),
+    (SDL_SCANCODE_LEFT, "Left"),
+    (SDL_SCANCODE_DOWN, "Down"),
+    (SDL_SCANCODE_UP, "Up"),
+    (SDL_SCANCODE_NUMLOCKCLEAR, "Numlock"),
+    (SDL_SCANCODE_KP_DIVIDE, "Keypad /"),
+    (SDL_SCANCODE_KP_MULTIPLY, "Keypad *"),
+    (SDL_SCANCODE_KP_MINUS, "Keypad -"),
+    (SDL_SCANCODE_KP_PLUS, "Keypad +"),
+    (SDL_SCANCODE_KP_ENTER, "Keypad Enter"),
+    (SDL_SCANCODE_KP_1, "Keypad 1"),
+    (SDL_SCANCODE_KP_2, "Keypad 2"),
+    (SDL_SCANCODE_KP_3, "Keypad 3"),
+    (SDL_SCANCODE_KP_4, "Keypad 4"),
+    (SDL_SCANCODE_KP_5, "Keypad 5"),
+    (SDL_SCANCODE_KP_6, "Keypad 6"),
+    (SDL_SCANCODE_KP_7, "Keypad 7"),
+    (SDL_SCANCODE_KP_8, "Keypad 8"),
+    (SDL_SCANCODE_KP_9, "Keypad 9"),
+    (SDL_SCANCODE_KP_0, "Keypad 0"),
+    (SDL_SCANCODE_KP_PERIOD, "Keypad ."),
+    (SDL_SCANCODE_NONUSBACKSLASH, "NonUSBackslash"),
+    (SDL_SCANCODE_APPLICATION, "Application"),
+    (SDL_SCANCODE_POWER, "Power"),
+    (SDL_SCANCODE_KP_EQUALS, "Keypad ="),
+    (SDL_SCANCODE_F13, "F13"),
+    (SDL_SCANCODE_F14, "F14"),
+    (SDL_SCANCODE_F15, "F15"),
+    (SDL_SCANCODE_F16, "F16"),
+    (SDL_SCANCODE_F17, "F17"),
+    (SDL_SCANCODE_F18, "F18"),
+    (SDL_SCANCODE_F19, "F19"),
+    (SDL_SCANCODE_F20, "F20"),
+    (SDL_SCANCODE_F21, "F21"),
+    (SDL_SCANCODE_F22, "F22"),
+    (SDL_SCANCODE_F23, "F23"),
+    (SDL_SCANCODE_F24, "F24"),
+    (SDL_SCANCODE_EXECUTE, "Execute"),
+    (SDL_SCANCODE_HELP, "Help"),
+    (SDL_SCANCODE_MENU, "Menu"),
+    (SDL_SCANCODE_SELECT, "Select"),
+    (SDL_SCANCODE_STOP, "Stop"),
+    (SDL_SCANCODE_AGAIN, "Again"),
+    (SDL_SCANCODE_UNDO, "Undo"),
+    (SDL_SCANCODE_CUT, "Cut"),
+    (SDL_SCANCODE_COPY, "Copy"),
+    (SDL_SCANCODE_PASTE, "Paste"),
+    (SDL_SCANCODE_FIND, "Find"),
+    (SDL_SCANCODE_MUTE, "Mute"),
+    (SDL_SCANCODE_VOLUMEUP, "VolumeUp"),
+    (SDL_SCANCODE_VOLUMEDOWN, "VolumeDown"),
+    (SDL_SCANCODE_KP_COMMA, "Keypad ,"),
+    (SDL_SCANCODE_KP_EQUALSAS400, "Keypad = (AS400)"),
+    (SDL_SCANCODE_INTERNATIONAL1, "International 1"),
+    (SDL_SCANCODE_INTERNATIONAL2, "International 2"),
+    (SDL_SCANCODE_INTERNATIONAL3, "International 3"),
+    (SDL_SCANCODE_INTERNATIONAL4, "International 4"),
+    (SDL_SCANCODE_INTERNATIONAL5, "International 5"),
+    (SDL_SCANCODE_INTERNATIONAL6, "International 6"),
+    (SDL_SCANCODE_INTERNATIONAL7, "International 7"),
+    (SDL_SCANCODE_INTERNATIONAL8, "International 8"),
+    (SDL_SCANCODE_INTERNATIONAL9, "International 9"),
+    (SDL_SCANCODE_LANG1, "Language 1"),
+    (SDL_SCANCODE_LANG2, "Language 2"),
+    (SDL_SCANCODE_LANG3, "Language 3"),
+    (SDL_SCANCODE_LANG4, "Language 4"),
+    (SDL_SCANCODE_LANG5, "Language 5"),
+    (SDL_SCANCODE_LANG6, "Language 6"),
+    (SDL_SCANCODE_LANG7, "Language 7"),
+    (SDL_SCANCODE_LANG8, "Language 8"),
+    (SDL_SCANCODE_LANG9, "Language 9"),
+    (SDL_SCANCODE_ALTERASE, "AltErase"),
+    (SDL_SCANCODE_SYSREQ, "SysReq"),
+    (SDL_SCANCODE_CANCEL, "Cancel"),
+    (SDL_SCANCODE_CLEAR, "Clear"),
+    (SDL_SCANCODE_PRIOR, "Prior"),
+    (SDL_SCANCODE_RETURN2, "Return"),
+    (SDL_SCANCODE_SEPARATOR, "Separator"),
+    (SDL_SCANCODE_OUT, "Out"),
+    (SDL_SCANCODE_OPER, "Oper"),
+    (SDL_SCANCODE_CLEARAGAIN, "Clear / Again"),
+    (SDL_SCANCODE_CRSEL, "CrSel"),
+    (SDL_SCANCODE_EXSEL, "ExSel"),
+    (SDL_SCANCODE_KP_00, "Keypad 00"),
+    (SDL_SCANCODE_KP_000, "Keypad 000"),
+    (SDL_SCANCODE_THOUSANDSSEPARATOR, "ThousandsSeparator"),
+    (SDL_SCANCODE_DECIMALSEPARATOR, "DecimalSeparator"),
+    (SDL_SCANCODE_CURRENCYUNIT, "CurrencyUnit"),
+    (SDL_SCANCODE_CURRENCYSUBUNIT, "CurrencySubUnit"),
+    (SDL_SCANCODE_KP_LEFTPAREN, "Keypad ("),
+    (SDL_SCANCODE_KP_RIGHTPAREN, "Keypad )"),
+    (SDL_SCANCODE_KP_LEFTBRACE, "Keypad {"),
+    (SDL_SCANCODE_KP_RIGHTBRACE, "Keypad }"),
+    (SDL_SCANCODE_KP_TAB, "Keypad Tab"),
+    (SDL_SCANCODE_KP_BACKSPACE, "Keypad Backspace"),
+    (SDL_SCANCODE_KP_A, "Keypad A"),
+    (SDL_SCANCODE_KP_B, "Keypad B"),
+    (SDL_SCANCODE_KP_C, "Keypad C"),
+    (SDL_SCANCODE_KP_D, "Keypad D"),
+    (SDL_SCANCODE_KP_E, "Keypad E"),
+    (SDL_SCANCODE_KP_F, "Keypad F"),
+    (SDL_SCANCODE_KP_XOR, "Keypad XOR"),
+    (SDL_SCANCODE_KP_POWER, "Keypad ^"),
+    (SDL_SCANCODE_KP_PERCENT, "Keypad %"),
+    (SDL_SCANCODE_KP_LESS, "Keypad <"),
+    (SDL_SCANCODE_KP_GREATER, "Keypad >"),
+    (SDL_SCANCODE_KP_AMPERSAND, "Keypad &"),
+    (SDL_SCANCODE_KP_DBLAMPERSAND, "Keypad &&"),
+    (SDL_SCANCODE_KP_VERTICALBAR, "Keypad |"),
+    (SDL_SCANCODE_KP_DBLVERTICALBAR, "Keypad ||"),
+    (SDL_SCANCODE_KP_COLON, "Keypad :"),
+    (SDL_SCANCODE_KP_HASH, "Keypad #"),
+    (SDL_SCANCODE_KP_SPACE, "Keypad Space"),
+    (SDL_SCANCODE_KP_AT, "Keypad @"),
+    (SDL_SCANCODE_KP_EXCLAM, "Keypad !"),
+    (SDL_SCANCODE_KP_MEMSTORE, "Keypad MemStore"),
+    (SDL_SCANCODE_KP_MEMRECALL, "Keypad MemRecall"),
+    (SDL_SCANCODE_KP_MEMCLEAR, "Keypad MemClear"),
+    (SDL_SCANCODE_KP_MEMADD, "Keypad MemAdd"),
+    (SDL_SCANCODE_KP_MEMSUBTRACT, "Keypad MemSubtract"),
+    (SDL_SCANCODE_KP_MEMMULTIPLY, "Keypad MemMultiply"),
+    (SDL_SCANCODE_KP_MEMDIVIDE, "Keypad MemDivide"),
+    (SDL_SCANCODE_KP_PLUSMINUS, "Keypad +/-"),
+    (SDL_SCANCODE_KP_CLEAR, "Keypad Clear"),
+    (SDL_SCANCODE_KP_CLEARENTRY, "Keypad ClearEntry"),
+    (SDL_SCANCODE_KP_BINARY, "Keypad Binary"),
+    (SDL_SCANCODE_KP_OCTAL, "Keypad Octal"),
+    (SDL_SCANCODE_KP_DECIMAL, "Keypad Decimal"),
+    (SDL_SCANCODE_KP_HEXADECIMAL, "Keypad Hexadecimal"),
+    (SDL_SCANCODE_LCTRL, "Left Ctrl"),
+    (SDL_SCANCODE_LSHIFT, "Left Shift"),
+    (SDL_SCANCODE_LALT, "Left Alt"),
+    (SDL_SCANCODE_LGUI, "Left GUI"),
+    (SDL_SCANCODE_RCTRL, "Right Ctrl"),
+    (SDL_SCANCODE_RSHIFT, "Right Shift"),
+    (SDL_SCANCODE_RALT, "Right Alt"),
+    (SDL_SCANCODE_RGUI, "Right GUI"),
+    (SDL_SCANCODE_MODE, "ModeSwitch"),
+    (SDL_SCANCODE_SLEEP, "Sleep"),
+    (SDL_SCANCODE_WAKE, "Wake"),
+    (SDL_SCANCODE_CHANNEL_INCREMENT, "ChannelUp"),
+    (SDL_SCANCODE_CHANNEL_DECREMENT, "ChannelDown"),
+    (SDL_SCANCODE_MEDIA_PLAY, "MediaPlay"),
+    (SDL_SCANCODE_MEDIA_PAUSE, "MediaPause"),
+    (SDL_SCANCODE_MEDIA_RECORD, "MediaRecord"),
+    (SDL_SCANCODE_MEDIA_FAST_FORWARD, "MediaFastForward"),
+    (SDL_SCANCODE_MEDIA_REWIND, "MediaRewind"),
+    (SDL_SCANCODE_MEDIA_NEXT_TRACK, "MediaTrackNext"),
+    (SDL_SCANCODE_MEDIA_PREVIOUS_TRACK, "MediaTrackPrevious"),
+    (SDL_SCANCODE_MEDIA_STOP, "MediaStop"),
+    (SDL_SCANCODE_MEDIA_EJECT, "Eject"),
+    (SDL_SCANCODE_MEDIA_PLAY_PAUSE, "MediaPlayPause"),
+    (SDL_SCANCODE_MEDIA_SELECT, "MediaSelect"),
+    (SDL_SCANCODE_AC_NEW, "AC New"),
+    (SDL_SCANCODE_AC_OPEN, "AC Open"),
+    (SDL_SCANCODE_AC_CLOSE, "AC Close"),
+    (SDL_SCANCODE_AC_EXIT, "AC Exit"),
+    (SDL_SCANCODE_AC_SAVE, "AC Save"),
+    (SDL_SCANCODE_AC_PRINT, "AC Print"),
+    (SDL_SCANCODE_AC_PROPERTIES, "AC Properties"),
+    (SDL_SCANCODE_AC_SEARCH, "AC Search"),
+    (SDL_SCANCODE_AC_HOME, "AC Home"),
+    (SDL_SCANCODE_AC_BACK, "AC Back"),
+    (SDL_SCANCODE_AC_FORWARD, "AC Forward"),
+    (SDL_SCANCODE_AC_STOP, "AC Stop"),
+    (SDL_SCANCODE_AC_REFRESH, "AC Refresh"),
+    (SDL_SCANCODE_AC_BOOKMARKS, "AC Bookmarks"),
+    (SDL_SCANCODE_SOFTLEFT, "SoftLeft"),
+    (SDL_SCANCODE_SOFTRIGHT, "SoftRight"),
+    (SDL_SCANCODE_CALL, "Call"),
+    (SDL_SCANCODE_ENDCALL, "EndCall"),
+];
+
 #[cfg(doc)]
 use crate::everything::*;
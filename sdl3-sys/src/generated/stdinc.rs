@@ -3015,6 +3015,7 @@ extern "C" {
     ///
     /// ### Availability
     /// This function is available since SDL 3.2.0.
+    #[cfg(any(not(feature = "sdl-3-1-3"), doc))]
     pub fn SDL_StepBackUTF8(
         start: *const ::core::ffi::c_char,
         pstr: *mut *const ::core::ffi::c_char,
@@ -165,6 +165,7 @@ extern "C" {
     ///
     /// ### Availability
     /// This function is available since SDL 3.2.0.
+    #[cfg(any(not(feature = "sdl-3-1-3"), doc))]
     pub fn SDL_DelayPrecise(ns: Uint64);
 }
 
@@ -162,5 +162,30 @@ extern "C" {
     pub fn SDL_GetRevision() -> *const ::core::ffi::c_char;
 }
 
+/// (`sdl3-sys`) Check that the SDL library linked at runtime is at least version `at_least`,
+/// e.g. `SDL_VERSIONNUM(3, 1, 6)`. If it's older, this sets an error that can be
+/// retrieved with [`SDL_GetError`] and returns false.
+///
+/// The `sdl-X-Y-Z` features of `sdl3-sys` disable functions that are newer than SDL X.Y.Z.
+/// Call this at startup with the same version to make sure the installed SDL has all the
+/// functions that are available.
+pub fn require(at_least: ::core::ffi::c_int) -> ::core::primitive::bool {
+    let version = unsafe { SDL_GetVersion() };
+    if version >= at_least {
+        return true;
+    }
+    unsafe {
+        crate::error::SDL_SetError(
+            c"SDL version %d.%d.%d is required, but version %d.%d.%d is linked".as_ptr(),
+            SDL_VERSIONNUM_MAJOR(at_least),
+            SDL_VERSIONNUM_MINOR(at_least),
+            SDL_VERSIONNUM_MICRO(at_least),
+            SDL_VERSIONNUM_MAJOR(version),
+            SDL_VERSIONNUM_MINOR(version),
+            SDL_VERSIONNUM_MICRO(version),
+        )
+    }
+}
+
 #[cfg(doc)]
 use crate::everything::*;